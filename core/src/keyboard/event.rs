@@ -7,6 +7,11 @@ use super::{KeyCode, Modifiers};
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Event {
     /// A keyboard key was pressed.
     KeyPressed {
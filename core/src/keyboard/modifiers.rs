@@ -1,5 +1,10 @@
 /// The current state of the keyboard modifiers.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Modifiers {
     /// Whether a shift key is pressed
     pub shift: bool,
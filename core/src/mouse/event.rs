@@ -9,6 +9,11 @@ use super::Button;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Event {
     /// The mouse cursor entered the window.
     CursorEntered,
@@ -37,6 +42,11 @@ pub enum Event {
 
 /// A scroll movement.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum ScrollDelta {
     /// A line-based scroll movement
     Lines {
@@ -11,6 +11,7 @@ pub enum Interaction {
     Grabbing,
     ResizingHorizontally,
     ResizingVertically,
+    NotAllowed,
 }
 
 impl Default for Interaction {
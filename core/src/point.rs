@@ -2,6 +2,11 @@ use crate::Vector;
 
 /// A 2D point.
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub struct Point {
     /// The X coordinate.
     pub x: f32,
@@ -1,7 +1,7 @@
 use crate::{Point, Size, Vector};
 
 /// A rectangle.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct Rectangle<T = f32> {
     /// X coordinate of the top-left corner.
     pub x: T,
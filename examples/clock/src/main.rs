@@ -1,7 +1,7 @@
 use iced::{
     canvas::{self, Cache, Canvas, Cursor, Geometry, LineCap, Path, Stroke},
-    executor, time, Application, Clipboard, Color, Command, Container, Element,
-    Length, Point, Rectangle, Settings, Subscription, Vector,
+    executor, window, Application, Clipboard, Color, Command, Container,
+    Element, Length, Point, Rectangle, Settings, Subscription, Vector,
 };
 
 pub fn main() -> iced::Result {
@@ -60,8 +60,7 @@ impl Application for Clock {
     }
 
     fn subscription(&self) -> Subscription<Message> {
-        time::every(std::time::Duration::from_millis(500))
-            .map(|_| Message::Tick(chrono::Local::now()))
+        window::frames().map(|_| Message::Tick(chrono::Local::now()))
     }
 
     fn view(&mut self) -> Element<Message> {
@@ -111,17 +110,21 @@ impl canvas::Program<Message> for Clock {
             frame.translate(Vector::new(center.x, center.y));
 
             frame.with_save(|frame| {
-                frame.rotate(hand_rotation(self.now.hour(), 12));
+                frame.rotate(hand_rotation(self.now.hour() as f32, 12));
                 frame.stroke(&short_hand, wide_stroke);
             });
 
             frame.with_save(|frame| {
-                frame.rotate(hand_rotation(self.now.minute(), 60));
+                frame.rotate(hand_rotation(self.now.minute() as f32, 60));
                 frame.stroke(&long_hand, wide_stroke);
             });
 
             frame.with_save(|frame| {
-                frame.rotate(hand_rotation(self.now.second(), 60));
+                // The second hand sweeps smoothly with every frame
+                let second = self.now.second() as f32
+                    + self.now.nanosecond() as f32 / 1_000_000_000.0;
+
+                frame.rotate(hand_rotation(second, 60));
                 frame.stroke(&long_hand, thin_stroke);
             })
         });
@@ -130,8 +133,8 @@ impl canvas::Program<Message> for Clock {
     }
 }
 
-fn hand_rotation(n: u32, total: u32) -> f32 {
-    let turns = n as f32 / total as f32;
+fn hand_rotation(n: f32, total: u32) -> f32 {
+    let turns = n / total as f32;
 
     2.0 * std::f32::consts::PI * turns
 }
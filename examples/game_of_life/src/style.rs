@@ -1,4 +1,4 @@
-use iced::{button, container, pick_list, slider, Background, Color};
+use iced::{button, container, pick_list, slider, Background, Color, Theme};
 
 const ACTIVE: Color = Color::from_rgb(
     0x72 as f32 / 255.0,
@@ -27,7 +27,7 @@ const BACKGROUND: Color = Color::from_rgb(
 pub struct Container;
 
 impl container::StyleSheet for Container {
-    fn style(&self) -> container::Style {
+    fn style(&self, _theme: &Theme) -> container::Style {
         container::Style {
             background: Some(Background::Color(Color::from_rgb8(
                 0x36, 0x39, 0x3F,
@@ -41,7 +41,7 @@ impl container::StyleSheet for Container {
 pub struct Button;
 
 impl button::StyleSheet for Button {
-    fn active(&self) -> button::Style {
+    fn active(&self, _theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(ACTIVE)),
             border_radius: 3.0,
@@ -50,19 +50,19 @@ impl button::StyleSheet for Button {
         }
     }
 
-    fn hovered(&self) -> button::Style {
+    fn hovered(&self, theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(HOVERED)),
             text_color: Color::WHITE,
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn pressed(&self) -> button::Style {
+    fn pressed(&self, theme: &Theme) -> button::Style {
         button::Style {
             border_width: 1.0,
             border_color: Color::WHITE,
-            ..self.hovered()
+            ..self.hovered(theme)
         }
     }
 }
@@ -70,7 +70,7 @@ impl button::StyleSheet for Button {
 pub struct Clear;
 
 impl button::StyleSheet for Clear {
-    fn active(&self) -> button::Style {
+    fn active(&self, _theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(DESTRUCTIVE)),
             border_radius: 3.0,
@@ -79,22 +79,22 @@ impl button::StyleSheet for Clear {
         }
     }
 
-    fn hovered(&self) -> button::Style {
+    fn hovered(&self, theme: &Theme) -> button::Style {
         button::Style {
             background: Some(Background::Color(Color {
                 a: 0.5,
                 ..DESTRUCTIVE
             })),
             text_color: Color::WHITE,
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn pressed(&self) -> button::Style {
+    fn pressed(&self, theme: &Theme) -> button::Style {
         button::Style {
             border_width: 1.0,
             border_color: Color::WHITE,
-            ..self.hovered()
+            ..self.hovered(theme)
         }
     }
 }
@@ -102,7 +102,7 @@ impl button::StyleSheet for Clear {
 pub struct Slider;
 
 impl slider::StyleSheet for Slider {
-    fn active(&self) -> slider::Style {
+    fn active(&self, _theme: &Theme) -> slider::Style {
         slider::Style {
             rail_colors: (ACTIVE, Color { a: 0.1, ..ACTIVE }),
             handle: slider::Handle {
@@ -114,8 +114,8 @@ impl slider::StyleSheet for Slider {
         }
     }
 
-    fn hovered(&self) -> slider::Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> slider::Style {
+        let active = self.active(theme);
 
         slider::Style {
             handle: slider::Handle {
@@ -126,8 +126,8 @@ impl slider::StyleSheet for Slider {
         }
     }
 
-    fn dragging(&self) -> slider::Style {
-        let active = self.active();
+    fn dragging(&self, theme: &Theme) -> slider::Style {
+        let active = self.active(theme);
 
         slider::Style {
             handle: slider::Handle {
@@ -142,8 +142,8 @@ impl slider::StyleSheet for Slider {
 pub struct PickList;
 
 impl pick_list::StyleSheet for PickList {
-    fn menu(&self) -> pick_list::Menu {
-        pick_list::Menu {
+    fn menu(&self) -> Box<dyn pick_list::menu::StyleSheet> {
+        Box::new(pick_list::Menu {
             text_color: Color::WHITE,
             background: BACKGROUND.into(),
             border_width: 1.0,
@@ -157,10 +157,10 @@ impl pick_list::StyleSheet for PickList {
             }
             .into(),
             selected_text_color: Color::WHITE,
-        }
+        })
     }
 
-    fn active(&self) -> pick_list::Style {
+    fn active(&self, _theme: &Theme) -> pick_list::Style {
         pick_list::Style {
             text_color: Color::WHITE,
             background: BACKGROUND.into(),
@@ -174,8 +174,8 @@ impl pick_list::StyleSheet for PickList {
         }
     }
 
-    fn hovered(&self) -> pick_list::Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> pick_list::Style {
+        let active = self.active(theme);
 
         pick_list::Style {
             border_color: Color {
@@ -372,7 +372,7 @@ impl Controls {
 
 mod style {
     use crate::PANE_ID_COLOR_FOCUSED;
-    use iced::{button, container, Background, Color, Vector};
+    use iced::{button, container, Background, Color, Theme, Vector};
 
     const SURFACE: Color = Color::from_rgb(
         0xF2 as f32 / 255.0,
@@ -397,11 +397,11 @@ mod style {
     }
 
     impl container::StyleSheet for TitleBar {
-        fn style(&self) -> container::Style {
+        fn style(&self, theme: &Theme) -> container::Style {
             let pane = Pane {
                 is_focused: self.is_focused,
             }
-            .style();
+            .style(theme);
 
             container::Style {
                 text_color: Some(Color::WHITE),
@@ -416,7 +416,7 @@ mod style {
     }
 
     impl container::StyleSheet for Pane {
-        fn style(&self) -> container::Style {
+        fn style(&self, _theme: &Theme) -> container::Style {
             container::Style {
                 background: Some(Background::Color(SURFACE)),
                 border_width: 2.0,
@@ -438,7 +438,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             let (background, text_color) = match self {
                 Button::Primary => (Some(ACTIVE), Color::WHITE),
                 Button::Destructive => {
@@ -457,8 +457,8 @@ mod style {
             }
         }
 
-        fn hovered(&self) -> button::Style {
-            let active = self.active();
+        fn hovered(&self, theme: &Theme) -> button::Style {
+            let active = self.active(theme);
 
             let background = match self {
                 Button::Primary => Some(HOVERED),
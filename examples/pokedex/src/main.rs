@@ -251,14 +251,14 @@ fn button<'a>(state: &'a mut button::State, text: &str) -> Button<'a, Message> {
 }
 
 mod style {
-    use iced::{button, Background, Color, Vector};
+    use iced::{button, Background, Color, Theme, Vector};
 
     pub enum Button {
         Primary,
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             button::Style {
                 background: Some(Background::Color(match self {
                     Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
@@ -53,7 +53,7 @@ impl From<Theme> for Box<dyn rule::StyleSheet> {
 }
 
 mod dark {
-    use iced::{container, radio, rule, scrollable, Color};
+    use iced::{container, radio, rule, scrollable, Color, Theme};
 
     const BACKGROUND: Color = Color::from_rgb(
         0x36 as f32 / 255.0,
@@ -94,7 +94,7 @@ mod dark {
     pub struct Container;
 
     impl container::StyleSheet for Container {
-        fn style(&self) -> container::Style {
+        fn style(&self, _theme: &Theme) -> container::Style {
             container::Style {
                 background: Color {
                     a: 0.99,
@@ -110,7 +110,7 @@ mod dark {
     pub struct Radio;
 
     impl radio::StyleSheet for Radio {
-        fn active(&self) -> radio::Style {
+        fn active(&self, _theme: &Theme) -> radio::Style {
             radio::Style {
                 background: SURFACE.into(),
                 dot_color: ACTIVE,
@@ -119,10 +119,10 @@ mod dark {
             }
         }
 
-        fn hovered(&self) -> radio::Style {
+        fn hovered(&self, theme: &Theme) -> radio::Style {
             radio::Style {
                 background: Color { a: 0.5, ..SURFACE }.into(),
-                ..self.active()
+                ..self.active(theme)
             }
         }
     }
@@ -130,7 +130,7 @@ mod dark {
     pub struct Scrollable;
 
     impl scrollable::StyleSheet for Scrollable {
-        fn active(&self) -> scrollable::Scrollbar {
+        fn active(&self, _theme: &Theme) -> scrollable::Scrollbar {
             scrollable::Scrollbar {
                 background: Color {
                     a: 0.8,
@@ -149,8 +149,8 @@ mod dark {
             }
         }
 
-        fn hovered(&self) -> scrollable::Scrollbar {
-            let active = self.active();
+        fn hovered(&self, theme: &Theme) -> scrollable::Scrollbar {
+            let active = self.active(theme);
 
             scrollable::Scrollbar {
                 background: SCROLLBAR.into(),
@@ -162,8 +162,8 @@ mod dark {
             }
         }
 
-        fn dragging(&self) -> scrollable::Scrollbar {
-            let hovered = self.hovered();
+        fn dragging(&self, theme: &Theme) -> scrollable::Scrollbar {
+            let hovered = self.hovered(theme);
 
             scrollable::Scrollbar {
                 scroller: scrollable::Scroller {
@@ -178,7 +178,7 @@ mod dark {
     pub struct Rule;
 
     impl rule::StyleSheet for Rule {
-        fn style(&self) -> rule::Style {
+        fn style(&self, _theme: &Theme) -> rule::Style {
             rule::Style {
                 color: SURFACE,
                 width: 2,
@@ -149,7 +149,7 @@ impl Application for Stopwatch {
 }
 
 mod style {
-    use iced::{button, Background, Color, Vector};
+    use iced::{button, Background, Color, Theme, Vector};
 
     pub enum Button {
         Primary,
@@ -158,7 +158,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             button::Style {
                 background: Some(Background::Color(match self {
                     Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
@@ -597,7 +597,7 @@ impl SavedState {
 }
 
 mod style {
-    use iced::{button, Background, Color, Vector};
+    use iced::{button, Background, Color, Theme, Vector};
 
     pub enum Button {
         Filter { selected: bool },
@@ -606,7 +606,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             match self {
                 Button::Filter { selected } => {
                     if *selected {
@@ -638,8 +638,8 @@ mod style {
             }
         }
 
-        fn hovered(&self) -> button::Style {
-            let active = self.active();
+        fn hovered(&self, theme: &Theme) -> button::Style {
+            let active = self.active(theme);
 
             button::Style {
                 text_color: match self {
@@ -121,12 +121,12 @@ fn tooltip<'a>(
 
 mod style {
     use iced::container;
-    use iced::Color;
+    use iced::{Color, Theme};
 
     pub struct Tooltip;
 
     impl container::StyleSheet for Tooltip {
-        fn style(&self) -> container::Style {
+        fn style(&self, _theme: &Theme) -> container::Style {
             container::Style {
                 text_color: Some(Color::from_rgb8(0xEE, 0xEE, 0xEE)),
                 background: Some(Color::from_rgb(0.11, 0.42, 0.87).into()),
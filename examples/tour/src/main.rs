@@ -785,7 +785,7 @@ pub enum Layout {
 }
 
 mod style {
-    use iced::{button, Background, Color, Vector};
+    use iced::{button, Background, Color, Theme, Vector};
 
     pub enum Button {
         Primary,
@@ -793,7 +793,7 @@ mod style {
     }
 
     impl button::StyleSheet for Button {
-        fn active(&self) -> button::Style {
+        fn active(&self, _theme: &Theme) -> button::Style {
             button::Style {
                 background: Some(Background::Color(match self {
                     Button::Primary => Color::from_rgb(0.11, 0.42, 0.87),
@@ -806,11 +806,11 @@ mod style {
             }
         }
 
-        fn hovered(&self) -> button::Style {
+        fn hovered(&self, theme: &Theme) -> button::Style {
             button::Style {
                 text_color: Color::WHITE,
                 shadow_offset: Vector::new(1.0, 2.0),
-                ..self.active()
+                ..self.active(theme)
             }
         }
     }
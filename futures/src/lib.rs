@@ -21,7 +21,8 @@ pub mod subscription;
         feature = "tokio",
         feature = "tokio_old",
         feature = "async-std",
-        feature = "smol"
+        feature = "smol",
+        feature = "thread-pool"
     ),
     not(target_arch = "wasm32")
 ))]
@@ -30,7 +31,8 @@ pub mod subscription;
     doc(cfg(any(
         feature = "tokio",
         feature = "async-std",
-        feature = "smol"
+        feature = "smol",
+        feature = "thread-pool"
     )))
 )]
 pub mod time;
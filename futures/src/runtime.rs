@@ -1,5 +1,7 @@
 //! Run commands and keep track of subscriptions.
-use crate::{subscription, Command, Executor, Subscription};
+use crate::{
+    subscription, BoxFuture, BoxStream, Command, Executor, Subscription,
+};
 
 use futures::{channel::mpsc, Sink};
 use std::marker::PhantomData;
@@ -7,7 +9,9 @@ use std::marker::PhantomData;
 /// A batteries-included runtime of commands and subscriptions.
 ///
 /// If you have an [`Executor`], a [`Runtime`] can be leveraged to run any
-/// [`Command`] or [`Subscription`] and get notified of the results!
+/// [`Future`] or [`Subscription`] and get notified of the results!
+///
+/// [`Future`]: futures::Future
 #[derive(Debug)]
 pub struct Runtime<Hasher, Event, Executor, Sender, Message> {
     executor: Executor,
@@ -47,28 +51,68 @@ where
         self.executor.enter(f)
     }
 
-    /// Spawns a [`Command`] in the [`Runtime`].
+    /// Spawns a [`Future`] in the [`Runtime`].
     ///
     /// The resulting `Message` will be forwarded to the `Sender` of the
     /// [`Runtime`].
-    pub fn spawn(&mut self, command: Command<Message>) {
+    ///
+    /// [`Future`]: futures::Future
+    pub fn spawn(&mut self, future: BoxFuture<Message>) {
         use futures::{FutureExt, SinkExt};
 
-        let futures = command.futures();
+        let mut sender = self.sender.clone();
 
-        for future in futures {
-            let mut sender = self.sender.clone();
+        let future = future.then(|message| async move {
+            let _ = sender.send(message).await;
+
+            ()
+        });
 
-            let future = future.then(|message| async move {
-                let _ = sender.send(message).await;
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::info_span!("command"),
+        );
 
-                ()
-            });
+        self.executor.spawn(future);
+    }
 
-            self.executor.spawn(future);
+    /// Spawns every future of the given [`Command`] in the [`Runtime`].
+    ///
+    /// A [`Command`] of `iced_futures` can only contain futures, so this is
+    /// the same as calling [`spawn`] with each one of them.
+    ///
+    /// [`spawn`]: Self::spawn
+    pub fn spawn_command(&mut self, command: Command<Message>) {
+        for future in command.futures() {
+            self.spawn(future);
         }
     }
 
+    /// Runs a [`Stream`] in the [`Runtime`] until it ends.
+    ///
+    /// Every `Message` produced by the [`Stream`] will be forwarded to the
+    /// `Sender` of the [`Runtime`]. Dropping the [`Stream`] early—by wrapping
+    /// it in an [`Abortable`], for instance—stops it without producing any
+    /// further messages.
+    ///
+    /// [`Stream`]: futures::Stream
+    /// [`Abortable`]: futures::stream::Abortable
+    pub fn run(&mut self, stream: BoxStream<Message>) {
+        use futures::{FutureExt, StreamExt};
+
+        let sender = self.sender.clone();
+        let future = stream.map(Ok).forward(sender).map(|_| ());
+
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(
+            future,
+            tracing::info_span!("command", stream = true),
+        );
+
+        self.executor.spawn(future);
+    }
+
     /// Tracks a [`Subscription`] in the [`Runtime`].
     ///
     /// It will spawn new streams or close old ones as necessary! See
@@ -79,6 +123,9 @@ where
         &mut self,
         subscription: Subscription<Hasher, Event, Message>,
     ) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("subscriptions").entered();
+
         let Runtime {
             executor,
             subscriptions,
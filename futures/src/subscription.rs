@@ -5,6 +5,10 @@ pub use tracker::Tracker;
 
 use crate::BoxStream;
 
+use futures::channel::mpsc;
+use futures::Future;
+use std::marker::PhantomData;
+
 /// A request to listen to external events.
 ///
 /// Besides performing async actions on demand with [`Command`], most
@@ -44,6 +48,60 @@ where
         }
     }
 
+    /// Creates a [`Subscription`] that runs the future produced by the given
+    /// closure and produces all the values sent through its channel.
+    ///
+    /// The closure receives the `Sender` of a channel with the given buffer
+    /// size. This is the easiest way to run a background worker that reports
+    /// back to your application, like a download or a connection to a server.
+    ///
+    /// The `id` identifies the [`Subscription`]. The worker will keep running
+    /// for as long as a [`Subscription`] with the same `id` is requested.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn channel<I, F, Fut>(id: I, buffer: usize, f: F) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static + Send,
+        I: std::hash::Hash + 'static,
+        F: FnOnce(mpsc::Sender<O>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static + Send,
+    {
+        Self::from_recipe(Channel {
+            id,
+            buffer,
+            f,
+            _output: PhantomData,
+        })
+    }
+
+    /// Creates a [`Subscription`] that runs the future produced by the given
+    /// closure and produces all the values sent through its channel.
+    ///
+    /// The closure receives the `Sender` of a channel with the given buffer
+    /// size. This is the easiest way to run a background worker that reports
+    /// back to your application, like a download or a connection to a server.
+    ///
+    /// The `id` identifies the [`Subscription`]. The worker will keep running
+    /// for as long as a [`Subscription`] with the same `id` is requested.
+    #[cfg(target_arch = "wasm32")]
+    pub fn channel<I, F, Fut>(id: I, buffer: usize, f: F) -> Self
+    where
+        H: 'static,
+        E: 'static,
+        O: 'static,
+        I: std::hash::Hash + 'static,
+        F: FnOnce(mpsc::Sender<O>) -> Fut + 'static,
+        Fut: Future<Output = ()> + 'static,
+    {
+        Self::from_recipe(Channel {
+            id,
+            buffer,
+            f,
+            _output: PhantomData,
+        })
+    }
+
     /// Batches all the provided subscriptions and returns the resulting
     /// [`Subscription`].
     pub fn batch(
@@ -230,3 +288,115 @@ where
         )
     }
 }
+
+struct Channel<I, F, O> {
+    id: I,
+    buffer: usize,
+    f: F,
+    _output: PhantomData<O>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<H, E, I, F, Fut, O> Recipe<H, E> for Channel<I, F, O>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    F: FnOnce(mpsc::Sender<O>) -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static + Send,
+    O: 'static + Send,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        Self::hash_id(&self.id, state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<O> {
+        Box::pin(Self::run(self.buffer, self.f))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<H, E, I, F, Fut, O> Recipe<H, E> for Channel<I, F, O>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    F: FnOnce(mpsc::Sender<O>) -> Fut + 'static,
+    Fut: Future<Output = ()> + 'static,
+    O: 'static,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        Self::hash_id(&self.id, state);
+    }
+
+    fn stream(self: Box<Self>, _input: BoxStream<E>) -> BoxStream<O> {
+        Box::pin(Self::run(self.buffer, self.f))
+    }
+}
+
+impl<I, F, O> Channel<I, F, O>
+where
+    I: std::hash::Hash + 'static,
+{
+    fn hash_id<H: std::hash::Hasher>(id: &I, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+        std::any::TypeId::of::<I>().hash(state);
+
+        id.hash(state);
+    }
+
+    fn run<Fut>(buffer: usize, f: F) -> impl futures::Stream<Item = O>
+    where
+        F: FnOnce(mpsc::Sender<O>) -> Fut,
+        Fut: Future<Output = ()>,
+    {
+        use futures::{future, stream, StreamExt};
+
+        let (sender, receiver) = mpsc::channel(buffer);
+
+        // The worker never produces values by itself; it only keeps running
+        // alongside the receiver until it finishes
+        let worker =
+            stream::once(f(sender)).filter_map(|_| future::ready(None::<O>));
+
+        stream::select(receiver, worker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::{executor, stream, SinkExt, StreamExt};
+    use std::collections::hash_map::DefaultHasher;
+
+    #[test]
+    fn channel_produces_every_sent_value() {
+        let subscription = Subscription::<DefaultHasher, (), u32>::channel(
+            "counter",
+            1,
+            |mut sender| async move {
+                for i in 0..3 {
+                    let _ = sender.send(i).await;
+                }
+            },
+        );
+
+        let values: Vec<u32> = subscription
+            .recipes()
+            .into_iter()
+            .flat_map(|recipe| {
+                executor::block_on(
+                    recipe.stream(stream::empty().boxed()).collect::<Vec<_>>(),
+                )
+            })
+            .collect();
+
+        assert_eq!(values, vec![0, 1, 2]);
+    }
+}
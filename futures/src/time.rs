@@ -1,5 +1,10 @@
 //! Listen and react to time.
 use crate::subscription::{self, Subscription};
+use crate::{BoxFuture, BoxStream};
+
+use futures::future::{self, Either, FutureExt};
+use futures::stream::{self, StreamExt};
+use std::time::{Duration, Instant};
 
 /// Returns a [`Subscription`] that produces messages at a set interval.
 ///
@@ -11,6 +16,384 @@ pub fn every<H: std::hash::Hasher, E>(
     Subscription::from_recipe(Every(duration))
 }
 
+/// Returns a [`Future`] that resolves with the current [`Instant`] once the
+/// given `duration` has elapsed.
+///
+/// [`Future`]: futures::Future
+pub fn after(duration: Duration) -> BoxFuture<Instant> {
+    sleep(duration).map(|_| Instant::now()).boxed()
+}
+
+/// Debounces the given [`Subscription`].
+///
+/// The resulting [`Subscription`] only produces the latest output of the
+/// original one once it has not produced anything else for `duration`. This
+/// is useful to avoid reacting to bursts of events, like a user typing.
+///
+/// The `id` identifies the resulting [`Subscription`].
+pub fn debounce<H, E, I, O>(
+    id: I,
+    duration: Duration,
+    subscription: Subscription<H, E, O>,
+) -> Subscription<H, E, O>
+where
+    H: std::hash::Hasher + 'static,
+    E: 'static,
+    I: std::hash::Hash + Clone + 'static,
+    O: Send + 'static,
+{
+    Subscription::batch(subscription.recipes().into_iter().map(|recipe| {
+        Subscription::from_recipe(Debounce {
+            id: id.clone(),
+            duration,
+            recipe,
+            clock: System,
+        })
+    }))
+}
+
+/// Throttles the given [`Subscription`].
+///
+/// The resulting [`Subscription`] produces at most one output of the
+/// original one every `duration`. Outputs produced too early are dropped,
+/// except for the latest one, which is produced once `duration` has elapsed.
+/// Therefore, the last output of a burst is never lost.
+///
+/// The `id` identifies the resulting [`Subscription`].
+pub fn throttle<H, E, I, O>(
+    id: I,
+    duration: Duration,
+    subscription: Subscription<H, E, O>,
+) -> Subscription<H, E, O>
+where
+    H: std::hash::Hasher + 'static,
+    E: 'static,
+    I: std::hash::Hash + Clone + 'static,
+    O: Send + 'static,
+{
+    Subscription::batch(subscription.recipes().into_iter().map(|recipe| {
+        Subscription::from_recipe(Throttle {
+            id: id.clone(),
+            duration,
+            recipe,
+            clock: System,
+        })
+    }))
+}
+
+/// A source of time for the combinators in this module.
+///
+/// It lets tests control the passage of time.
+trait Clock: Send + Sync + 'static {
+    /// Returns the current [`Instant`].
+    fn now(&self) -> Instant;
+
+    /// Returns a [`Future`] that resolves once the given `duration` has
+    /// elapsed.
+    ///
+    /// [`Future`]: futures::Future
+    fn sleep(&self, duration: Duration) -> BoxFuture<()>;
+}
+
+/// The [`Clock`] of the system, driven by the timer of the executor.
+struct System;
+
+impl Clock for System {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+        sleep(duration)
+    }
+}
+
+struct Debounce<H, E, I, O, C> {
+    id: I,
+    duration: Duration,
+    recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+    clock: C,
+}
+
+impl<H, E, I, O, C> subscription::Recipe<H, E> for Debounce<H, E, I, O, C>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    O: Send + 'static,
+    C: Clock,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.id.hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<O> {
+        let duration = self.duration;
+        let clock = std::sync::Arc::new(self.clock);
+        let outputs = self.recipe.stream(input).fuse();
+
+        stream::unfold((outputs, None), move |(mut outputs, mut pending)| {
+            let clock = clock.clone();
+
+            async move {
+                loop {
+                    match pending.take() {
+                        None => {
+                            pending = Some(outputs.next().await?);
+                        }
+                        Some(output) => {
+                            match future::select(
+                                outputs.next(),
+                                clock.sleep(duration),
+                            )
+                            .await
+                            {
+                                Either::Left((Some(next), _)) => {
+                                    pending = Some(next);
+                                }
+                                Either::Left((None, _)) | Either::Right(_) => {
+                                    return Some((output, (outputs, None)));
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+struct Throttle<H, E, I, O, C> {
+    id: I,
+    duration: Duration,
+    recipe: Box<dyn subscription::Recipe<H, E, Output = O>>,
+    clock: C,
+}
+
+impl<H, E, I, O, C> subscription::Recipe<H, E> for Throttle<H, E, I, O, C>
+where
+    H: std::hash::Hasher,
+    I: std::hash::Hash + 'static,
+    O: Send + 'static,
+    C: Clock,
+{
+    type Output = O;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.id.hash(state);
+        self.duration.hash(state);
+        self.recipe.hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: BoxStream<E>) -> BoxStream<O> {
+        let duration = self.duration;
+        let clock = std::sync::Arc::new(self.clock);
+        let outputs = self.recipe.stream(input).fuse();
+
+        stream::unfold(
+            (outputs, None),
+            move |(mut outputs, window_end): (_, Option<Instant>)| {
+                let clock = clock.clone();
+
+                async move {
+                    let window_end = match window_end {
+                        Some(window_end) => window_end,
+                        None => {
+                            let output = outputs.next().await?;
+
+                            return Some((
+                                output,
+                                (outputs, Some(clock.now() + duration)),
+                            ));
+                        }
+                    };
+
+                    let mut latest = None;
+
+                    loop {
+                        let remaining =
+                            window_end.saturating_duration_since(clock.now());
+
+                        match future::select(
+                            outputs.next(),
+                            clock.sleep(remaining),
+                        )
+                        .await
+                        {
+                            Either::Left((Some(output), _)) => {
+                                latest = Some(output);
+                            }
+                            Either::Left((None, sleep)) => {
+                                sleep.await;
+                                break;
+                            }
+                            Either::Right(_) => break,
+                        }
+                    }
+
+                    match latest {
+                        Some(output) => Some((
+                            output,
+                            (outputs, Some(clock.now() + duration)),
+                        )),
+                        None => {
+                            let output = outputs.next().await?;
+
+                            Some((
+                                output,
+                                (outputs, Some(clock.now() + duration)),
+                            ))
+                        }
+                    }
+                }
+            },
+        )
+        .boxed()
+    }
+}
+
+#[cfg(all(
+    not(any(feature = "tokio_old", feature = "tokio", feature = "async-std")),
+    feature = "smol"
+))]
+fn sleep(duration: Duration) -> BoxFuture<()> {
+    smol::Timer::after(duration).map(|_| ()).boxed()
+}
+
+#[cfg(feature = "async-std")]
+fn sleep(duration: Duration) -> BoxFuture<()> {
+    async_std::task::sleep(duration).boxed()
+}
+
+#[cfg(all(
+    any(feature = "tokio", feature = "tokio_old"),
+    not(any(feature = "async-std", feature = "smol"))
+))]
+fn sleep(duration: Duration) -> BoxFuture<()> {
+    // `tokio_old` takes precedence, like in the default executor
+    #[cfg(feature = "tokio_old")]
+    {
+        tokio_old::time::delay_for(duration).boxed()
+    }
+    #[cfg(not(feature = "tokio_old"))]
+    {
+        tokio::time::sleep(duration).boxed()
+    }
+}
+
+#[cfg(all(
+    feature = "thread-pool",
+    not(any(
+        feature = "tokio_old",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))
+))]
+fn sleep(duration: Duration) -> BoxFuture<()> {
+    use futures::channel::oneshot;
+
+    let (sender, receiver) = oneshot::channel();
+
+    timer::schedule(Instant::now() + duration, sender);
+
+    receiver.map(|_| ()).boxed()
+}
+
+/// A single thread that wakes up sleeping futures, since a thread pool has no
+/// timer of its own.
+#[cfg(all(
+    feature = "thread-pool",
+    not(any(
+        feature = "tokio_old",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))
+))]
+mod timer {
+    use futures::channel::oneshot;
+    use std::sync::{mpsc, Mutex, PoisonError};
+    use std::thread;
+    use std::time::Instant;
+
+    type Timeout = (Instant, oneshot::Sender<()>);
+
+    static TIMER: Mutex<Option<mpsc::Sender<Timeout>>> = Mutex::new(None);
+
+    /// Notifies the given sender once the deadline has passed, unless its
+    /// receiver is dropped before.
+    pub fn schedule(deadline: Instant, sender: oneshot::Sender<()>) {
+        let mut timer = TIMER.lock().unwrap_or_else(PoisonError::into_inner);
+
+        let _ = timer.get_or_insert_with(spawn).send((deadline, sender));
+    }
+
+    fn spawn() -> mpsc::Sender<Timeout> {
+        let (sender, receiver) = mpsc::channel();
+
+        let _ = thread::Builder::new()
+            .name(String::from("iced_futures timer"))
+            .spawn(move || run(receiver))
+            .expect("Spawn timer thread");
+
+        sender
+    }
+
+    fn run(receiver: mpsc::Receiver<Timeout>) {
+        let mut timeouts: Vec<Timeout> = Vec::new();
+
+        loop {
+            let now = Instant::now();
+
+            let (due, pending): (Vec<_>, Vec<_>) = timeouts
+                .drain(..)
+                .partition(|(deadline, _)| *deadline <= now);
+
+            for (_, sender) in due {
+                let _ = sender.send(());
+            }
+
+            // Sleeps whose futures were dropped are forgotten
+            timeouts = pending
+                .into_iter()
+                .filter(|(_, sender)| !sender.is_canceled())
+                .collect();
+
+            let next_deadline =
+                timeouts.iter().map(|(deadline, _)| *deadline).min();
+
+            let timeout = match next_deadline {
+                Some(deadline) => receiver
+                    .recv_timeout(deadline.saturating_duration_since(now)),
+                None => receiver
+                    .recv()
+                    .map_err(|_| mpsc::RecvTimeoutError::Disconnected),
+            };
+
+            match timeout {
+                Ok(timeout) => timeouts.push(timeout),
+                Err(mpsc::RecvTimeoutError::Timeout) => {}
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    }
+}
+
 struct Every(std::time::Duration);
 
 #[cfg(all(
@@ -111,3 +494,213 @@ where
         stream.map(tokio::time::Instant::into_std).boxed()
     }
 }
+
+#[cfg(all(
+    feature = "thread-pool",
+    not(any(
+        feature = "tokio_old",
+        feature = "tokio",
+        feature = "async-std",
+        feature = "smol"
+    ))
+))]
+impl<H, E> subscription::Recipe<H, E> for Every
+where
+    H: std::hash::Hasher,
+{
+    type Output = std::time::Instant;
+
+    fn hash(&self, state: &mut H) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+        self.0.hash(state);
+    }
+
+    fn stream(
+        self: Box<Self>,
+        _input: futures::stream::BoxStream<'static, E>,
+    ) -> futures::stream::BoxStream<'static, Self::Output> {
+        let duration = self.0;
+
+        stream::unfold(Instant::now() + duration, move |next| async move {
+            sleep(next.saturating_duration_since(Instant::now())).await;
+
+            Some((Instant::now(), next + duration))
+        })
+        .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::task;
+    use std::collections::hash_map::DefaultHasher;
+    use std::sync::{Arc, Mutex};
+
+    /// A [`Clock`] that only moves forward when every future waiting on it
+    /// is stuck.
+    #[derive(Clone)]
+    struct Manual {
+        start: Instant,
+        state: Arc<Mutex<State>>,
+    }
+
+    #[derive(Default)]
+    struct State {
+        elapsed: Duration,
+        deadlines: Vec<Duration>,
+    }
+
+    impl Manual {
+        fn new() -> Self {
+            Manual {
+                start: Instant::now(),
+                state: Arc::new(Mutex::new(State::default())),
+            }
+        }
+
+        fn elapsed(&self) -> Duration {
+            self.state.lock().unwrap().elapsed
+        }
+
+        /// Jumps to the earliest pending deadline.
+        fn advance(&self) {
+            let mut state = self.state.lock().unwrap();
+            let elapsed = state.elapsed;
+
+            let next = state
+                .deadlines
+                .iter()
+                .copied()
+                .filter(|deadline| *deadline > elapsed)
+                .min()
+                .expect("Stalled without pending timers");
+
+            state.elapsed = next;
+            state.deadlines.retain(|deadline| *deadline > next);
+        }
+    }
+
+    impl Clock for Manual {
+        fn now(&self) -> Instant {
+            self.start + self.elapsed()
+        }
+
+        fn sleep(&self, duration: Duration) -> BoxFuture<()> {
+            let state = self.state.clone();
+            let deadline = {
+                let mut state = state.lock().unwrap();
+                let deadline = state.elapsed + duration;
+
+                state.deadlines.push(deadline);
+                deadline
+            };
+
+            future::poll_fn(move |_| {
+                if state.lock().unwrap().elapsed >= deadline {
+                    task::Poll::Ready(())
+                } else {
+                    task::Poll::Pending
+                }
+            })
+            .boxed()
+        }
+    }
+
+    /// Collects every output of the given stream, together with the time
+    /// it was produced at.
+    ///
+    /// Time only moves forward once polling the stream again a few times
+    /// does not produce anything, since some futures may need more than one
+    /// poll to notice the progress of others.
+    fn outputs(clock: &Manual, mut stream: BoxStream<u32>) -> Vec<(u32, u64)> {
+        const IDLE_POLLS: usize = 10;
+
+        let mut context = task::Context::from_waker(task::noop_waker_ref());
+        let mut outputs = Vec::new();
+        let mut idle_polls = 0;
+
+        loop {
+            match stream.poll_next_unpin(&mut context) {
+                task::Poll::Ready(Some(output)) => {
+                    outputs.push((output, clock.elapsed().as_millis() as u64));
+                    idle_polls = 0;
+                }
+                task::Poll::Ready(None) => break,
+                task::Poll::Pending if idle_polls < IDLE_POLLS => {
+                    idle_polls += 1;
+                }
+                task::Poll::Pending => {
+                    clock.advance();
+                    idle_polls = 0;
+                }
+            }
+        }
+
+        outputs
+    }
+
+    /// Produces 0, 1 and 2 right away and 3 after 100 milliseconds.
+    fn burst(
+        clock: &Manual,
+    ) -> Box<dyn subscription::Recipe<DefaultHasher, (), Output = u32>> {
+        let clock = clock.clone();
+
+        Subscription::channel("burst", 1, move |mut sender| async move {
+            use futures::SinkExt;
+
+            for i in 0..3 {
+                let _ = sender.send(i).await;
+            }
+
+            clock.sleep(Duration::from_millis(100)).await;
+
+            let _ = sender.send(3).await;
+        })
+        .recipes()
+        .remove(0)
+    }
+
+    #[test]
+    fn debounce_keeps_the_latest_output_of_a_burst() {
+        let clock = Manual::new();
+
+        let debounce = Box::new(Debounce {
+            id: "search",
+            duration: Duration::from_millis(50),
+            recipe: burst(&clock),
+            clock: clock.clone(),
+        });
+
+        assert_eq!(
+            outputs(
+                &clock,
+                subscription::Recipe::stream(debounce, stream::empty().boxed())
+            ),
+            vec![(2, 50), (3, 100)]
+        );
+    }
+
+    #[test]
+    fn throttle_delays_the_latest_output_of_a_burst() {
+        let clock = Manual::new();
+
+        let throttle = Box::new(Throttle {
+            id: "search",
+            duration: Duration::from_millis(50),
+            recipe: burst(&clock),
+            clock: clock.clone(),
+        });
+
+        assert_eq!(
+            outputs(
+                &clock,
+                subscription::Recipe::stream(throttle, stream::empty().boxed())
+            ),
+            vec![(0, 0), (2, 50), (3, 100)]
+        );
+    }
+}
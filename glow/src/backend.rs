@@ -1,3 +1,6 @@
+#[cfg(feature = "image_rs")]
+use crate::image;
+use crate::offscreen;
 use crate::quad;
 use crate::text;
 use crate::triangle;
@@ -16,8 +19,11 @@ use iced_native::{Font, HorizontalAlignment, Size, VerticalAlignment};
 #[derive(Debug)]
 pub struct Backend {
     quad_pipeline: quad::Pipeline,
+    #[cfg(feature = "image_rs")]
+    image_pipeline: image::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    offscreen_pipeline: offscreen::Pipeline,
     default_text_size: u16,
 }
 
@@ -27,11 +33,18 @@ impl Backend {
         let text_pipeline = text::Pipeline::new(gl, settings.default_font);
         let quad_pipeline = quad::Pipeline::new(gl);
         let triangle_pipeline = triangle::Pipeline::new(gl);
+        let offscreen_pipeline = offscreen::Pipeline::new(gl);
+
+        #[cfg(feature = "image_rs")]
+        let image_pipeline = image::Pipeline::new(gl);
 
         Self {
             quad_pipeline,
+            #[cfg(feature = "image_rs")]
+            image_pipeline,
             text_pipeline,
             triangle_pipeline,
+            offscreen_pipeline,
             default_text_size: settings.default_text_size,
         }
     }
@@ -55,15 +68,14 @@ impl Backend {
         layers.push(Layer::overlay(overlay_text, viewport));
 
         for layer in layers {
-            self.flush(
-                gl,
-                scale_factor,
-                projection,
-                &layer,
-                viewport_size.height,
-            );
+            self.flush(gl, scale_factor, projection, &layer, viewport_size);
         }
 
+        self.offscreen_pipeline.trim(gl);
+
+        #[cfg(feature = "image_rs")]
+        self.image_pipeline.trim_cache(gl);
+
         *mouse_interaction
     }
 
@@ -73,8 +85,9 @@ impl Backend {
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
-        target_height: u32,
+        target_size: Size<u32>,
     ) {
+        let target_height = target_size.height;
         let mut bounds = (layer.bounds * scale_factor).snap();
         bounds.height = bounds.height.min(target_height);
 
@@ -89,6 +102,22 @@ impl Backend {
             );
         }
 
+        #[cfg(feature = "image_rs")]
+        {
+            if !layer.images.is_empty() {
+                let scaled = transformation
+                    * Transformation::scale(scale_factor, scale_factor);
+
+                self.image_pipeline.draw(
+                    gl,
+                    target_height,
+                    &layer.images,
+                    scaled,
+                    bounds,
+                );
+            }
+        }
+
         if !layer.meshes.is_empty() {
             let scaled = transformation
                 * Transformation::scale(scale_factor, scale_factor);
@@ -102,6 +131,19 @@ impl Backend {
             );
         }
 
+        if !layer.shaders.is_empty() {
+            let scaled = transformation
+                * Transformation::scale(scale_factor, scale_factor);
+
+            self.offscreen_pipeline.draw(
+                gl,
+                target_size,
+                &layer.shaders,
+                scaled,
+                scale_factor,
+            );
+        }
+
         if !layer.text.is_empty() {
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
@@ -208,10 +250,17 @@ impl backend::Text for Backend {
     }
 }
 
-#[cfg(feature = "image")]
+#[cfg(feature = "image_rs")]
 impl backend::Image for Backend {
-    fn dimensions(&self, _handle: &iced_native::image::Handle) -> (u32, u32) {
-        (50, 50)
+    fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
+        self.image_pipeline.dimensions(handle)
+    }
+
+    fn frames(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::animated::Frames {
+        self.image_pipeline.frames(handle)
     }
 }
 
@@ -0,0 +1,187 @@
+mod raster;
+
+use crate::program;
+use crate::Transformation;
+use glow::HasContext;
+use iced_graphics::layer;
+use iced_native::image;
+use iced_native::Rectangle;
+use std::cell::RefCell;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    transform_location: <glow::Context as HasContext>::UniformLocation,
+    bounds_location: <glow::Context as HasContext>::UniformLocation,
+    source_location: <glow::Context as HasContext>::UniformLocation,
+    raster_cache: RefCell<raster::Cache>,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context) -> Pipeline {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (glow::VERTEX_SHADER, include_str!("shader/image.vert")),
+                    (glow::FRAGMENT_SHADER, include_str!("shader/image.frag")),
+                ],
+            )
+        };
+
+        let transform_location =
+            unsafe { gl.get_uniform_location(program, "u_Transform") }
+                .expect("Get transform location");
+
+        let bounds_location =
+            unsafe { gl.get_uniform_location(program, "u_Bounds") }
+                .expect("Get bounds location");
+
+        let source_location =
+            unsafe { gl.get_uniform_location(program, "u_Source") }
+                .expect("Get source location");
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(Some(&texture_location), 0);
+            gl.use_program(None);
+        }
+
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        Pipeline {
+            program,
+            vertex_array,
+            transform_location,
+            bounds_location,
+            source_location,
+            raster_cache: RefCell::new(raster::Cache::new()),
+        }
+    }
+
+    pub fn dimensions(&self, handle: &image::Handle) -> (u32, u32) {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(handle);
+
+        memory.dimensions()
+    }
+
+    pub fn frames(&self, handle: &image::Handle) -> image::animated::Frames {
+        let mut cache = self.raster_cache.borrow_mut();
+
+        cache.frames(handle)
+    }
+
+    pub fn draw(
+        &mut self,
+        gl: &glow::Context,
+        target_height: u32,
+        images: &[layer::Image],
+        transformation: Transformation,
+        bounds: Rectangle<u32>,
+    ) {
+        let cache = self.raster_cache.get_mut();
+
+        unsafe {
+            gl.enable(glow::SCISSOR_TEST);
+            gl.scissor(
+                bounds.x as i32,
+                (target_height - (bounds.y + bounds.height)) as i32,
+                bounds.width as i32,
+                bounds.height as i32,
+            );
+
+            gl.use_program(Some(self.program));
+            gl.bind_vertex_array(Some(self.vertex_array));
+            gl.active_texture(glow::TEXTURE0);
+
+            let matrix: [f32; 16] = transformation.into();
+            gl.uniform_matrix_4_f32_slice(
+                Some(&self.transform_location),
+                false,
+                &matrix,
+            );
+        }
+
+        for image in images {
+            // Vector images are not supported yet!
+            if let layer::Image::Raster {
+                handle,
+                bounds,
+                filter_method,
+                source,
+            } = image
+            {
+                if let Some(texture) = cache.upload(gl, handle) {
+                    let width = texture.width as f32;
+                    let height = texture.height as f32;
+
+                    let source = source.unwrap_or(Rectangle {
+                        x: 0.0,
+                        y: 0.0,
+                        width,
+                        height,
+                    });
+
+                    let filter = match filter_method {
+                        image::FilterMethod::Linear => glow::LINEAR,
+                        image::FilterMethod::Nearest => glow::NEAREST,
+                    };
+
+                    unsafe {
+                        gl.uniform_4_f32(
+                            Some(&self.bounds_location),
+                            bounds.x,
+                            bounds.y,
+                            bounds.width,
+                            bounds.height,
+                        );
+
+                        gl.uniform_4_f32(
+                            Some(&self.source_location),
+                            source.x / width,
+                            source.y / height,
+                            source.width / width,
+                            source.height / height,
+                        );
+
+                        gl.bind_texture(
+                            glow::TEXTURE_2D,
+                            Some(texture.texture),
+                        );
+
+                        gl.tex_parameter_i32(
+                            glow::TEXTURE_2D,
+                            glow::TEXTURE_MIN_FILTER,
+                            filter as i32,
+                        );
+                        gl.tex_parameter_i32(
+                            glow::TEXTURE_2D,
+                            glow::TEXTURE_MAG_FILTER,
+                            filter as i32,
+                        );
+
+                        gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+                    }
+                }
+            }
+        }
+
+        unsafe {
+            gl.bind_texture(glow::TEXTURE_2D, None);
+            gl.bind_vertex_array(None);
+            gl.use_program(None);
+            gl.disable(glow::SCISSOR_TEST);
+        }
+    }
+
+    pub fn trim_cache(&mut self, gl: &glow::Context) {
+        self.raster_cache.get_mut().trim(gl);
+    }
+}
@@ -0,0 +1,207 @@
+use glow::HasContext;
+use iced_graphics::raster;
+use iced_native::image::{self, animated};
+use std::collections::{HashMap, HashSet};
+
+#[derive(Debug)]
+pub enum Memory {
+    Host(raster::Image),
+    Device(Texture),
+    NotFound,
+    Invalid,
+}
+
+impl Memory {
+    pub fn dimensions(&self) -> (u32, u32) {
+        match self {
+            Memory::Host(image) => image.dimensions(),
+            Memory::Device(texture) => (texture.width, texture.height),
+            Memory::NotFound => (1, 1),
+            Memory::Invalid => (1, 1),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Texture {
+    pub texture: <glow::Context as HasContext>::Texture,
+    pub width: u32,
+    pub height: u32,
+}
+
+#[derive(Debug)]
+pub struct Cache {
+    map: HashMap<u64, Memory>,
+    animations: HashMap<u64, animated::Frames>,
+    hits: HashSet<u64>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self {
+            map: HashMap::new(),
+            animations: HashMap::new(),
+            hits: HashSet::new(),
+        }
+    }
+
+    pub fn load(&mut self, handle: &image::Handle) -> &mut Memory {
+        if self.contains(handle) {
+            return self.get(handle).unwrap();
+        }
+
+        let memory = match raster::load(handle) {
+            Ok(image) => Memory::Host(image),
+            Err(raster::Error::NotFound) => Memory::NotFound,
+            Err(raster::Error::Invalid) => Memory::Invalid,
+        };
+
+        self.insert(handle, memory);
+        self.get(handle).unwrap()
+    }
+
+    pub fn frames(&mut self, handle: &image::Handle) -> animated::Frames {
+        let frames = self
+            .animations
+            .entry(handle.id())
+            .or_insert_with(|| {
+                raster::load_frames(handle)
+                    .unwrap_or_else(|_| animated::Frames::from(handle.clone()))
+            })
+            .clone();
+
+        // Keep every frame of the animation in video memory while it is in
+        // use, not only the one that is currently displayed.
+        let _ = self.hits.insert(handle.id());
+
+        for frame in frames.iter() {
+            let _ = self.hits.insert(frame.handle.id());
+        }
+
+        frames
+    }
+
+    pub fn upload(
+        &mut self,
+        gl: &glow::Context,
+        handle: &image::Handle,
+    ) -> Option<&Texture> {
+        let memory = self.load(handle);
+
+        if let Memory::Host(image) = memory {
+            let (width, height) = image.dimensions();
+
+            let texture = unsafe {
+                let texture = gl.create_texture().expect("Create texture");
+
+                gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+                gl.tex_image_2d(
+                    glow::TEXTURE_2D,
+                    0,
+                    glow::SRGB8_ALPHA8 as i32,
+                    width as i32,
+                    height as i32,
+                    0,
+                    glow::BGRA,
+                    glow::UNSIGNED_BYTE,
+                    Some(image.as_raw()),
+                );
+
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_WRAP_S,
+                    glow::CLAMP_TO_EDGE as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_WRAP_T,
+                    glow::CLAMP_TO_EDGE as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MIN_FILTER,
+                    glow::LINEAR as i32,
+                );
+                gl.tex_parameter_i32(
+                    glow::TEXTURE_2D,
+                    glow::TEXTURE_MAG_FILTER,
+                    glow::LINEAR as i32,
+                );
+
+                gl.bind_texture(glow::TEXTURE_2D, None);
+
+                texture
+            };
+
+            *memory = Memory::Device(Texture {
+                texture,
+                width,
+                height,
+            });
+        }
+
+        if let (Memory::Device(texture), image::Data::Texture(source)) =
+            (&*memory, handle.data())
+        {
+            if let Some((region, pixels)) = source.take_changes() {
+                unsafe {
+                    gl.bind_texture(glow::TEXTURE_2D, Some(texture.texture));
+                    gl.tex_sub_image_2d_u8_slice(
+                        glow::TEXTURE_2D,
+                        0,
+                        region.x as i32,
+                        region.y as i32,
+                        region.width as i32,
+                        region.height as i32,
+                        glow::BGRA,
+                        glow::UNSIGNED_BYTE,
+                        Some(&pixels),
+                    );
+                    gl.bind_texture(glow::TEXTURE_2D, None);
+                }
+            }
+        }
+
+        if let Memory::Device(texture) = memory {
+            Some(texture)
+        } else {
+            None
+        }
+    }
+
+    pub fn trim(&mut self, gl: &glow::Context) {
+        let hits = &self.hits;
+
+        self.animations.retain(|k, _| hits.contains(k));
+
+        self.map.retain(|k, memory| {
+            let retain = hits.contains(k);
+
+            if !retain {
+                if let Memory::Device(texture) = memory {
+                    unsafe {
+                        gl.delete_texture(texture.texture);
+                    }
+                }
+            }
+
+            retain
+        });
+
+        self.hits.clear();
+    }
+
+    fn get(&mut self, handle: &image::Handle) -> Option<&mut Memory> {
+        let _ = self.hits.insert(handle.id());
+
+        self.map.get_mut(&handle.id())
+    }
+
+    fn insert(&mut self, handle: &image::Handle, memory: Memory) {
+        let _ = self.map.insert(handle.id(), memory);
+    }
+
+    fn contains(&self, handle: &image::Handle) -> bool {
+        self.map.contains_key(&handle.id())
+    }
+}
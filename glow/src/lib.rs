@@ -11,6 +11,9 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 mod backend;
+#[cfg(feature = "image_rs")]
+mod image;
+mod offscreen;
 mod program;
 mod quad;
 mod text;
@@ -23,6 +26,8 @@ pub mod window;
 pub use backend::Backend;
 pub use settings::Settings;
 
+pub use glow;
+
 pub(crate) use iced_graphics::Transformation;
 
 #[doc(no_inline)]
@@ -34,6 +39,10 @@ pub use iced_native::{
     VerticalAlignment,
 };
 
+#[cfg(feature = "sheet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sheet")))]
+pub use iced_graphics::sheet;
+
 /// A [`glow`] graphics renderer for [`iced`].
 ///
 /// [`glow`]: https://github.com/grovesNL/glow
@@ -0,0 +1,259 @@
+use crate::program;
+use crate::widget::shader;
+use crate::Transformation;
+use glow::HasContext;
+use iced_graphics::layer;
+use iced_native::Size;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    program: <glow::Context as HasContext>::Program,
+    vertex_array: <glow::Context as HasContext>::VertexArray,
+    transform_location: <glow::Context as HasContext>::UniformLocation,
+    bounds_location: <glow::Context as HasContext>::UniformLocation,
+    targets: Vec<Target>,
+    next_target: usize,
+}
+
+impl Pipeline {
+    pub fn new(gl: &glow::Context) -> Pipeline {
+        let program = unsafe {
+            program::create(
+                gl,
+                &[
+                    (
+                        glow::VERTEX_SHADER,
+                        include_str!("shader/offscreen.vert"),
+                    ),
+                    (glow::FRAGMENT_SHADER, include_str!("shader/image.frag")),
+                ],
+            )
+        };
+
+        let transform_location =
+            unsafe { gl.get_uniform_location(program, "u_Transform") }
+                .expect("Get transform location");
+
+        let bounds_location =
+            unsafe { gl.get_uniform_location(program, "u_Bounds") }
+                .expect("Get bounds location");
+
+        let texture_location =
+            unsafe { gl.get_uniform_location(program, "u_Texture") }
+                .expect("Get texture location");
+
+        unsafe {
+            gl.use_program(Some(program));
+            gl.uniform_1_i32(Some(&texture_location), 0);
+            gl.use_program(None);
+        }
+
+        let vertex_array =
+            unsafe { gl.create_vertex_array().expect("Create vertex array") };
+
+        Pipeline {
+            program,
+            vertex_array,
+            transform_location,
+            bounds_location,
+            targets: Vec::new(),
+            next_target: 0,
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        gl: &glow::Context,
+        target_size: Size<u32>,
+        shaders: &[layer::Shader<'_>],
+        transformation: Transformation,
+        scale_factor: f32,
+    ) {
+        for shader in shaders {
+            let primitive = match shader
+                .primitive
+                .downcast_ref::<Box<dyn shader::Primitive>>()
+            {
+                Some(primitive) => primitive,
+                None => continue,
+            };
+
+            let clip_bounds = (shader.clip_bounds * scale_factor).snap();
+
+            let size = Size::new(
+                (shader.bounds.width * scale_factor).round() as u32,
+                (shader.bounds.height * scale_factor).round() as u32,
+            );
+
+            if size.width < 1
+                || size.height < 1
+                || clip_bounds.width < 1
+                || clip_bounds.height < 1
+            {
+                continue;
+            }
+
+            let index = self.prepare_target(gl, size);
+            let offscreen = &self.targets[index];
+
+            unsafe {
+                gl.bind_framebuffer(
+                    glow::FRAMEBUFFER,
+                    Some(offscreen.framebuffer),
+                );
+                gl.viewport(0, 0, size.width as i32, size.height as i32);
+            }
+
+            primitive.render(shader::Target {
+                gl,
+                framebuffer: offscreen.framebuffer,
+                size,
+                scale_factor,
+            });
+
+            unsafe {
+                gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+                gl.viewport(
+                    0,
+                    0,
+                    target_size.width as i32,
+                    target_size.height as i32,
+                );
+
+                gl.enable(glow::BLEND);
+                gl.blend_func(glow::SRC_ALPHA, glow::ONE_MINUS_SRC_ALPHA);
+
+                gl.enable(glow::SCISSOR_TEST);
+                gl.scissor(
+                    clip_bounds.x as i32,
+                    target_size.height as i32
+                        - (clip_bounds.y + clip_bounds.height) as i32,
+                    clip_bounds.width as i32,
+                    clip_bounds.height as i32,
+                );
+
+                gl.use_program(Some(self.program));
+                gl.bind_vertex_array(Some(self.vertex_array));
+
+                let matrix: [f32; 16] = transformation.into();
+                gl.uniform_matrix_4_f32_slice(
+                    Some(&self.transform_location),
+                    false,
+                    &matrix,
+                );
+
+                gl.uniform_4_f32(
+                    Some(&self.bounds_location),
+                    shader.bounds.x,
+                    shader.bounds.y,
+                    shader.bounds.width,
+                    shader.bounds.height,
+                );
+
+                gl.active_texture(glow::TEXTURE0);
+                gl.bind_texture(glow::TEXTURE_2D, Some(offscreen.texture));
+
+                gl.draw_arrays(glow::TRIANGLE_STRIP, 0, 4);
+
+                gl.bind_texture(glow::TEXTURE_2D, None);
+                gl.bind_vertex_array(None);
+                gl.use_program(None);
+                gl.disable(glow::SCISSOR_TEST);
+            }
+        }
+    }
+
+    pub fn trim(&mut self, gl: &glow::Context) {
+        for target in self.targets.drain(self.next_target..) {
+            target.destroy(gl);
+        }
+
+        self.next_target = 0;
+    }
+
+    fn prepare_target(&mut self, gl: &glow::Context, size: Size<u32>) -> usize {
+        let index = self.next_target;
+
+        match self.targets.get_mut(index) {
+            Some(target) if target.size != size => {
+                let outdated = std::mem::replace(target, Target::new(gl, size));
+
+                outdated.destroy(gl);
+            }
+            Some(_) => {}
+            None => {
+                self.targets.push(Target::new(gl, size));
+            }
+        }
+
+        self.next_target += 1;
+
+        index
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    framebuffer: <glow::Context as HasContext>::Framebuffer,
+    texture: <glow::Context as HasContext>::Texture,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(gl: &glow::Context, size: Size<u32>) -> Target {
+        unsafe {
+            let texture = gl.create_texture().expect("Create texture");
+
+            gl.bind_texture(glow::TEXTURE_2D, Some(texture));
+            gl.tex_image_2d(
+                glow::TEXTURE_2D,
+                0,
+                glow::SRGB8_ALPHA8 as i32,
+                size.width as i32,
+                size.height as i32,
+                0,
+                glow::RGBA,
+                glow::UNSIGNED_BYTE,
+                None,
+            );
+
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MIN_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.tex_parameter_i32(
+                glow::TEXTURE_2D,
+                glow::TEXTURE_MAG_FILTER,
+                glow::NEAREST as i32,
+            );
+            gl.bind_texture(glow::TEXTURE_2D, None);
+
+            let framebuffer =
+                gl.create_framebuffer().expect("Create framebuffer");
+
+            gl.bind_framebuffer(glow::FRAMEBUFFER, Some(framebuffer));
+            gl.framebuffer_texture_2d(
+                glow::FRAMEBUFFER,
+                glow::COLOR_ATTACHMENT0,
+                glow::TEXTURE_2D,
+                Some(texture),
+                0,
+            );
+            gl.bind_framebuffer(glow::FRAMEBUFFER, None);
+
+            Target {
+                framebuffer,
+                texture,
+                size,
+            }
+        }
+    }
+
+    fn destroy(self, gl: &glow::Context) {
+        unsafe {
+            gl.delete_framebuffer(self.framebuffer);
+            gl.delete_texture(self.texture);
+        }
+    }
+}
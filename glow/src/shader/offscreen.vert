@@ -0,0 +1,23 @@
+#version 330
+
+uniform mat4 u_Transform;
+uniform vec4 u_Bounds;
+
+out vec2 v_TexCoord;
+
+const vec2 positions[4] = vec2[](
+    vec2(0.0, 0.0),
+    vec2(0.0, 1.0),
+    vec2(1.0, 0.0),
+    vec2(1.0, 1.0)
+);
+
+void main() {
+    vec2 q_Pos = positions[gl_VertexID];
+
+    // Framebuffer textures start at the bottom left corner
+    v_TexCoord = vec2(q_Pos.x, 1.0 - q_Pos.y);
+
+    gl_Position = u_Transform
+        * vec4(u_Bounds.xy + q_Pos * u_Bounds.zw, 0.0, 1.0);
+}
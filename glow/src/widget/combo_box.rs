@@ -0,0 +1,14 @@
+//! Display a text input with a list of suggestions to choose from.
+//!
+//! A [`ComboBox`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::combo_box::StyleSheet;
+pub use iced_native::combo_box::State;
+
+/// A text input with a list of suggestions to choose from.
+///
+/// This is an alias of an `iced_native` combo box with an
+/// `iced_glow::Renderer`.
+pub type ComboBox<'a, T, Message> =
+    iced_native::ComboBox<'a, T, Message, Renderer>;
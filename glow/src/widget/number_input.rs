@@ -0,0 +1,14 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::number_input::{Style, StyleSheet};
+pub use iced_native::number_input::State;
+
+/// A field that can be filled with numbers.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_glow::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::NumberInput<'a, T, Message, Renderer>;
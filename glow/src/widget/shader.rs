@@ -0,0 +1,267 @@
+//! Render custom content with [`glow`] inside of your user interface.
+//!
+//! A [`Shader`] widget lets a [`Program`] draw freely into an offscreen
+//! framebuffer using the [`glow::Context`] of the renderer.
+//! The resulting texture is then composited with the rest of the user
+//! interface like any other primitive. Therefore, it can be clipped,
+//! scrolled, and placed inside of a pane.
+//!
+//! [`glow`]: https://github.com/grovesNL/glow
+use crate::Renderer;
+use iced_graphics::Defaults;
+
+use glow::HasContext;
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A widget that renders custom [`glow`] content in an offscreen texture.
+///
+/// [`glow`]: https://github.com/grovesNL/glow
+#[derive(Debug)]
+pub struct Shader<Message, P: Program<Message>> {
+    width: Length,
+    height: Length,
+    program: P,
+    phantom: PhantomData<Message>,
+}
+
+impl<Message, P: Program<Message>> Shader<Message, P> {
+    const DEFAULT_SIZE: u16 = 100;
+
+    /// Creates a new [`Shader`] with the given [`Program`].
+    pub fn new(program: P) -> Self {
+        Shader {
+            width: Length::Units(Self::DEFAULT_SIZE),
+            height: Length::Units(Self::DEFAULT_SIZE),
+            program,
+            phantom: PhantomData,
+        }
+    }
+
+    /// Sets the width of the [`Shader`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Shader`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+}
+
+impl<Message, P> Widget<Message, Renderer> for Shader<Message, P>
+where
+    P: Program<Message>,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        let (event_status, message) =
+            self.program.update(event, layout.bounds(), cursor_position);
+
+        if let Some(message) = message {
+            messages.push(message);
+        }
+
+        event_status
+    }
+
+    fn draw(
+        &self,
+        _renderer: &mut Renderer,
+        _defaults: &Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> (iced_graphics::Primitive, mouse::Interaction) {
+        let bounds = layout.bounds();
+        let primitive: Box<dyn Primitive> =
+            Box::new(self.program.draw(bounds, cursor_position));
+
+        (
+            iced_graphics::Primitive::Shader {
+                bounds,
+                primitive: Arc::new(primitive),
+            },
+            self.program.mouse_interaction(bounds, cursor_position),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, P> From<Shader<Message, P>> for Element<'a, Message, Renderer>
+where
+    Message: 'static,
+    P: Program<Message> + 'a,
+{
+    fn from(shader: Shader<Message, P>) -> Element<'a, Message, Renderer> {
+        Element::new(shader)
+    }
+}
+
+/// The state and logic of a [`Shader`].
+///
+/// A [`Program`] can mutate internal state and produce messages for an
+/// application. Every time the user interface is drawn, it produces a
+/// [`Primitive`] that will be rendered by the GPU.
+///
+/// [`Primitive`]: Program::Primitive
+pub trait Program<Message> {
+    /// The custom [`Primitive`](self::Primitive) produced by the [`Program`].
+    type Primitive: self::Primitive;
+
+    /// Updates the state of the [`Program`].
+    ///
+    /// When a [`Program`] is used in a [`Shader`], the runtime will call this
+    /// method for each [`Event`].
+    ///
+    /// This method can optionally return a `Message` to notify an
+    /// application of any meaningful interactions.
+    ///
+    /// By default, this method does and returns nothing.
+    fn update(
+        &mut self,
+        _event: Event,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> (event::Status, Option<Message>) {
+        (event::Status::Ignored, None)
+    }
+
+    /// Produces the [`Primitive`] to render in the given bounds.
+    ///
+    /// [`Primitive`]: Program::Primitive
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Self::Primitive;
+
+    /// Returns the current mouse interaction of the [`Program`].
+    ///
+    /// The interaction returned will be in effect even if the cursor position
+    /// is out of bounds of the program's [`Shader`].
+    fn mouse_interaction(
+        &self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+    ) -> mouse::Interaction {
+        mouse::Interaction::default()
+    }
+}
+
+impl<T, Message> Program<Message> for &mut T
+where
+    T: Program<Message>,
+{
+    type Primitive = T::Primitive;
+
+    fn update(
+        &mut self,
+        event: Event,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> (event::Status, Option<Message>) {
+        T::update(self, event, bounds, cursor_position)
+    }
+
+    fn draw(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> Self::Primitive {
+        T::draw(self, bounds, cursor_position)
+    }
+
+    fn mouse_interaction(
+        &self,
+        bounds: Rectangle,
+        cursor_position: Point,
+    ) -> mouse::Interaction {
+        T::mouse_interaction(self, bounds, cursor_position)
+    }
+}
+
+/// The GPU work of a [`Shader`] for a single frame.
+///
+/// A [`Primitive`] is rendered in its own texture right before being
+/// composited. It should only contain the data necessary to render the
+/// frame. Any long-lived GPU resources, like
+/// pipelines or buffers, can be shared with the [`Program`] that produces it.
+pub trait Primitive: fmt::Debug + Send + Sync + 'static {
+    /// Renders the [`Primitive`] in the given [`Target`].
+    fn render(&self, target: Target<'_>);
+}
+
+/// The offscreen texture where a [`Primitive`] is rendered.
+///
+/// The framebuffer of the texture is bound and the viewport covers it entirely
+/// when [`Primitive::render`] is called. The renderer restores the framebuffer,
+/// viewport, scissor, and blending state it relies on afterwards.
+pub struct Target<'a> {
+    /// The [`glow::Context`] of the renderer.
+    pub gl: &'a glow::Context,
+
+    /// The framebuffer of the offscreen texture.
+    ///
+    /// Its contents are undefined. You should clear it before drawing.
+    pub framebuffer: <glow::Context as HasContext>::Framebuffer,
+
+    /// The size of the offscreen texture, in physical pixels.
+    pub size: Size<u32>,
+
+    /// The scale factor of the window.
+    pub scale_factor: f32,
+}
+
+impl<'a> fmt::Debug for Target<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Target")
+            .field("framebuffer", &self.framebuffer)
+            .field("size", &self.size)
+            .field("scale_factor", &self.scale_factor)
+            .finish()
+    }
+}
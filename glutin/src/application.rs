@@ -4,14 +4,19 @@ use crate::{mouse, Error, Executor, Runtime};
 pub use iced_winit::Application;
 
 use iced_graphics::window;
+use iced_graphics::Themed;
+use iced_winit::appearance;
 use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
 use iced_winit::futures::channel::mpsc;
-use iced_winit::{Cache, Clipboard, Debug, Proxy, Settings};
+use iced_winit::{Cache, Clipboard, Command, Debug, Proxy, Settings};
 
 use glutin::window::Window;
+use std::cell::Cell;
 use std::mem::ManuallyDrop;
+use std::rc::Rc;
+use std::time::Instant;
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
@@ -23,6 +28,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: application::inspector::Renderer + Themed,
 {
     use futures::task;
     use futures::Future;
@@ -33,7 +39,7 @@ where
     debug.startup_started();
 
     let event_loop = EventLoop::with_user_event();
-    let mut runtime = {
+    let runtime = {
         let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
         let proxy = Proxy::new(event_loop.create_proxy());
 
@@ -46,11 +52,6 @@ where
         runtime.enter(|| A::new(flags))
     };
 
-    let subscription = application.subscription();
-
-    runtime.spawn(init_command);
-    runtime.track(subscription);
-
     let context = {
         let builder = settings.window.into_builder(
             &application.title(),
@@ -88,14 +89,20 @@ where
 
     let (mut sender, receiver) = mpsc::unbounded();
 
+    // The first frame is drawn right away
+    let next_frame = Rc::new(Cell::new(Some(Instant::now())));
+
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
         compositor,
         renderer,
         runtime,
+        event_loop.create_proxy(),
         debug,
         receiver,
         context,
+        next_frame.clone(),
+        init_command,
         settings.exit_on_close_request,
     ));
 
@@ -129,7 +136,10 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => match next_frame.get() {
+                    Some(at) => ControlFlow::WaitUntil(at),
+                    None => ControlFlow::Wait,
+                },
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -141,22 +151,42 @@ async fn run_instance<A, E, C>(
     mut compositor: C,
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
     context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
+    next_frame: Rc<Cell<Option<Instant>>>,
+    init_command: Command<A::Message>,
     exit_on_close_request: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::GLCompositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: application::inspector::Renderer + Themed,
 {
     use glutin::event;
     use iced_winit::futures::stream::StreamExt;
 
     let mut clipboard = Clipboard::connect(context.window());
 
+    let mut operations = Vec::new();
+
+    application::run_command(
+        init_command,
+        &mut runtime,
+        &mut proxy,
+        &mut operations,
+        context.window(),
+    );
+    runtime.track(application.subscription());
+
     let mut state = application::State::new(&application, context.window());
     let mut viewport_version = state.viewport_version();
+    let mut time_travel = application::TimeTravel::new(&application);
+    let can_time_travel = time_travel.is_enabled();
+    let mut is_traveling = false;
+    debug.time_travel(time_travel.overlay());
+
     let mut user_interface =
         ManuallyDrop::new(application::build_user_interface(
             &mut application,
@@ -166,27 +196,84 @@ async fn run_instance<A, E, C>(
             &mut debug,
         ));
 
-    let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+    application::run_operations(
+        &mut user_interface,
+        &mut operations,
+        &mut proxy,
+    );
+
+    let mut inspector = application::Inspector::new();
+    let mut primitive = inspector.draw(
+        &mut user_interface,
+        &mut renderer,
+        state.cursor_position(),
+        &mut debug,
+    );
     let mut mouse_interaction = mouse::Interaction::default();
 
-    let mut events = Vec::new();
+    let mut events: Vec<_> = appearance::window_theme(context.window())
+        .map(|theme| {
+            iced_native::Event::Window(
+                iced_native::window::Event::ThemeChanged(theme),
+            )
+        })
+        .into_iter()
+        .collect();
     let mut messages = Vec::new();
 
+    #[cfg(feature = "recording")]
+    let mut session =
+        iced_winit::recording::Session::from_env(context.window());
+
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                #[cfg(feature = "recording")]
+                session.replay(context.window(), &mut events);
+
+                // Earlier states are frozen while traveling back in time
+                if is_traveling {
+                    events.clear();
+                }
+
+                let now = Instant::now();
+
+                let is_frame_late = match next_frame.get() {
+                    Some(at) => at <= now,
+                    None => false,
+                };
+
+                // Nothing is drawn while the window is minimized, and earlier
+                // states do not animate
+                let can_draw_frames = !state.is_minimized() && !is_traveling;
+
+                if is_frame_late && !can_draw_frames {
+                    next_frame.set(None);
+                }
+
+                let mut is_frame_due = is_frame_late && can_draw_frames;
+
+                if events.is_empty() && messages.is_empty() && !is_frame_due {
                     continue;
                 }
 
+                #[cfg(feature = "recording")]
+                session.record(&events);
+
+                #[cfg(feature = "recording")]
+                let cursor_position =
+                    session.cursor_position(state.cursor_position());
+
+                #[cfg(not(feature = "recording"))]
+                let cursor_position = state.cursor_position();
+
                 debug.event_processing_started();
 
                 let statuses = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    cursor_position,
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -206,9 +293,13 @@ async fn run_instance<A, E, C>(
                     application::update(
                         &mut application,
                         &mut runtime,
+                        &mut proxy,
                         &mut debug,
                         &mut clipboard,
                         &mut messages,
+                        &mut operations,
+                        &mut time_travel,
+                        context.window(),
                     );
 
                     // Update window
@@ -218,21 +309,79 @@ async fn run_instance<A, E, C>(
 
                     user_interface =
                         ManuallyDrop::new(application::build_user_interface(
-                            &mut application,
+                            time_travel.state(&mut application),
                             cache,
                             &mut renderer,
                             state.logical_size(),
                             &mut debug,
                         ));
 
+                    application::run_operations(
+                        &mut user_interface,
+                        &mut operations,
+                        &mut proxy,
+                    );
+
                     if should_exit {
                         break;
                     }
+
+                    // The new state of the application is drawn right away
+                    is_frame_due = can_draw_frames;
+                }
+
+                // Widgets and subscriptions are notified of the new frame
+                // right before it is drawn
+                let status = if is_frame_due {
+                    let frame = iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    );
+
+                    let status = user_interface.update(
+                        std::slice::from_ref(&frame),
+                        cursor_position,
+                        &renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    )[0];
+
+                    runtime.broadcast((frame, status));
+
+                    status
+                } else {
+                    iced_native::event::Status::Ignored
+                };
+
+                next_frame.set(if is_traveling {
+                    None
+                } else {
+                    application::schedule_frame(
+                        &mut user_interface,
+                        status,
+                        &messages,
+                        now,
+                        state.is_minimized(),
+                    )
+                });
+
+                // Wake up again when the next recorded events are due
+                #[cfg(feature = "recording")]
+                {
+                    if let Some(at) = session.next_replay() {
+                        next_frame.set(Some(match next_frame.get() {
+                            Some(next) => next.min(at),
+                            None => at,
+                        }));
+                    }
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = inspector.draw(
+                    &mut user_interface,
+                    &mut renderer,
+                    state.cursor_position(),
+                    &mut debug,
+                );
                 debug.draw_finished();
 
                 context.window().request_redraw();
@@ -266,8 +415,12 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
                     debug.draw_finished();
 
                     context.resize(glutin::dpi::PhysicalSize::new(
@@ -299,9 +452,6 @@ async fn run_instance<A, E, C>(
 
                     mouse_interaction = new_mouse_interaction;
                 }
-
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -313,8 +463,96 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                let step = if can_time_travel && debug.is_enabled() {
+                    application::time_travel::Step::from_window_event(
+                        &window_event,
+                    )
+                } else {
+                    None
+                };
+
+                if let Some(step) = step {
+                    let cache =
+                        ManuallyDrop::into_inner(user_interface).into_cache();
+
+                    time_travel.step(step);
+                    is_traveling = time_travel.is_traveling();
+                    debug.time_travel(time_travel.overlay());
+
+                    // Animations resume once back in the present
+                    next_frame.set(if is_traveling {
+                        None
+                    } else {
+                        Some(Instant::now())
+                    });
+
+                    user_interface =
+                        ManuallyDrop::new(application::build_user_interface(
+                            time_travel.state(&mut application),
+                            cache,
+                            &mut renderer,
+                            state.logical_size(),
+                            &mut debug,
+                        ));
+
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
+
+                    context.window().request_redraw();
+
+                    continue;
+                }
+
+                let scale_factor = state.scale_factor();
+                let was_minimized = state.is_minimized();
+
                 state.update(context.window(), &window_event, &mut debug);
 
+                // `winit` only reports minimization as an empty resize
+                if state.is_minimized() != was_minimized {
+                    events.extend_from_slice(&conversion::minimized(
+                        state.is_minimized(),
+                    ));
+
+                    // Animations resume as soon as the window is restored
+                    if !state.is_minimized() {
+                        next_frame.set(Some(Instant::now()));
+                    }
+                }
+
+                // `ScaleFactorChanged` is turned into a `Resized` event before
+                // reaching us, so we notice the change through the viewport
+                if state.scale_factor() != scale_factor {
+                    events.push(crate::Event::Window(
+                        iced_native::window::Event::ScaleFactorChanged(
+                            context.window().scale_factor(),
+                        ),
+                    ));
+                }
+
+                // The inspector captures any input while enabled
+                if inspector.update(
+                    &window_event,
+                    state.cursor_position(),
+                    user_interface.layout(),
+                    debug.is_enabled(),
+                ) {
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
+
+                    context.window().request_redraw();
+
+                    continue;
+                }
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
@@ -327,6 +565,9 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    #[cfg(feature = "recording")]
+    session.finish();
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
@@ -16,8 +16,9 @@ pub use iced_native::*;
 
 pub mod application;
 
+pub use iced_winit::appearance;
 pub use iced_winit::settings;
-pub use iced_winit::{Clipboard, Error, Mode};
+pub use iced_winit::{Clipboard, Error, Mode, Theme};
 
 #[doc(no_inline)]
 pub use application::Application;
@@ -49,6 +49,13 @@ pub trait Text {
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the decoded frames of the provided animated image.
+    ///
+    /// By default, the image is treated as a still with a single frame.
+    fn frames(&self, handle: &image::Handle) -> image::animated::Frames {
+        image::animated::Frames::from(handle.clone())
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
@@ -7,6 +7,8 @@ use crate::{
     Vector, VerticalAlignment, Viewport,
 };
 
+use std::any::Any;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
 pub struct Layer<'a> {
@@ -24,6 +26,9 @@ pub struct Layer<'a> {
 
     /// The images of the [`Layer`].
     pub images: Vec<Image>,
+
+    /// The custom shaders of the [`Layer`].
+    pub shaders: Vec<Shader<'a>>,
 }
 
 impl<'a> Layer<'a> {
@@ -35,6 +40,7 @@ impl<'a> Layer<'a> {
             meshes: Vec::new(),
             text: Vec::new(),
             images: Vec::new(),
+            shaders: Vec::new(),
         }
     }
 
@@ -173,6 +179,19 @@ impl<'a> Layer<'a> {
                     });
                 }
             }
+            Primitive::Shader { bounds, primitive } => {
+                let layer = &mut layers[current_layer];
+                let bounds = *bounds + translation;
+
+                // Only draw visible content
+                if let Some(clip_bounds) = layer.bounds.intersection(&bounds) {
+                    layer.shaders.push(Shader {
+                        bounds,
+                        clip_bounds,
+                        primitive: primitive.as_ref(),
+                    });
+                }
+            }
             Primitive::Clip {
                 bounds,
                 offset,
@@ -216,12 +235,19 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                bounds,
+                filter_method,
+                source,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
                     bounds: *bounds + translation,
+                    filter_method: *filter_method,
+                    source: *source,
                 });
             }
             Primitive::Svg { handle, bounds } => {
@@ -274,6 +300,19 @@ pub struct Mesh<'a> {
     pub clip_bounds: Rectangle<f32>,
 }
 
+/// A custom primitive rendered by the backend in an offscreen texture.
+#[derive(Debug, Clone, Copy)]
+pub struct Shader<'a> {
+    /// The bounds of the offscreen texture of the [`Shader`].
+    pub bounds: Rectangle,
+
+    /// The clipping bounds of the [`Shader`].
+    pub clip_bounds: Rectangle,
+
+    /// The backend-specific contents of the [`Shader`].
+    pub primitive: &'a (dyn Any + Send + Sync),
+}
+
 /// A paragraph of text.
 #[derive(Debug, Clone, Copy)]
 pub struct Text<'a> {
@@ -309,6 +348,12 @@ pub enum Image {
 
         /// The bounds of the image.
         bounds: Rectangle,
+
+        /// The filter method used to sample the image.
+        filter_method: image::FilterMethod,
+
+        /// The region of the image to draw, in pixels, if any.
+        source: Option<Rectangle>,
     },
     /// A vector image.
     Vector {
@@ -22,6 +22,12 @@ pub mod defaults;
 pub mod font;
 pub mod layer;
 pub mod overlay;
+#[cfg(feature = "image_rs")]
+#[cfg_attr(docsrs, doc(cfg(feature = "image_rs")))]
+pub mod raster;
+#[cfg(feature = "sheet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sheet")))]
+pub mod sheet;
 pub mod triangle;
 pub mod widget;
 pub mod window;
@@ -35,10 +41,12 @@ pub use defaults::Defaults;
 pub use error::Error;
 pub use layer::Layer;
 pub use primitive::Primitive;
-pub use renderer::Renderer;
+pub use renderer::{Renderer, Themed};
 pub use transformation::Transformation;
 pub use viewport::Viewport;
 
+pub use iced_style::Theme;
+
 pub use iced_native::{
     Background, Color, Font, HorizontalAlignment, Point, Rectangle, Size,
     Vector, VerticalAlignment,
@@ -1,26 +1,28 @@
 //! Build and show dropdown menus.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Themed};
 use iced_native::{
     mouse, overlay, Color, Font, HorizontalAlignment, Padding, Point,
     Rectangle, VerticalAlignment,
 };
 
-pub use iced_style::menu::Style;
+pub use iced_style::menu::{Style, StyleSheet};
 
 impl<B> overlay::menu::Renderer for Renderer<B>
 where
     B: Backend + backend::Text,
 {
-    type Style = Style;
+    type Style = Box<dyn StyleSheet>;
 
     fn decorate(
         &mut self,
         bounds: Rectangle,
         _cursor_position: Point,
-        style: &Style,
+        style_sheet: &Box<dyn StyleSheet>,
         (primitives, mouse_cursor): Self::Output,
     ) -> Self::Output {
+        let style = style_sheet.style(self.theme());
+
         (
             Primitive::Group {
                 primitives: vec![
@@ -48,10 +50,12 @@ where
         padding: Padding,
         text_size: u16,
         font: Font,
-        style: &Style,
+        style_sheet: &Box<dyn StyleSheet>,
     ) -> Self::Output {
         use std::f32;
 
+        let style = style_sheet.style(self.theme());
+
         let is_mouse_over = bounds.contains(cursor_position);
         let option_height = (text_size + padding.vertical()) as usize;
 
@@ -4,6 +4,7 @@ use iced_native::{
 };
 
 use crate::triangle;
+use std::any::Any;
 use std::sync::Arc;
 
 /// A rendering primitive.
@@ -52,6 +53,11 @@ pub enum Primitive {
         handle: image::Handle,
         /// The bounds of the image
         bounds: Rectangle,
+        /// The filter method used to sample the image
+        filter_method: image::FilterMethod,
+        /// The region of the image to draw, in pixels. The whole image is
+        /// drawn if `None`.
+        source: Option<Rectangle>,
     },
     /// An SVG primitive
     Svg {
@@ -90,6 +96,17 @@ pub enum Primitive {
         /// Any geometry that falls out of this region will be clipped.
         size: Size,
     },
+    /// A custom primitive rendered by the backend in an offscreen texture.
+    ///
+    /// The texture is composited like any other primitive. Backends that do
+    /// not understand the primitive will ignore it.
+    Shader {
+        /// The bounds of the offscreen texture
+        bounds: Rectangle,
+
+        /// The backend-specific contents of the primitive
+        primitive: Arc<dyn Any + Send + Sync>,
+    },
     /// A cached primitive.
     ///
     /// This can be useful if you are implementing a widget where primitive
@@ -0,0 +1,123 @@
+//! Decode raster images.
+use iced_native::image::{self, animated};
+
+/// A decoded raster image, with its pixels in BGRA order.
+pub type Image = image_rs::ImageBuffer<image_rs::Bgra<u8>, Vec<u8>>;
+
+/// An error produced when decoding a raster image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The image file could not be found.
+    NotFound,
+
+    /// The image data is invalid or its format is not supported.
+    Invalid,
+}
+
+/// Decodes the first frame of the image with the given [`Handle`].
+///
+/// [`Handle`]: image::Handle
+pub fn load(handle: &image::Handle) -> Result<Image, Error> {
+    match handle.data() {
+        image::Data::Path(path) => image_rs::open(path)
+            .map(|image| image.to_bgra8())
+            .map_err(|_| Error::NotFound),
+        image::Data::Bytes(bytes) => image_rs::load_from_memory(bytes)
+            .map(|image| image.to_bgra8())
+            .map_err(|_| Error::Invalid),
+        image::Data::Pixels {
+            width,
+            height,
+            pixels,
+        } => image_rs::ImageBuffer::from_vec(*width, *height, pixels.to_vec())
+            .ok_or(Error::Invalid),
+        image::Data::Texture(texture) => image_rs::ImageBuffer::from_vec(
+            texture.width(),
+            texture.height(),
+            texture.snapshot(),
+        )
+        .ok_or(Error::Invalid),
+    }
+}
+
+/// Decodes all the frames of the animated image with the given [`Handle`].
+///
+/// GIF and APNG animations are supported. Any other image is decoded as a
+/// single frame referencing the original [`Handle`].
+///
+/// [`Handle`]: image::Handle
+pub fn load_frames(handle: &image::Handle) -> Result<animated::Frames, Error> {
+    let bytes = match handle.data() {
+        image::Data::Path(path) => {
+            std::fs::read(path).map_err(|_| Error::NotFound)?
+        }
+        image::Data::Bytes(bytes) => bytes.to_vec(),
+        image::Data::Pixels { .. } | image::Data::Texture(_) => {
+            return Ok(animated::Frames::from(handle.clone()));
+        }
+    };
+
+    let frames = match image_rs::guess_format(&bytes) {
+        #[cfg(feature = "gif")]
+        Ok(image_rs::ImageFormat::Gif) => {
+            let decoder = image_rs::codecs::gif::GifDecoder::new(&bytes[..])
+                .map_err(|_| Error::Invalid)?;
+
+            decode(decoder)?
+        }
+        #[cfg(feature = "png")]
+        Ok(image_rs::ImageFormat::Png) => {
+            let decoder = image_rs::codecs::png::PngDecoder::new(&bytes[..])
+                .map_err(|_| Error::Invalid)?;
+
+            if decoder.is_apng() {
+                decode(decoder.apng())?
+            } else {
+                Vec::new()
+            }
+        }
+        _ => Vec::new(),
+    };
+
+    if frames.is_empty() {
+        Ok(animated::Frames::from(handle.clone()))
+    } else {
+        Ok(animated::Frames::new(frames))
+    }
+}
+
+#[cfg(any(feature = "gif", feature = "png"))]
+fn decode<'a>(
+    decoder: impl image_rs::AnimationDecoder<'a>,
+) -> Result<Vec<animated::Frame>, Error> {
+    decoder
+        .into_frames()
+        .map(|frame| {
+            let frame = frame.map_err(|_| Error::Invalid)?;
+            let (numerator, denominator) = frame.delay().numer_denom_ms();
+
+            let delay = if denominator == 0 {
+                std::time::Duration::from_secs(0)
+            } else {
+                std::time::Duration::from_micros(
+                    u64::from(numerator) * 1_000 / u64::from(denominator),
+                )
+            };
+
+            let buffer = frame.into_buffer();
+            let (width, height) = buffer.dimensions();
+
+            let mut pixels = buffer.into_raw();
+
+            // RGBA -> BGRA
+            for pixel in pixels.chunks_exact_mut(4) {
+                pixel.swap(0, 2);
+            }
+
+            Ok(animated::Frame {
+                handle: image::Handle::from_pixels(width, height, pixels),
+                delay,
+            })
+        })
+        .collect()
+}
@@ -1,4 +1,5 @@
-use crate::{Backend, Defaults, Primitive};
+use crate::defaults::{self, Defaults};
+use crate::{Backend, Primitive, Theme};
 use iced_native::layout::{self, Layout};
 use iced_native::mouse;
 use iced_native::{
@@ -9,12 +10,16 @@ use iced_native::{
 #[derive(Debug)]
 pub struct Renderer<B: Backend> {
     backend: B,
+    theme: Theme,
 }
 
 impl<B: Backend> Renderer<B> {
     /// Creates a new [`Renderer`] from the given [`Backend`].
     pub fn new(backend: B) -> Self {
-        Self { backend }
+        Self {
+            backend,
+            theme: Theme::default(),
+        }
     }
 
     /// Returns a reference to the [`Backend`] of the [`Renderer`].
@@ -28,6 +33,25 @@ impl<B: Backend> Renderer<B> {
     }
 }
 
+/// A renderer that draws the built-in widgets using a [`Theme`].
+pub trait Themed {
+    /// Returns the current [`Theme`] of the renderer.
+    fn theme(&self) -> &Theme;
+
+    /// Sets the [`Theme`] used to draw the built-in widgets.
+    fn set_theme(&mut self, theme: Theme);
+}
+
+impl<B: Backend> Themed for Renderer<B> {
+    fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+    }
+}
+
 impl<B> iced_native::Renderer for Renderer<B>
 where
     B: Backend,
@@ -35,6 +59,14 @@ where
     type Output = (Primitive, mouse::Interaction);
     type Defaults = Defaults;
 
+    fn defaults(&self) -> Defaults {
+        Defaults {
+            text: defaults::Text {
+                color: self.theme.palette().text,
+            },
+        }
+    }
+
     fn layout<'a, Message>(
         &mut self,
         element: &Element<'a, Message, Self>,
@@ -100,6 +132,41 @@ where
 
         (Primitive::Group { primitives }, cursor)
     }
+
+    fn highlight(
+        &mut self,
+        (primitive, cursor): (Primitive, mouse::Interaction),
+        bounds: Rectangle,
+        color: Color,
+    ) -> Self::Output {
+        (
+            Primitive::Group {
+                primitives: vec![
+                    primitive,
+                    Primitive::Clip {
+                        bounds: Rectangle {
+                            x: bounds.x - 1.0,
+                            y: bounds.y - 1.0,
+                            width: bounds.width + 2.0,
+                            height: bounds.height + 2.0,
+                        },
+                        offset: Vector::new(0, 0),
+                        content: Box::new(Primitive::Quad {
+                            bounds,
+                            background: Background::Color(Color {
+                                a: color.a * 0.2,
+                                ..color
+                            }),
+                            border_radius: 0.0,
+                            border_width: 1.0,
+                            border_color: color,
+                        }),
+                    },
+                ],
+            },
+            cursor,
+        )
+    }
 }
 
 fn explain_layout(
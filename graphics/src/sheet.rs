@@ -0,0 +1,67 @@
+//! Load style sheets from files and reload them when they change.
+pub use iced_style::sheet::*;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use watch::watch;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod watch {
+    use super::{Error, Sheet};
+
+    use iced_native::futures::channel::mpsc;
+    use iced_native::futures::{SinkExt, StreamExt};
+    use iced_native::Subscription;
+
+    use std::fs;
+    use std::path::PathBuf;
+    use std::thread;
+    use std::time::Duration;
+
+    const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Returns a [`Subscription`] that loads the [`Sheet`] at the given path
+    /// on startup and every time the file is modified.
+    ///
+    /// Every new [`Sheet`] produces a message, which means the application
+    /// will be updated and redrawn with the new styles.
+    pub fn watch(
+        path: impl Into<PathBuf>,
+    ) -> Subscription<Result<Sheet, Error>> {
+        let path = path.into();
+
+        Subscription::channel(path.clone(), 1, move |mut output| async move {
+            let (sender, mut receiver) = mpsc::unbounded();
+
+            let _ = thread::Builder::new()
+                .name(String::from("iced_graphics sheet"))
+                .spawn(move || {
+                    let mut last_modified = None;
+
+                    while !sender.is_closed() {
+                        let modified = fs::metadata(&path)
+                            .and_then(|metadata| metadata.modified())
+                            .ok();
+
+                        if last_modified != Some(modified) {
+                            last_modified = Some(modified);
+
+                            if sender
+                                .unbounded_send(Sheet::load(&path))
+                                .is_err()
+                            {
+                                break;
+                            }
+                        }
+
+                        thread::sleep(POLL_INTERVAL);
+                    }
+                });
+
+            while let Some(result) = receiver.next().await {
+                if output.send(result).await.is_err() {
+                    break;
+                }
+            }
+        })
+    }
+}
@@ -9,8 +9,10 @@
 //! ```
 pub mod button;
 pub mod checkbox;
+pub mod combo_box;
 pub mod container;
 pub mod image;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -33,8 +35,12 @@ pub use button::Button;
 #[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -77,3 +83,95 @@ pub mod qr_code;
 #[cfg(feature = "qr_code")]
 #[doc(no_inline)]
 pub use qr_code::QRCode;
+
+#[cfg(test)]
+mod tests {
+    use crate::backend::{self, Backend};
+    use crate::{Font, Renderer};
+
+    use iced_native::layout::{self, Layout};
+    use iced_native::{button, pick_list, slider};
+    use iced_native::{mouse, Element, Point, Rectangle, Size};
+
+    /// A [`Backend`] that measures text without a font.
+    struct Headless;
+
+    impl Backend for Headless {}
+
+    impl backend::Text for Headless {
+        const ICON_FONT: Font = Font::Default;
+        const CHECKMARK_ICON: char = '✔';
+        const ARROW_DOWN_ICON: char = '▼';
+
+        fn default_size(&self) -> u16 {
+            20
+        }
+
+        fn measure(
+            &self,
+            contents: &str,
+            size: f32,
+            _font: Font,
+            _bounds: Size,
+        ) -> (f32, f32) {
+            (contents.chars().count() as f32 * size * 0.5, size)
+        }
+    }
+
+    /// Returns the [`mouse::Interaction`] of the given widget while the
+    /// cursor is over its top left corner.
+    fn hover(
+        element: Element<'_, (), Renderer<Headless>>,
+    ) -> mouse::Interaction {
+        let mut renderer = Renderer::new(Headless);
+        let viewport = Rectangle::with_size(Size::new(400.0, 300.0));
+        let node = element.layout(
+            &renderer,
+            &layout::Limits::new(Size::ZERO, viewport.size()),
+        );
+        let defaults = iced_native::Renderer::defaults(&renderer);
+
+        let (_, interaction) = element.draw(
+            &mut renderer,
+            &defaults,
+            Layout::new(&node),
+            Point::new(1.0, 1.0),
+            &viewport,
+        );
+
+        interaction
+    }
+
+    #[test]
+    fn reports_disabled_widgets_as_not_allowed() {
+        let mut button = button::State::new();
+        let mut slider = slider::State::new();
+        let mut pick_list = pick_list::State::default();
+
+        let disabled: Vec<Element<'_, (), Renderer<Headless>>> = vec![
+            super::Button::new(&mut button, super::Text::new("Send"))
+                .on_press(())
+                .disabled(true)
+                .into(),
+            super::Checkbox::new(false, "Accept", |_| ())
+                .disabled(true)
+                .into(),
+            super::Radio::new(1, "One", None, |_| ())
+                .disabled(true)
+                .into(),
+            super::Slider::new(&mut slider, 0..=100, 50, |_| ())
+                .disabled(true)
+                .into(),
+            super::Toggler::new(false, String::from("Dark mode"), |_| ())
+                .disabled(true)
+                .into(),
+            super::PickList::new(&mut pick_list, &["A", "B"][..], None, |_| ())
+                .disabled(true)
+                .into(),
+        ];
+
+        for element in disabled {
+            assert_eq!(hover(element), mouse::Interaction::NotAllowed);
+        }
+    }
+}
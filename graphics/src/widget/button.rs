@@ -2,7 +2,7 @@
 //!
 //! A [`Button`] has some local [`State`].
 use crate::defaults::{self, Defaults};
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::{
     Background, Color, Element, Layout, Padding, Point, Rectangle, Vector,
@@ -39,15 +39,15 @@ where
         let is_mouse_over = bounds.contains(cursor_position);
 
         let styling = if is_disabled {
-            style.disabled()
+            style.disabled(self.theme())
         } else if is_mouse_over {
             if is_pressed {
-                style.pressed()
+                style.pressed(self.theme())
             } else {
-                style.hovered()
+                style.hovered(self.theme())
             }
         } else {
-            style.active()
+            style.active(self.theme())
         };
 
         let (content, _) = content.draw(
@@ -101,7 +101,9 @@ where
             } else {
                 content
             },
-            if is_mouse_over && !is_disabled {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
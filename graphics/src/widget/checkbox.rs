@@ -1,6 +1,6 @@
 //! Show toggle controls using checkboxes.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Themed};
 use iced_native::checkbox;
 use iced_native::mouse;
 use iced_native::{HorizontalAlignment, Rectangle, VerticalAlignment};
@@ -27,13 +27,16 @@ where
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered(is_checked)
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme(), is_checked)
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme(), is_checked)
         } else {
-            style_sheet.active(is_checked)
+            style_sheet.active(self.theme(), is_checked)
         };
 
         let checkbox = Primitive::Quad {
@@ -66,7 +69,9 @@ where
                     vec![checkbox, label]
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
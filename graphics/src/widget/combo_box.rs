@@ -0,0 +1,34 @@
+//! Display a text input with a list of suggestions to choose from.
+//!
+//! A [`ComboBox`] has some local [`State`].
+use crate::backend::{self, Backend};
+use crate::Renderer;
+use iced_native::combo_box;
+use iced_style::{menu, text_input};
+
+pub use iced_native::combo_box::State;
+pub use iced_style::combo_box::StyleSheet;
+
+/// A text input with a list of suggestions to choose from.
+///
+/// This is an alias of an `iced_native` combo box with an
+/// `iced_wgpu::Renderer`.
+pub type ComboBox<'a, T, Message, Backend> =
+    iced_native::ComboBox<'a, T, Message, Renderer<Backend>>;
+
+impl<B> combo_box::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn text_input_style(
+        style: &Box<dyn StyleSheet>,
+    ) -> Box<dyn text_input::StyleSheet> {
+        style.text_input()
+    }
+
+    fn menu_style(style: &Box<dyn StyleSheet>) -> Box<dyn menu::StyleSheet> {
+        style.menu()
+    }
+}
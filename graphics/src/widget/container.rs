@@ -1,7 +1,7 @@
 //! Decorate content and apply alignment.
 use crate::container;
 use crate::defaults::{self, Defaults};
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::{Background, Color, Element, Layout, Point, Rectangle};
 
 pub use iced_style::container::{Style, StyleSheet};
@@ -29,7 +29,7 @@ where
         content: &Element<'_, Message, Self>,
         content_layout: Layout<'_>,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let defaults = Defaults {
             text: defaults::Text {
@@ -1,4 +1,5 @@
 //! Display images in your user interface.
+pub mod animated;
 pub mod viewer;
 
 use crate::backend::{self, Backend};
@@ -6,9 +7,11 @@ use crate::backend::{self, Backend};
 use crate::{Primitive, Renderer};
 use iced_native::image;
 use iced_native::mouse;
-use iced_native::Layout;
+use iced_native::Rectangle;
 
-pub use iced_native::image::{Handle, Image, Viewer};
+pub use iced_native::image::{
+    AnimatedImage, FilterMethod, Handle, Image, Texture, Viewer,
+};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -21,12 +24,16 @@ where
     fn draw(
         &mut self,
         handle: image::Handle,
-        layout: Layout<'_>,
+        filter_method: image::FilterMethod,
+        source: Rectangle,
+        bounds: Rectangle,
     ) -> Self::Output {
         (
             Primitive::Image {
                 handle,
-                bounds: layout.bounds(),
+                bounds,
+                filter_method,
+                source: Some(source),
             },
             mouse::Interaction::default(),
         )
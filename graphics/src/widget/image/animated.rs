@@ -0,0 +1,17 @@
+//! Play animated images, like GIFs.
+use crate::backend::{self, Backend};
+use crate::Renderer;
+
+use iced_native::image;
+use iced_native::image::animated;
+
+pub use iced_native::image::animated::{Frame, Frames, State};
+
+impl<B> animated::Renderer for Renderer<B>
+where
+    B: Backend + backend::Image,
+{
+    fn frames(&self, handle: &image::Handle) -> animated::Frames {
+        self.backend().frames(handle)
+    }
+}
@@ -18,6 +18,8 @@ where
         image_size: Size,
         translation: Vector,
         handle: image::Handle,
+        filter_method: image::FilterMethod,
+        source: Rectangle,
         is_mouse_over: bool,
     ) -> Self::Output {
         (
@@ -33,6 +35,8 @@ where
                                 y: bounds.y,
                                 ..Rectangle::with_size(image_size)
                             },
+                            filter_method,
+                            source: Some(source),
                         }),
                     }),
                     offset: Vector::new(0, 0),
@@ -0,0 +1,103 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::backend::{self, Backend};
+use crate::{Primitive, Renderer, Themed};
+use iced_native::mouse;
+use iced_native::number_input;
+use iced_native::{
+    Color, Font, HorizontalAlignment, Point, Rectangle, VerticalAlignment,
+};
+use iced_style::text_input;
+
+pub use iced_native::number_input::State;
+pub use iced_style::number_input::{Style, StyleSheet};
+
+/// A field that can be filled with numbers.
+///
+/// This is an alias of an `iced_native` number input with an
+/// `iced_wgpu::Renderer`.
+pub type NumberInput<'a, T, Message, Backend> =
+    iced_native::NumberInput<'a, T, Message, Renderer<Backend>>;
+
+impl<B> number_input::Renderer for Renderer<B>
+where
+    B: Backend + backend::Text,
+{
+    type Style = Box<dyn StyleSheet>;
+
+    fn text_input_style(
+        style: &Box<dyn StyleSheet>,
+    ) -> Box<dyn text_input::StyleSheet> {
+        style.text_input()
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        increment_bounds: Rectangle,
+        cursor_position: Point,
+        can_decrement: bool,
+        can_increment: bool,
+        is_disabled: bool,
+        (input, input_interaction): Self::Output,
+        style_sheet: &Box<dyn StyleSheet>,
+    ) -> Self::Output {
+        let mut primitives = vec![input];
+        let mut interaction = input_interaction;
+
+        for (bounds, icon, is_enabled) in [
+            (decrement_bounds, "−", can_decrement && !is_disabled),
+            (increment_bounds, "+", can_increment && !is_disabled),
+        ]
+        .iter()
+        {
+            let is_mouse_over = bounds.contains(cursor_position);
+
+            let style = if !is_enabled {
+                style_sheet.disabled(self.theme())
+            } else if is_mouse_over {
+                style_sheet.hovered(self.theme())
+            } else {
+                style_sheet.active(self.theme())
+            };
+
+            primitives.push(Primitive::Quad {
+                bounds: *bounds,
+                background: style.button_background,
+                border_radius: style.button_border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            });
+
+            primitives.push(Primitive::Text {
+                content: icon.to_string(),
+                size: bounds.height * 0.7,
+                font: Font::Default,
+                color: style.icon_color,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..*bounds
+                },
+                horizontal_alignment: HorizontalAlignment::Center,
+                vertical_alignment: VerticalAlignment::Center,
+            });
+
+            if is_mouse_over {
+                interaction = if *is_enabled {
+                    mouse::Interaction::Pointer
+                } else {
+                    mouse::Interaction::NotAllowed
+                };
+            }
+        }
+
+        if is_disabled && bounds.contains(cursor_position) {
+            interaction = mouse::Interaction::NotAllowed;
+        }
+
+        (Primitive::Group { primitives }, interaction)
+    }
+}
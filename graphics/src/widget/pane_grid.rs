@@ -8,7 +8,7 @@
 //!
 //! [`pane_grid` example]: https://github.com/hecrj/iced/tree/0.3/examples/pane_grid
 use crate::defaults;
-use crate::{Backend, Color, Primitive, Renderer};
+use crate::{Backend, Color, Primitive, Renderer, Themed};
 use iced_native::container;
 use iced_native::mouse;
 use iced_native::pane_grid;
@@ -119,9 +119,9 @@ where
         let (primitives, mouse_interaction) =
             if let Some((axis, split_region, is_picked)) = resizing {
                 let highlight = if is_picked {
-                    style_sheet.picked_split()
+                    style_sheet.picked_split(self.theme())
                 } else {
-                    style_sheet.hovered_split()
+                    style_sheet.hovered_split(self.theme())
                 };
 
                 if let Some(highlight) = highlight {
@@ -188,7 +188,7 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
         let (body, body_layout) = body;
 
         let (body_primitive, body_interaction) =
@@ -250,7 +250,7 @@ where
         cursor_position: Point,
         viewport: &Rectangle,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
         let (title_content, title_layout) = content;
 
         let defaults = Self::Defaults {
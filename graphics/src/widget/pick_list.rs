@@ -1,6 +1,6 @@
 //! Display a dropdown list of selectable values.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Themed};
 use iced_native::{
     mouse, Font, HorizontalAlignment, Padding, Point, Rectangle,
     VerticalAlignment,
@@ -22,7 +22,7 @@ where
 
     const DEFAULT_PADDING: Padding = Padding::new(5);
 
-    fn menu_style(style: &Box<dyn StyleSheet>) -> menu::Style {
+    fn menu_style(style: &Box<dyn StyleSheet>) -> Box<dyn menu::StyleSheet> {
         style.menu()
     }
 
@@ -34,14 +34,17 @@ where
         padding: Padding,
         text_size: u16,
         font: Font,
+        is_disabled: bool,
         style: &Box<dyn StyleSheet>,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_mouse_over {
-            style.hovered()
+        let style = if is_disabled {
+            style.disabled(self.theme())
+        } else if is_mouse_over {
+            style.hovered(self.theme())
         } else {
-            style.active()
+            style.active(self.theme())
         };
 
         let background = Primitive::Quad {
@@ -88,7 +91,9 @@ where
                     vec![background, arrow_down]
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
@@ -2,7 +2,7 @@
 //!
 //! A [`ProgressBar`] has a range of possible values and a current value,
 //! as well as a length, height and style.
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::progress_bar;
 use iced_native::{Color, Rectangle};
@@ -30,7 +30,7 @@ where
         value: f32,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
         let (range_start, range_end) = range.into_inner();
 
         let active_progress_width = if range_start >= range_end {
@@ -1,5 +1,5 @@
 //! Create choices using radio buttons.
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::radio;
 use iced_native::{Background, Color, Rectangle};
@@ -27,13 +27,16 @@ where
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         (label, _): Self::Output,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered()
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme())
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let size = bounds.width;
@@ -68,7 +71,9 @@ where
                     vec![radio, label]
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
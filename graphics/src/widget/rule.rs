@@ -1,6 +1,6 @@
 //! Display a horizontal or vertical rule for dividing content.
 
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::rule;
 use iced_native::{Background, Color, Rectangle};
@@ -24,7 +24,7 @@ where
         style_sheet: &Self::Style,
         is_horizontal: bool,
     ) -> Self::Output {
-        let style = style_sheet.style();
+        let style = style_sheet.style(self.theme());
 
         let line = if is_horizontal {
             let line_y = (bounds.y + (bounds.height / 2.0)
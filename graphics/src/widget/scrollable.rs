@@ -1,5 +1,5 @@
 //! Navigate an endless amount of content with a scrollbar.
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::scrollable;
 use iced_native::{Background, Color, Rectangle, Vector};
@@ -95,11 +95,11 @@ where
                 };
 
                 let style = if state.is_scroller_grabbed() {
-                    style_sheet.dragging()
+                    style_sheet.dragging(self.theme())
                 } else if is_mouse_over_scrollbar {
-                    style_sheet.hovered()
+                    style_sheet.hovered(self.theme())
                 } else {
-                    style_sheet.active()
+                    style_sheet.active(self.theme())
                 };
 
                 let is_scrollbar_visible =
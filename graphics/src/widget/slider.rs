@@ -1,7 +1,7 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
-use crate::{Backend, Primitive, Renderer};
+use crate::{Backend, Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::slider;
 use iced_native::{Background, Color, Point, Rectangle};
@@ -31,16 +31,19 @@ where
         range: std::ops::RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        is_disabled: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let style = if is_dragging {
-            style_sheet.dragging()
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme())
+        } else if is_dragging {
+            style_sheet.dragging(self.theme())
         } else if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let rail_y = bounds.y + (bounds.height / 2.0).round();
@@ -111,7 +114,9 @@ where
             Primitive::Group {
                 primitives: vec![rail_top, rail_bottom, handle],
             },
-            if is_dragging {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_dragging {
                 mouse::Interaction::Grabbing
             } else if is_mouse_over {
                 mouse::Interaction::Grab
@@ -2,7 +2,7 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::text_input::{self, cursor};
 use iced_native::{
@@ -75,17 +75,28 @@ where
         size: u16,
         placeholder: &str,
         value: &text_input::Value,
+        mask: &str,
         state: &text_input::State,
+        is_disabled: bool,
+        is_read_only: bool,
+        is_invalid: bool,
         style_sheet: &Self::Style,
     ) -> Self::Output {
         let is_mouse_over = bounds.contains(cursor_position);
+        let is_focused = state.is_focused() && !is_disabled;
 
-        let style = if state.is_focused() {
-            style_sheet.focused()
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme())
+        } else if is_read_only {
+            style_sheet.read_only(self.theme())
+        } else if is_invalid {
+            style_sheet.invalid(self.theme())
+        } else if is_focused {
+            style_sheet.focused(self.theme())
         } else if is_mouse_over {
-            style_sheet.hovered()
+            style_sheet.hovered(self.theme())
         } else {
-            style_sheet.active()
+            style_sheet.active(self.theme())
         };
 
         let input = Primitive::Quad {
@@ -97,17 +108,21 @@ where
         };
 
         let text = value.to_string();
+        let show_placeholder =
+            text.is_empty() && (!is_focused || mask.is_empty());
 
         let text_value = Primitive::Text {
-            content: if text.is_empty() {
+            content: if show_placeholder {
                 placeholder.to_string()
             } else {
                 text.clone()
             },
-            color: if text.is_empty() {
-                style_sheet.placeholder_color()
+            color: if show_placeholder {
+                style_sheet.placeholder_color(self.theme())
+            } else if is_disabled {
+                style_sheet.disabled_color(self.theme())
             } else {
-                style_sheet.value_color()
+                style_sheet.value_color(self.theme())
             },
             font,
             bounds: Rectangle {
@@ -120,7 +135,30 @@ where
             vertical_alignment: VerticalAlignment::Center,
         };
 
-        let (contents_primitive, offset) = if state.is_focused() {
+        let text_value = if show_placeholder || mask.is_empty() {
+            text_value
+        } else {
+            let mask_value = Primitive::Text {
+                content: mask.to_string(),
+                color: style_sheet.placeholder_color(self.theme()),
+                font,
+                bounds: Rectangle {
+                    x: text_bounds.x + self.measure_value(&text, size, font),
+                    y: text_bounds.center_y(),
+                    width: f32::INFINITY,
+                    ..text_bounds
+                },
+                size: f32::from(size),
+                horizontal_alignment: HorizontalAlignment::Left,
+                vertical_alignment: VerticalAlignment::Center,
+            };
+
+            Primitive::Group {
+                primitives: vec![text_value, mask_value],
+            }
+        };
+
+        let (contents_primitive, offset) = if is_focused {
             let cursor = state.cursor();
 
             let (cursor_primitive, offset) = match cursor.state(value) {
@@ -144,7 +182,7 @@ where
                                 height: text_bounds.height,
                             },
                             background: Background::Color(
-                                style_sheet.value_color(),
+                                style_sheet.value_color(self.theme()),
                             ),
                             border_radius: 0.0,
                             border_width: 0.0,
@@ -188,7 +226,7 @@ where
                                 height: text_bounds.height,
                             },
                             background: Background::Color(
-                                style_sheet.selection_color(),
+                                style_sheet.selection_color(self.theme()),
                             ),
                             border_radius: 0.0,
                             border_width: 0.0,
@@ -213,11 +251,11 @@ where
             (text_value, Vector::new(0, 0))
         };
 
-        let text_width = self.measure_value(
-            if text.is_empty() { placeholder } else { &text },
-            size,
-            font,
-        );
+        let text_width = if show_placeholder {
+            self.measure_value(placeholder, size, font)
+        } else {
+            self.measure_value(&(text + mask), size, font)
+        };
 
         let contents = if text_width > text_bounds.width {
             Primitive::Clip {
@@ -233,7 +271,9 @@ where
             Primitive::Group {
                 primitives: vec![input, contents],
             },
-            if is_mouse_over {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Text
             } else {
                 mouse::Interaction::default()
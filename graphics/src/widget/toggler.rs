@@ -1,6 +1,6 @@
 //! Show toggle controls using togglers.
 use crate::backend::{self, Backend};
-use crate::{Primitive, Renderer};
+use crate::{Primitive, Renderer, Themed};
 use iced_native::mouse;
 use iced_native::toggler;
 use iced_native::Rectangle;
@@ -33,13 +33,16 @@ where
         bounds: Rectangle,
         is_active: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Option<Self::Output>,
         style_sheet: &Self::Style,
     ) -> Self::Output {
-        let style = if is_mouse_over {
-            style_sheet.hovered(is_active)
+        let style = if is_disabled {
+            style_sheet.disabled(self.theme(), is_active)
+        } else if is_mouse_over {
+            style_sheet.hovered(self.theme(), is_active)
         } else {
-            style_sheet.active(is_active)
+            style_sheet.active(self.theme(), is_active)
         };
 
         let border_radius = bounds.height as f32 / BORDER_RADIUS_RATIO;
@@ -89,7 +92,9 @@ where
                     None => vec![toggler_background, toggler_foreground],
                 },
             },
-            if is_mouse_over {
+            if is_mouse_over && is_disabled {
+                mouse::Interaction::NotAllowed
+            } else if is_mouse_over {
                 mouse::Interaction::Pointer
             } else {
                 mouse::Interaction::default()
@@ -1,7 +1,7 @@
 //! Decorate content and apply alignment.
 use crate::backend::{self, Backend};
 use crate::defaults::{self, Defaults};
-use crate::{Primitive, Renderer, Vector};
+use crate::{Primitive, Renderer, Themed, Vector};
 
 use iced_native::container;
 use iced_native::layout::{self, Layout};
@@ -49,7 +49,7 @@ where
             use iced_native::Widget;
 
             let gap = f32::from(gap);
-            let style = style_sheet.style();
+            let style = style_sheet.style(self.theme());
 
             let defaults = Defaults {
                 text: defaults::Text {
@@ -0,0 +1,150 @@
+//! Animate values over time.
+//!
+//! An [`Animation`] interpolates between two values of a type implementing
+//! [`Interpolate`] over a set duration, following some [`Easing`] curve.
+//!
+//! Animations are driven by the frame time produced by shells. You can listen
+//! to it using [`window::frames`] and feed the resulting [`Instant`] to your
+//! animations in your update logic.
+//!
+//! The built-in widgets do not animate by themselves yet. Style sheets, like
+//! `button::StyleSheet::hovered`, still switch styles instantly, and
+//! `Scrollable::scroll_to` jumps straight to its offset. Both would need an
+//! [`Animation`] kept inside of the widget state.
+//!
+//! [`window::frames`]: crate::window::frames
+mod easing;
+mod interpolate;
+
+pub use easing::Easing;
+pub use interpolate::Interpolate;
+
+use std::time::{Duration, Instant};
+
+/// A value that transitions smoothly between states over time.
+///
+/// ```
+/// use iced_native::animation::{Animation, Easing};
+/// use std::time::{Duration, Instant};
+///
+/// let mut opacity = Animation::new(0.0)
+///     .duration(Duration::from_millis(300))
+///     .easing(Easing::EaseOutCubic);
+///
+/// let now = Instant::now();
+/// opacity.go_to(1.0, now);
+///
+/// assert_eq!(opacity.value(now), 0.0);
+/// assert_eq!(opacity.value(now + Duration::from_millis(300)), 1.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Animation<T> {
+    from: T,
+    to: T,
+    start: Option<Instant>,
+    duration: Duration,
+    easing: Easing,
+}
+
+impl<T> Animation<T>
+where
+    T: Interpolate + Clone,
+{
+    /// The default duration of an [`Animation`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Animation`] resting at the given value.
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value.clone(),
+            to: value,
+            start: None,
+            duration: Self::DEFAULT_DURATION,
+            easing: Easing::Linear,
+        }
+    }
+
+    /// Sets the duration of the transitions of the [`Animation`].
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the [`Easing`] curve of the transitions of the [`Animation`].
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Starts transitioning the [`Animation`] towards the given target at the
+    /// given moment in time.
+    ///
+    /// The transition starts from the current value of the [`Animation`].
+    /// Therefore, an ongoing transition can be interrupted smoothly.
+    pub fn go_to(&mut self, target: T, now: Instant) {
+        self.from = self.value(now);
+        self.to = target;
+        self.start = Some(now);
+    }
+
+    /// Moves the [`Animation`] to the given value instantly, stopping any
+    /// ongoing transition.
+    pub fn snap_to(&mut self, value: T) {
+        self.from = value.clone();
+        self.to = value;
+        self.start = None;
+    }
+
+    /// Returns the value of the [`Animation`] at the given moment in time.
+    pub fn value(&self, now: Instant) -> T {
+        match self.start {
+            Some(_) if self.is_animating(now) => self
+                .from
+                .interpolate(&self.to, self.easing.apply(self.progress(now))),
+            _ => self.to.clone(),
+        }
+    }
+
+    /// Returns the value the [`Animation`] is transitioning to.
+    pub fn target(&self) -> &T {
+        &self.to
+    }
+
+    /// Returns the linear progress of the current transition of the
+    /// [`Animation`] at the given moment in time, between `0.0` and `1.0`.
+    ///
+    /// An [`Animation`] at rest is always done and, therefore, returns `1.0`.
+    pub fn progress(&self, now: Instant) -> f32 {
+        match self.start {
+            Some(start) => {
+                if self.duration == Duration::from_secs(0) {
+                    return 1.0;
+                }
+
+                let elapsed = now.saturating_duration_since(start);
+
+                (elapsed.as_secs_f32() / self.duration.as_secs_f32()).min(1.0)
+            }
+            None => 1.0,
+        }
+    }
+
+    /// Returns whether the [`Animation`] is still transitioning at the given
+    /// moment in time.
+    ///
+    /// Shells will keep producing frames as long as you keep listening to
+    /// them. You can use this method to stop listening once all of your
+    /// animations are done.
+    pub fn is_animating(&self, now: Instant) -> bool {
+        self.progress(now) < 1.0
+    }
+}
+
+impl<T> Default for Animation<T>
+where
+    T: Interpolate + Clone + Default,
+{
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
@@ -0,0 +1,130 @@
+use std::f32::consts::PI;
+
+/// The rate of change of an [`Animation`] over time.
+///
+/// [`Animation`]: super::Animation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Easing {
+    /// A constant rate of change.
+    Linear,
+
+    /// A cubic curve that starts slowly and accelerates.
+    EaseInCubic,
+
+    /// A cubic curve that starts quickly and decelerates.
+    EaseOutCubic,
+
+    /// A cubic curve that accelerates until halfway and then decelerates.
+    EaseInOutCubic,
+
+    /// A damped spring that may overshoot its target before settling.
+    Spring {
+        /// The damping ratio of the spring.
+        ///
+        /// Values closer to `0.0` oscillate longer, while `1.0` or greater
+        /// settles without overshooting.
+        damping: f32,
+
+        /// The amount of oscillations of the spring during the animation.
+        frequency: f32,
+    },
+}
+
+impl Easing {
+    /// A bouncy [`Easing::Spring`] that overshoots its target slightly.
+    pub const SPRING: Easing = Easing::Spring {
+        damping: 0.5,
+        frequency: 1.5,
+    };
+
+    /// Applies the [`Easing`] to the given linear progress, between `0.0` and
+    /// `1.0`.
+    ///
+    /// The result is `0.0` at the start and `1.0` at the end, but it may fall
+    /// outside of this range in between (e.g. a [`Easing::Spring`]).
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        match self {
+            Easing::Linear => t,
+            Easing::EaseInCubic => t * t * t,
+            Easing::EaseOutCubic => 1.0 - (1.0 - t).powi(3),
+            Easing::EaseInOutCubic => {
+                if t < 0.5 {
+                    4.0 * t * t * t
+                } else {
+                    1.0 - (-2.0 * t + 2.0).powi(3) / 2.0
+                }
+            }
+            Easing::Spring { damping, frequency } => {
+                let omega = 2.0 * PI * frequency;
+                let damping = damping.max(0.0);
+
+                if omega <= 0.0 {
+                    return t;
+                }
+
+                // The spring has not fully settled when the animation ends,
+                // so the remaining displacement is removed progressively to
+                // end exactly at the target
+                let displacement = |t| spring_displacement(damping, omega, t);
+
+                1.0 - displacement(t) + displacement(1.0) * t
+            }
+        }
+    }
+}
+
+/// Returns the displacement of a damped spring released from `1.0` at the
+/// given moment in time.
+fn spring_displacement(damping: f32, omega: f32, t: f32) -> f32 {
+    if damping < 1.0 {
+        let damped = omega * (1.0 - damping * damping).sqrt();
+        let decay = (-damping * omega * t).exp();
+
+        decay
+            * ((damped * t).cos()
+                + damping * omega / damped * (damped * t).sin())
+    } else {
+        (-omega * t).exp() * (1.0 + omega * t)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EASINGS: [Easing; 5] = [
+        Easing::Linear,
+        Easing::EaseInCubic,
+        Easing::EaseOutCubic,
+        Easing::EaseInOutCubic,
+        Easing::SPRING,
+    ];
+
+    #[test]
+    fn starts_and_ends_at_bounds() {
+        for easing in EASINGS.iter() {
+            assert!(easing.apply(0.0).abs() < f32::EPSILON, "{:?}", easing);
+            assert!((easing.apply(1.0) - 1.0).abs() < f32::EPSILON);
+        }
+    }
+
+    #[test]
+    fn ends_without_jumping() {
+        for easing in EASINGS.iter() {
+            let before_end = easing.apply(1.0 - 1.0 / 1000.0);
+
+            assert!((before_end - 1.0).abs() < 0.01, "{:?}", easing);
+        }
+    }
+
+    #[test]
+    fn spring_overshoots() {
+        let peak = (0..100)
+            .map(|i| Easing::SPRING.apply(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+
+        assert!(peak > 1.0);
+    }
+}
@@ -0,0 +1,56 @@
+use crate::{Color, Point, Size, Vector};
+
+/// A value that can be interpolated linearly.
+pub trait Interpolate {
+    /// Interpolates between `self` and `other` by the given factor.
+    ///
+    /// A factor of `0.0` produces `self`, while `1.0` produces `other`. Some
+    /// easing curves may produce factors outside of this range.
+    fn interpolate(&self, other: &Self, t: f32) -> Self;
+}
+
+impl Interpolate for f32 {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t
+    }
+}
+
+impl Interpolate for Color {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        let channel = |a: f32, b: f32| a.interpolate(&b, t).clamp(0.0, 1.0);
+
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+            a: channel(self.a, other.a),
+        }
+    }
+}
+
+impl Interpolate for Point {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Point::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Vector {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Vector::new(
+            self.x.interpolate(&other.x, t),
+            self.y.interpolate(&other.y, t),
+        )
+    }
+}
+
+impl Interpolate for Size {
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Size::new(
+            self.width.interpolate(&other.width, t),
+            self.height.interpolate(&other.height, t),
+        )
+    }
+}
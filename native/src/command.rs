@@ -0,0 +1,265 @@
+//! Run asynchronous actions.
+mod action;
+
+pub use action::Action;
+pub use iced_futures::futures::future::AbortHandle;
+
+use crate::widget;
+
+use iced_futures::futures::future::{self, Abortable, Future, FutureExt};
+use iced_futures::futures::stream::{self, Stream, StreamExt};
+
+/// A set of asynchronous actions to be performed by some runtime.
+///
+/// A [`Command`] can run futures or ask the shell to perform some action,
+/// like resizing the window.
+///
+/// This [`Command`] is only supported by native runtimes. On the web, the
+/// `Command` of `iced_web` can only run futures.
+pub struct Command<T> {
+    actions: Vec<Action<T>>,
+}
+
+impl<T> Command<T> {
+    /// Creates an empty [`Command`].
+    ///
+    /// In other words, a [`Command`] that does nothing.
+    pub fn none() -> Self {
+        Self {
+            actions: Vec::new(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs a single [`Action`].
+    pub fn single(action: Action<T>) -> Self {
+        Self {
+            actions: vec![action],
+        }
+    }
+
+    /// Creates a [`Command`] that runs the given [`widget::Operation`] on the
+    /// widget tree of the application.
+    ///
+    /// If the operation produces a result, it will be fed back as a message.
+    pub fn widget(operation: impl widget::Operation<T> + 'static) -> Self {
+        Self::single(Action::Widget(Box::new(operation)))
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::single(Action::Future(Box::pin(future.map(f))))
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future.
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::single(Action::Future(Box::pin(future.map(f))))
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future,
+    /// together with an [`AbortHandle`] that can be used to cancel it.
+    ///
+    /// Once aborted, the future is dropped and no result will be produced.
+    /// This is useful to discard stale work, like an outdated search request
+    /// that has been superseded by a new one.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn perform_abortable<A>(
+        future: impl Future<Output = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> (Command<A>, AbortHandle)
+    where
+        A: 'static + Send,
+    {
+        let (handle, registration) = AbortHandle::new_pair();
+        let future = Abortable::new(future.map(f), registration);
+
+        let stream = stream::once(future)
+            .filter_map(|result| future::ready(result.ok()));
+
+        (Command::single(Action::Stream(stream.boxed())), handle)
+    }
+
+    /// Creates a [`Command`] that performs the action of the given future,
+    /// together with an [`AbortHandle`] that can be used to cancel it.
+    ///
+    /// Once aborted, the future is dropped and no result will be produced.
+    /// This is useful to discard stale work, like an outdated search request
+    /// that has been superseded by a new one.
+    #[cfg(target_arch = "wasm32")]
+    pub fn perform_abortable<A>(
+        future: impl Future<Output = T> + 'static,
+        f: impl Fn(T) -> A + 'static,
+    ) -> (Command<A>, AbortHandle)
+    where
+        A: 'static,
+    {
+        let (handle, registration) = AbortHandle::new_pair();
+        let future = Abortable::new(future.map(f), registration);
+
+        let stream = stream::once(future)
+            .filter_map(|result| future::ready(result.ok()));
+
+        (
+            Command::single(Action::Stream(stream.boxed_local())),
+            handle,
+        )
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion and
+    /// produces a result for each of its items.
+    ///
+    /// This is useful for long-running work that needs to report progress
+    /// back to your application.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static + Send,
+        f: impl Fn(T) -> A + 'static + Send,
+    ) -> Command<A> {
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
+    /// Creates a [`Command`] that runs the given stream to completion and
+    /// produces a result for each of its items.
+    ///
+    /// This is useful for long-running work that needs to report progress
+    /// back to your application.
+    #[cfg(target_arch = "wasm32")]
+    pub fn run<A>(
+        stream: impl Stream<Item = T> + 'static,
+        f: impl Fn(T) -> A + 'static,
+    ) -> Command<A> {
+        Command::single(Action::Stream(Box::pin(stream.map(f))))
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn map<A>(
+        self,
+        f: impl Fn(T) -> A + 'static + Send + Sync,
+    ) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = std::sync::Arc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| action.map(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    #[cfg(target_arch = "wasm32")]
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static) -> Command<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        let f = std::rc::Rc::new(f);
+
+        Command {
+            actions: self
+                .actions
+                .into_iter()
+                .map(|action| action.map(f.clone()))
+                .collect(),
+        }
+    }
+
+    /// Creates a [`Command`] that performs the actions of all the given
+    /// commands.
+    ///
+    /// Once this command is run, all the commands will be executed at once.
+    pub fn batch(commands: impl IntoIterator<Item = Command<T>>) -> Self {
+        Self {
+            actions: commands
+                .into_iter()
+                .flat_map(|command| command.actions)
+                .collect(),
+        }
+    }
+
+    /// Converts a [`Command`] into its underlying list of actions.
+    pub fn actions(self) -> Vec<Action<T>> {
+        self.actions
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static + Send,
+{
+    fn from(future: A) -> Self {
+        Self::single(Action::Future(future.boxed()))
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+impl<T, A> From<A> for Command<T>
+where
+    A: Future<Output = T> + 'static,
+{
+    fn from(future: A) -> Self {
+        Self::single(Action::Future(future.boxed_local()))
+    }
+}
+
+impl<T> std::fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_list().entries(&self.actions).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_futures::futures::executor;
+
+    fn results(command: Command<u32>) -> Vec<u32> {
+        command
+            .actions()
+            .into_iter()
+            .flat_map(|action| match action {
+                Action::Stream(stream) => {
+                    executor::block_on(stream.collect::<Vec<_>>())
+                }
+                _ => Vec::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn run_produces_a_result_per_item() {
+        let command = Command::run(stream::iter(vec![1, 2, 3]), |n| n * 2);
+
+        assert_eq!(results(command), vec![2, 4, 6]);
+    }
+
+    #[test]
+    fn abortable_commands_produce_nothing_once_aborted() {
+        let (command, _handle) =
+            Command::perform_abortable(async { 1 }, |n| n + 1);
+
+        assert_eq!(results(command), vec![2]);
+
+        let (command, handle) =
+            Command::perform_abortable(async { 1 }, |n| n + 1);
+
+        handle.abort();
+
+        assert_eq!(results(command), Vec::<u32>::new());
+    }
+}
@@ -0,0 +1,96 @@
+use crate::widget;
+use crate::window;
+
+use iced_futures::futures::future::FutureExt;
+use iced_futures::futures::stream::StreamExt;
+use iced_futures::{BoxFuture, BoxStream};
+
+use std::fmt;
+
+/// An action that a [`Command`] can perform.
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Run a [`Future`] to completion.
+    ///
+    /// [`Future`]: iced_futures::futures::Future
+    Future(BoxFuture<T>),
+
+    /// Run a [`Stream`] until it ends, producing a result for each of its
+    /// items.
+    ///
+    /// [`Stream`]: iced_futures::futures::Stream
+    Stream(BoxStream<T>),
+
+    /// Run a window action.
+    Window(window::Action<T>),
+
+    /// Run a [`widget::Operation`] on the widget tree of the application.
+    Widget(Box<dyn widget::Operation<T>>),
+}
+
+impl<T> Action<T> {
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: crate::Command
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn map<A>(
+        self,
+        f: std::sync::Arc<impl Fn(T) -> A + 'static + Send + Sync>,
+    ) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Self::Future(future) => {
+                Action::Future(Box::pin(future.map(move |result| f(result))))
+            }
+            Self::Stream(stream) => {
+                Action::Stream(Box::pin(stream.map(move |result| f(result))))
+            }
+            Self::Window(window) => {
+                Action::Window(window.map(move |result| f(result)))
+            }
+            Self::Widget(operation) => Action::Widget(Box::new(
+                widget::operation::map(operation, move |result| f(result)),
+            )),
+        }
+    }
+
+    /// Applies a transformation to the result of a [`Command`].
+    ///
+    /// [`Command`]: crate::Command
+    #[cfg(target_arch = "wasm32")]
+    pub fn map<A>(self, f: std::rc::Rc<impl Fn(T) -> A + 'static>) -> Action<A>
+    where
+        T: 'static,
+        A: 'static,
+    {
+        match self {
+            Self::Future(future) => {
+                Action::Future(Box::pin(future.map(move |result| f(result))))
+            }
+            Self::Stream(stream) => {
+                Action::Stream(Box::pin(stream.map(move |result| f(result))))
+            }
+            Self::Window(window) => {
+                Action::Window(window.map(move |result| f(result)))
+            }
+            Self::Widget(operation) => Action::Widget(Box::new(
+                widget::operation::map(operation, move |result| f(result)),
+            )),
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Future(_) => write!(f, "Action::Future"),
+            Self::Stream(_) => write!(f, "Action::Stream"),
+            Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::Widget(_) => write!(f, "Action::Widget"),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+// We disable debug capabilities on release builds unless the `debug` feature
+// is explicitly enabled.
+#[cfg(feature = "debug")]
+mod basic;
+#[cfg(not(feature = "debug"))]
+mod null;
+
+#[cfg(feature = "tracing")]
+mod span;
+
+#[cfg(feature = "debug")]
+pub use basic::Debug;
+#[cfg(not(feature = "debug"))]
+pub use null::Debug;
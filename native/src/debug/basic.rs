@@ -1,6 +1,9 @@
 #![allow(missing_docs)]
 use std::{collections::VecDeque, time};
 
+#[cfg(feature = "tracing")]
+use super::span::{Phase, Spans};
+
 /// A bunch of time measurements for debugging purposes.
 #[derive(Debug)]
 pub struct Debug {
@@ -29,6 +32,11 @@ pub struct Debug {
 
     message_count: usize,
     last_messages: VecDeque<String>,
+    time_travel: Vec<String>,
+    inspector: Vec<String>,
+
+    #[cfg(feature = "tracing")]
+    spans: Spans,
 }
 
 impl Debug {
@@ -61,6 +69,11 @@ impl Debug {
 
             message_count: 0,
             last_messages: VecDeque::new(),
+            time_travel: Vec::new(),
+            inspector: Vec::new(),
+
+            #[cfg(feature = "tracing")]
+            spans: Spans::default(),
         }
     }
 
@@ -68,69 +81,118 @@ impl Debug {
         self.is_enabled = !self.is_enabled;
     }
 
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
     pub fn startup_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Startup);
+
         self.startup_start = time::Instant::now();
     }
 
     pub fn startup_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Startup);
+
         self.startup_duration = time::Instant::now() - self.startup_start;
     }
 
     pub fn update_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Update);
+
         self.update_start = time::Instant::now();
     }
 
     pub fn update_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Update);
+
         self.update_durations
             .push(time::Instant::now() - self.update_start);
     }
 
     pub fn view_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::View);
+
         self.view_start = time::Instant::now();
     }
 
     pub fn view_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::View);
+
         self.view_durations
             .push(time::Instant::now() - self.view_start);
     }
 
     pub fn layout_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Layout);
+
         self.layout_start = time::Instant::now();
     }
 
     pub fn layout_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Layout);
+
         self.layout_durations
             .push(time::Instant::now() - self.layout_start);
     }
 
     pub fn event_processing_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::EventProcessing);
+
         self.event_start = time::Instant::now();
     }
 
     pub fn event_processing_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::EventProcessing);
+
         self.event_durations
             .push(time::Instant::now() - self.event_start);
     }
 
     pub fn draw_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Draw);
+
         self.draw_start = time::Instant::now();
     }
 
     pub fn draw_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Draw);
+
         self.draw_durations
             .push(time::Instant::now() - self.draw_start);
     }
 
     pub fn render_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Render);
+
         self.render_start = time::Instant::now();
     }
 
     pub fn render_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Render);
+
         self.render_durations
             .push(time::Instant::now() - self.render_start);
     }
 
     pub fn log_message<Message: std::fmt::Debug>(&mut self, message: &Message) {
+        #[cfg(feature = "tracing")]
+        self.spans.log_message(message);
+
         self.last_messages.push_back(format!("{:?}", message));
 
         if self.last_messages.len() > 10 {
@@ -140,6 +202,14 @@ impl Debug {
         self.message_count += 1;
     }
 
+    pub fn time_travel(&mut self, lines: Vec<String>) {
+        self.time_travel = lines;
+    }
+
+    pub fn inspector(&mut self, lines: Vec<String>) {
+        self.inspector = lines;
+    }
+
     pub fn overlay(&self) -> Vec<String> {
         if !self.is_enabled {
             return Vec::new();
@@ -179,6 +249,8 @@ impl Debug {
                 format!("    {:.100}...", msg)
             }
         }));
+        lines.extend(self.time_travel.iter().cloned());
+        lines.extend(self.inspector.iter().cloned());
 
         lines
     }
@@ -1,46 +1,107 @@
 #![allow(missing_docs)]
+#[cfg(feature = "tracing")]
+use super::span::{Phase, Spans};
+
 #[derive(Debug)]
-pub struct Debug;
+pub struct Debug {
+    #[cfg(feature = "tracing")]
+    spans: Spans,
+}
 
 impl Debug {
     pub fn new() -> Self {
-        Self
+        Self {
+            #[cfg(feature = "tracing")]
+            spans: Spans::default(),
+        }
     }
 
-    pub fn startup_started(&mut self) {}
+    pub fn is_enabled(&self) -> bool {
+        false
+    }
+
+    pub fn startup_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Startup);
+    }
 
-    pub fn startup_finished(&mut self) {}
+    pub fn startup_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Startup);
+    }
 
-    pub fn update_started(&mut self) {}
+    pub fn update_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Update);
+    }
 
-    pub fn update_finished(&mut self) {}
+    pub fn update_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Update);
+    }
 
-    pub fn view_started(&mut self) {}
+    pub fn view_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::View);
+    }
 
-    pub fn view_finished(&mut self) {}
+    pub fn view_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::View);
+    }
 
-    pub fn layout_started(&mut self) {}
+    pub fn layout_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Layout);
+    }
 
-    pub fn layout_finished(&mut self) {}
+    pub fn layout_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Layout);
+    }
 
-    pub fn event_processing_started(&mut self) {}
+    pub fn event_processing_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::EventProcessing);
+    }
 
-    pub fn event_processing_finished(&mut self) {}
+    pub fn event_processing_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::EventProcessing);
+    }
 
-    pub fn draw_started(&mut self) {}
+    pub fn draw_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Draw);
+    }
 
-    pub fn draw_finished(&mut self) {}
+    pub fn draw_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Draw);
+    }
 
-    pub fn render_started(&mut self) {}
+    pub fn render_started(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.start(Phase::Render);
+    }
 
-    pub fn render_finished(&mut self) {}
+    pub fn render_finished(&mut self) {
+        #[cfg(feature = "tracing")]
+        self.spans.finish(Phase::Render);
+    }
 
     pub fn log_message<Message: std::fmt::Debug>(
         &mut self,
         _message: &Message,
     ) {
+        #[cfg(feature = "tracing")]
+        self.spans.log_message(_message);
     }
 
+    pub fn time_travel(&mut self, _lines: Vec<String>) {}
+
+    pub fn inspector(&mut self, _lines: Vec<String>) {}
+
     pub fn overlay(&self) -> Vec<String> {
         Vec::new()
     }
@@ -0,0 +1,137 @@
+use tracing::span::EnteredSpan;
+use tracing::Span;
+
+use std::fmt::{self, Write};
+
+/// A phase of an application, emitted as a `tracing` span.
+#[derive(Debug, Clone, Copy)]
+pub enum Phase {
+    Startup,
+    Update,
+    View,
+    Layout,
+    EventProcessing,
+    Draw,
+    Render,
+}
+
+impl Phase {
+    const COUNT: usize = 7;
+}
+
+/// The `tracing` spans of the phases that are currently in progress.
+#[derive(Debug, Default)]
+pub struct Spans {
+    entered: [Option<EnteredSpan>; Phase::COUNT],
+    update: Option<Span>,
+}
+
+impl Spans {
+    /// Enters the span of the given [`Phase`].
+    ///
+    /// The span of an update records the last logged message.
+    pub fn start(&mut self, phase: Phase) {
+        let span = match phase {
+            Phase::Startup => tracing::info_span!("startup"),
+            Phase::Update => self.update.take().unwrap_or_else(update_span),
+            Phase::View => tracing::info_span!("view"),
+            Phase::Layout => tracing::info_span!("layout"),
+            Phase::EventProcessing => tracing::info_span!("event_processing"),
+            Phase::Draw => tracing::info_span!("draw"),
+            Phase::Render => tracing::info_span!("render"),
+        };
+
+        self.entered[phase as usize] = Some(span.entered());
+    }
+
+    /// Exits and closes the span of the given [`Phase`].
+    pub fn finish(&mut self, phase: Phase) {
+        self.entered[phase as usize] = None;
+    }
+
+    /// Logs the message that will be recorded by the next update span.
+    ///
+    /// Only the name of the message is recorded, and only if the span is
+    /// enabled.
+    pub fn log_message<Message: fmt::Debug>(&mut self, message: &Message) {
+        let span = update_span();
+
+        if !span.is_disabled() {
+            let _ = span.record("message", message_name(message).as_str());
+        }
+
+        self.update = Some(span);
+    }
+}
+
+fn update_span() -> Span {
+    tracing::info_span!("update", message = tracing::field::Empty)
+}
+
+/// Returns the name of the given message, as written by its `Debug`
+/// implementation, without any of its fields.
+///
+/// The type name of the message is returned if its `Debug` implementation
+/// does not start with a name.
+fn message_name<Message: fmt::Debug>(message: &Message) -> String {
+    struct Name(String);
+
+    impl Write for Name {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            let is_part_of_name =
+                |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+
+            match s.find(|c| !is_part_of_name(c)) {
+                Some(end) => {
+                    self.0.push_str(&s[..end]);
+
+                    // Stop formatting the fields of the message
+                    Err(fmt::Error)
+                }
+                None => {
+                    self.0.push_str(s);
+
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    let mut name = Name(String::new());
+    let _ = write!(name, "{:?}", message);
+
+    if name.0.is_empty() {
+        String::from(std::any::type_name::<Message>())
+    } else {
+        name.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::message_name;
+
+    #[derive(Debug)]
+    #[allow(dead_code)]
+    enum Message {
+        Reset,
+        Increment(u32),
+        Rename { name: String },
+    }
+
+    #[test]
+    fn names_messages_without_their_fields() {
+        assert_eq!(message_name(&Message::Reset), "Reset");
+        assert_eq!(message_name(&Message::Increment(1)), "Increment");
+        assert_eq!(
+            message_name(&Message::Rename {
+                name: String::from("Iced")
+            }),
+            "Rename"
+        );
+        assert_eq!(
+            message_name(&String::from("Iced")),
+            "alloc::string::String"
+        );
+    }
+}
@@ -1,8 +1,9 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget;
 use crate::{
-    Clipboard, Color, Hasher, Layout, Length, Point, Rectangle, Widget,
+    Clipboard, Color, Hasher, Layout, Length, Padding, Point, Rectangle, Widget,
 };
 
 /// A generic [`Widget`].
@@ -17,6 +18,7 @@ use crate::{
 #[allow(missing_debug_implementations)]
 pub struct Element<'a, Message, Renderer> {
     pub(crate) widget: Box<dyn Widget<Message, Renderer> + 'a>,
+    name: &'static str,
 }
 
 impl<'a, Message, Renderer> Element<'a, Message, Renderer>
@@ -24,11 +26,13 @@ where
     Renderer: crate::Renderer,
 {
     /// Creates a new [`Element`] containing the given [`Widget`].
-    pub fn new(
-        widget: impl Widget<Message, Renderer> + 'a,
-    ) -> Element<'a, Message, Renderer> {
+    pub fn new<W>(widget: W) -> Element<'a, Message, Renderer>
+    where
+        W: Widget<Message, Renderer> + 'a,
+    {
         Element {
             widget: Box::new(widget),
+            name: widget_name(std::any::type_name::<W>()),
         }
     }
 
@@ -174,6 +178,7 @@ where
     {
         Element {
             widget: Box::new(Map::new(self.widget, f)),
+            name: self.name,
         }
     }
 
@@ -191,8 +196,11 @@ where
         Message: 'static,
         Renderer: 'a + layout::Debugger,
     {
+        let name = self.name;
+
         Element {
             widget: Box::new(Explain::new(self, color.into())),
+            name,
         }
     }
 
@@ -206,15 +214,36 @@ where
         self.widget.height()
     }
 
+    /// Returns the padding of the [`Element`].
+    pub fn padding(&self) -> Padding {
+        self.widget.padding()
+    }
+
     /// Computes the layout of the [`Element`] in the given [`Limits`].
     ///
+    /// When the `debug` feature is enabled, the [`Details`] of the
+    /// computation are recorded in the resulting [`Node`].
+    ///
     /// [`Limits`]: layout::Limits
+    /// [`Details`]: layout::Details
+    /// [`Node`]: layout::Node
     pub fn layout(
         &self,
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        self.widget.layout(renderer, limits)
+        let node = self.widget.layout(renderer, limits);
+
+        #[cfg(feature = "debug")]
+        let node = node.describe(layout::Details {
+            name: self.name,
+            width: self.widget.width(),
+            height: self.widget.height(),
+            padding: self.widget.padding(),
+            limits: *limits,
+        });
+
+        node
     }
 
     /// Processes a runtime [`Event`].
@@ -262,6 +291,17 @@ where
     ) -> Option<overlay::Element<'b, Message, Renderer>> {
         self.widget.overlay(layout)
     }
+
+    /// Applies an [`Operation`] to the [`Element`] and its children.
+    ///
+    /// [`Operation`]: crate::widget::Operation
+    pub fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.widget.operate(layout, operation);
+    }
 }
 
 struct Map<'a, A, B, Renderer> {
@@ -298,6 +338,10 @@ where
         self.widget.height()
     }
 
+    fn padding(&self) -> Padding {
+        self.widget.padding()
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -359,6 +403,14 @@ where
             .overlay(layout)
             .map(move |overlay| overlay.map(mapper))
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.widget.operate(layout, operation);
+    }
 }
 
 struct Explain<'a, Message, Renderer: crate::Renderer> {
@@ -388,6 +440,10 @@ where
         self.element.widget.height()
     }
 
+    fn padding(&self) -> Padding {
+        self.element.widget.padding()
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -443,4 +499,22 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.element.overlay(layout)
     }
+
+    fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn widget::Operation,
+    ) {
+        self.element.operate(layout, operation);
+    }
+}
+
+/// Turns the type name of a widget into a short, readable name.
+///
+/// For instance, `iced_native::widget::button::Button<'_, Message, Renderer>`
+/// becomes `Button`.
+fn widget_name(type_name: &'static str) -> &'static str {
+    let path = type_name.split('<').next().unwrap_or(type_name);
+
+    path.rsplit("::").next().unwrap_or(path)
 }
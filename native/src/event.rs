@@ -11,6 +11,11 @@ use crate::window;
 ///
 /// [open an issue]: https://github.com/hecrj/iced/issues
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Event {
     /// A keyboard event
     Keyboard(keyboard::Event),
@@ -30,6 +35,11 @@ pub enum Event {
 
 /// A platform specific event
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum PlatformSpecific {
     /// A MacOS specific event
     MacOS(MacOS),
@@ -37,6 +47,11 @@ pub enum PlatformSpecific {
 
 /// Describes an event specific to MacOS
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum MacOS {
     /// Triggered when the app receives an URL from the system
     ///
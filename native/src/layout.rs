@@ -1,11 +1,13 @@
 //! Position your widgets properly.
 mod debugger;
+mod details;
 mod limits;
 mod node;
 
 pub mod flex;
 
 pub use debugger::Debugger;
+pub use details::Details;
 pub use limits::Limits;
 pub use node::Node;
 
@@ -55,6 +57,11 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Details`] of the [`Node`] of the [`Layout`], if recorded.
+    pub fn details(&self) -> Option<&'a Details> {
+        self.node.details()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
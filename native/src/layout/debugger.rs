@@ -21,4 +21,17 @@ pub trait Debugger: Renderer {
         viewport: &Rectangle,
         color: Color,
     ) -> Self::Output;
+
+    /// Highlights the given bounds on top of the provided output.
+    ///
+    /// This is used to point at a [`Layout`] while inspecting a user
+    /// interface. By default, the output is returned unchanged.
+    fn highlight(
+        &mut self,
+        output: Self::Output,
+        _bounds: Rectangle,
+        _color: Color,
+    ) -> Self::Output {
+        output
+    }
 }
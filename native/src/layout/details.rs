@@ -0,0 +1,27 @@
+use crate::layout::Limits;
+use crate::{Length, Padding};
+
+/// A description of how the [`Node`] of a widget was computed.
+///
+/// It is recorded for every [`Element`] that is laid out when the `debug`
+/// feature is enabled, so the layout of a user interface can be inspected.
+///
+/// [`Node`]: crate::layout::Node
+/// [`Element`]: crate::Element
+#[derive(Debug, Clone, Copy)]
+pub struct Details {
+    /// The name of the widget.
+    pub name: &'static str,
+
+    /// The width of the widget.
+    pub width: Length,
+
+    /// The height of the widget.
+    pub height: Length,
+
+    /// The padding of the widget.
+    pub padding: Padding,
+
+    /// The [`Limits`] the widget was laid out with.
+    pub limits: Limits,
+}
@@ -1,3 +1,4 @@
+use crate::layout::Details;
 use crate::{Align, Point, Rectangle, Size};
 
 /// The bounds of an element and its children.
@@ -5,6 +6,8 @@ use crate::{Align, Point, Rectangle, Size};
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    #[cfg(feature = "debug")]
+    details: Option<Details>,
 }
 
 impl Node {
@@ -23,6 +26,8 @@ impl Node {
                 height: size.height,
             },
             children,
+            #[cfg(feature = "debug")]
+            details: None,
         }
     }
 
@@ -41,6 +46,29 @@ impl Node {
         &self.children
     }
 
+    /// Returns the [`Details`] of the [`Node`], if they were recorded.
+    ///
+    /// [`Details`] are only recorded when the `debug` feature is enabled.
+    #[cfg(feature = "debug")]
+    pub fn details(&self) -> Option<&Details> {
+        self.details.as_ref()
+    }
+
+    /// Returns the [`Details`] of the [`Node`], if they were recorded.
+    ///
+    /// [`Details`] are only recorded when the `debug` feature is enabled.
+    #[cfg(not(feature = "debug"))]
+    pub fn details(&self) -> Option<&Details> {
+        None
+    }
+
+    /// Records the [`Details`] of how the [`Node`] was computed.
+    #[cfg(feature = "debug")]
+    pub(crate) fn describe(mut self, details: Details) -> Self {
+        self.details = Some(details);
+        self
+    }
+
     /// Aligns the [`Node`] in the given space.
     pub fn align(
         &mut self,
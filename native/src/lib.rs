@@ -33,7 +33,9 @@
 #![deny(unused_results)]
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
+pub mod animation;
 pub mod clipboard;
+pub mod command;
 pub mod event;
 pub mod keyboard;
 pub mod layout;
@@ -42,6 +44,7 @@ pub mod overlay;
 pub mod program;
 pub mod renderer;
 pub mod subscription;
+pub mod testing;
 pub mod touch;
 pub mod widget;
 pub mod window;
@@ -51,25 +54,19 @@ mod hasher;
 mod runtime;
 mod user_interface;
 
-// We disable debug capabilities on release builds unless the `debug` feature
-// is explicitly enabled.
-#[cfg(feature = "debug")]
-#[path = "debug/basic.rs"]
-mod debug;
-#[cfg(not(feature = "debug"))]
-#[path = "debug/null.rs"]
 mod debug;
 
 pub use iced_core::{
-    Align, Background, Color, Font, HorizontalAlignment, Length, Padding,
-    Point, Rectangle, Size, Vector, VerticalAlignment,
+    Align, Background, Color, ContentFit, Font, HorizontalAlignment, Length,
+    Padding, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
-pub use iced_futures::{executor, futures, Command};
+pub use iced_futures::{executor, futures};
 
 #[doc(no_inline)]
 pub use executor::Executor;
 
 pub use clipboard::Clipboard;
+pub use command::Command;
 pub use debug::Debug;
 pub use element::Element;
 pub use event::Event;
@@ -1,24 +1,40 @@
 //! Build and show dropdown menus.
 use crate::container;
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::scrollable;
 use crate::text;
 use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
     Clipboard, Container, Element, Hasher, Layout, Length, Padding, Point,
     Rectangle, Scrollable, Size, Vector, Widget,
 };
 
+use std::cell::Cell;
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+/// The time after which the characters typed to find an option are
+/// forgotten.
+const TYPE_AHEAD_TIMEOUT: Duration = Duration::from_secs(1);
+
 /// A list of selectable options.
+///
+/// Options can be highlighted with the mouse or with the arrow keys, and
+/// pressing enter selects the highlighted option. The page up and page down
+/// keys scroll the options.
 #[allow(missing_debug_implementations)]
 pub struct Menu<'a, T, Renderer: self::Renderer> {
     state: &'a mut State,
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    is_searchable: bool,
     width: u16,
     padding: Padding,
     text_size: Option<u16>,
@@ -44,6 +60,7 @@ where
             options,
             hovered_option,
             last_selection,
+            is_searchable: false,
             width: 0,
             padding: Padding::ZERO,
             text_size: None,
@@ -52,6 +69,17 @@ where
         }
     }
 
+    /// Sets whether the options of the [`Menu`] can be found by typing.
+    ///
+    /// A searchable [`Menu`] highlights the option starting with the letters
+    /// typed recently, and its first and last options with the home and end
+    /// keys. Owners that edit text with these keys should leave it disabled,
+    /// which is the default.
+    pub fn searchable(mut self, is_searchable: bool) -> Self {
+        self.is_searchable = is_searchable;
+        self
+    }
+
     /// Sets the width of the [`Menu`].
     pub fn width(mut self, width: u16) -> Self {
         self.width = width;
@@ -107,6 +135,7 @@ where
 #[derive(Debug, Clone, Default)]
 pub struct State {
     scrollable: scrollable::State,
+    type_ahead: TypeAhead,
 }
 
 impl State {
@@ -116,11 +145,21 @@ impl State {
     }
 }
 
+/// The characters typed recently to find an option of a [`Menu`].
+#[derive(Debug, Clone, Default)]
+struct TypeAhead {
+    query: String,
+    last_input: Option<Instant>,
+}
+
 struct Overlay<'a, Message, Renderer: self::Renderer> {
     container: Container<'a, Message, Renderer>,
     width: u16,
     target_height: f32,
-    style: <Renderer as self::Renderer>::Style,
+    padding: Padding,
+    text_size: Option<u16>,
+    revealed_option: Rc<Cell<Option<usize>>>,
+    style: Rc<<Renderer as self::Renderer>::Style>,
 }
 
 impl<'a, Message, Renderer: self::Renderer> Overlay<'a, Message, Renderer>
@@ -137,6 +176,7 @@ where
             options,
             hovered_option,
             last_selection,
+            is_searchable,
             width,
             padding,
             font,
@@ -144,15 +184,26 @@ where
             style,
         } = menu;
 
+        let State {
+            scrollable,
+            type_ahead,
+        } = state;
+
+        let style = Rc::new(style);
+        let revealed_option = Rc::new(Cell::new(None));
+
         let container =
-            Container::new(Scrollable::new(&mut state.scrollable).push(List {
+            Container::new(Scrollable::new(scrollable).push(List {
                 options,
                 hovered_option,
                 last_selection,
+                type_ahead,
+                is_searchable,
+                revealed_option: Rc::clone(&revealed_option),
                 font,
                 text_size,
                 padding,
-                style: style.clone(),
+                style: Rc::clone(&style),
             }))
             .padding(1);
 
@@ -160,11 +211,70 @@ where
             container,
             width: width,
             target_height,
+            padding,
+            text_size,
+            revealed_option,
             style: style,
         }
     }
 }
 
+impl<'a, Message, Renderer: self::Renderer> Overlay<'a, Message, Renderer> {
+    /// Scrolls the list of options to the offset returned by the given
+    /// function, which receives the visible bounds of the list and its
+    /// current offset.
+    fn scroll(
+        &mut self,
+        layout: Layout<'_>,
+        offset: impl FnMut(Rectangle, f32) -> f32,
+    ) {
+        struct Scroll<F> {
+            offset: F,
+            content_bounds: Rectangle,
+        }
+
+        impl<F> Operation for Scroll<F>
+        where
+            F: FnMut(Rectangle, f32) -> f32,
+        {
+            fn container(
+                &mut self,
+                _id: Option<&Id>,
+                _bounds: Rectangle,
+                operate_on_children: &mut dyn FnMut(&mut dyn Operation),
+            ) {
+                operate_on_children(self)
+            }
+
+            fn scrollable(
+                &mut self,
+                state: &mut dyn operation::Scrollable,
+                _id: Option<&Id>,
+                bounds: Rectangle,
+            ) {
+                let offset = state.offset(bounds, self.content_bounds);
+
+                state.scroll_to((self.offset)(bounds, offset));
+            }
+        }
+
+        let content_bounds = layout
+            .children()
+            .next()
+            .and_then(|scrollable| scrollable.children().next())
+            .map(|content| content.bounds())
+            .unwrap_or_else(|| layout.bounds());
+
+        self.container.operate(
+            layout,
+            &mut Scroll {
+                offset,
+                content_bounds,
+            },
+        );
+    }
+}
+
 impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
     for Overlay<'a, Message, Renderer>
 where
@@ -223,14 +333,55 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
-        self.container.on_event(
+        if let Event::Keyboard(keyboard::Event::KeyPressed {
+            key_code, ..
+        }) = event
+        {
+            let direction = match key_code {
+                keyboard::KeyCode::PageUp => -1.0,
+                keyboard::KeyCode::PageDown => 1.0,
+                _ => 0.0,
+            };
+
+            if direction != 0.0 {
+                self.scroll(layout, |bounds, offset| {
+                    offset + direction * bounds.height
+                });
+
+                return event::Status::Captured;
+            }
+        }
+
+        let event_status = self.container.on_event(
             event.clone(),
             layout,
             cursor_position,
             renderer,
             clipboard,
             messages,
-        )
+        );
+
+        if let Some(index) = self.revealed_option.take() {
+            let option_height = f32::from(
+                self.text_size.unwrap_or(renderer.default_size())
+                    + self.padding.vertical(),
+            );
+
+            let top = option_height * index as f32;
+            let bottom = top + option_height;
+
+            self.scroll(layout, |bounds, offset| {
+                if top < offset {
+                    top
+                } else if bottom > offset + bounds.height {
+                    bottom - bounds.height
+                } else {
+                    offset
+                }
+            });
+        }
+
+        event_status
     }
 
     fn draw(
@@ -261,10 +412,76 @@ struct List<'a, T, Renderer: self::Renderer> {
     options: &'a [T],
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
+    type_ahead: &'a mut TypeAhead,
+    is_searchable: bool,
+    revealed_option: Rc<Cell<Option<usize>>>,
     padding: Padding,
     text_size: Option<u16>,
     font: Renderer::Font,
-    style: <Renderer as self::Renderer>::Style,
+    style: Rc<<Renderer as self::Renderer>::Style>,
+}
+
+impl<'a, T, Renderer> List<'a, T, Renderer>
+where
+    T: ToString,
+    Renderer: self::Renderer,
+{
+    /// Highlights the option with the given index, scrolling the list to
+    /// keep it visible.
+    fn highlight(&mut self, index: usize) {
+        *self.hovered_option = Some(index);
+        self.revealed_option.set(Some(index));
+    }
+
+    /// Finds the option starting with the characters typed recently, after
+    /// typing the given one.
+    ///
+    /// Typing the same letter repeatedly cycles through the options starting
+    /// with it.
+    fn type_ahead(&mut self, c: char) -> Option<usize> {
+        let now = Instant::now();
+
+        let is_expired = match self.type_ahead.last_input {
+            Some(last_input) => now - last_input > TYPE_AHEAD_TIMEOUT,
+            None => true,
+        };
+
+        if is_expired {
+            self.type_ahead.query.clear();
+        }
+
+        if self.type_ahead.query.is_empty() && c.is_whitespace() {
+            return None;
+        }
+
+        self.type_ahead.query.extend(c.to_lowercase());
+        self.type_ahead.last_input = Some(now);
+
+        let query = &self.type_ahead.query;
+        let first = query.chars().next()?;
+
+        let (prefix, start) = if query.chars().all(|c| c == first) {
+            let next = match *self.hovered_option {
+                Some(index) => index + 1,
+                None => 0,
+            };
+
+            (first.to_string(), next)
+        } else {
+            (query.clone(), self.hovered_option.unwrap_or(0))
+        };
+
+        let options = self.options;
+
+        (0..options.len())
+            .map(|i| (start + i) % options.len())
+            .find(|&index| {
+                options[index]
+                    .to_string()
+                    .to_lowercase()
+                    .starts_with(&prefix)
+            })
+    }
 }
 
 impl<'a, T, Message, Renderer: self::Renderer> Widget<Message, Renderer>
@@ -370,6 +587,52 @@ where
                     }
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if !self.options.is_empty() => {
+                let last = self.options.len() - 1;
+
+                let index = match key_code {
+                    keyboard::KeyCode::Up => match *self.hovered_option {
+                        Some(index) => index.saturating_sub(1),
+                        None => last,
+                    },
+                    keyboard::KeyCode::Down => match *self.hovered_option {
+                        Some(index) => (index + 1).min(last),
+                        None => 0,
+                    },
+                    keyboard::KeyCode::Home if self.is_searchable => 0,
+                    keyboard::KeyCode::End if self.is_searchable => last,
+                    keyboard::KeyCode::Enter => {
+                        self.type_ahead.query.clear();
+
+                        if let Some(index) = *self.hovered_option {
+                            if let Some(option) = self.options.get(index) {
+                                *self.last_selection = Some(option.clone());
+
+                                return event::Status::Captured;
+                            }
+                        }
+
+                        return event::Status::Ignored;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                self.type_ahead.query.clear();
+                self.highlight(index);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.is_searchable && !c.is_control() =>
+            {
+                if let Some(index) = self.type_ahead(c) {
+                    self.highlight(index);
+
+                    return event::Status::Captured;
+                }
+            }
             _ => {}
         }
 
@@ -409,7 +672,7 @@ pub trait Renderer:
     scrollable::Renderer + container::Renderer + text::Renderer
 {
     /// The [`Menu`] style supported by this renderer.
-    type Style: Default + Clone;
+    type Style: Default;
 
     /// Decorates a the list of options of a [`Menu`].
     ///
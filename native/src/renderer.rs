@@ -41,6 +41,17 @@ pub trait Renderer: Sized {
     /// This type can be leveraged to implement style inheritance.
     type Defaults: Default;
 
+    /// Returns the [`Defaults`] used to draw the root of a user interface.
+    ///
+    /// By default, it returns the [`Default`] value of the [`Defaults`].
+    /// Renderers with a notion of a theme may override this to derive them
+    /// from it.
+    ///
+    /// [`Defaults`]: Self::Defaults
+    fn defaults(&self) -> Self::Defaults {
+        Self::Defaults::default()
+    }
+
     /// Lays out the elements of a user interface.
     ///
     /// You should override this if you need to perform any operations before or
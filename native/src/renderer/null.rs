@@ -1,8 +1,8 @@
 use crate::{
-    button, checkbox, column, container, pane_grid, progress_bar, radio, row,
-    scrollable, slider, text, text_input, toggler, Color, Element, Font,
-    HorizontalAlignment, Layout, Padding, Point, Rectangle, Renderer, Size,
-    VerticalAlignment,
+    button, checkbox, column, container, number_input, pane_grid, progress_bar,
+    radio, row, scrollable, slider, text, text_input, toggler, Color, Element,
+    Font, HorizontalAlignment, Layout, Padding, Point, Rectangle, Renderer,
+    Size, VerticalAlignment,
 };
 
 /// A renderer that does nothing.
@@ -138,12 +138,36 @@ impl text_input::Renderer for Null {
         _size: u16,
         _placeholder: &str,
         _value: &text_input::Value,
+        _mask: &str,
         _state: &text_input::State,
+        _is_disabled: bool,
+        _is_read_only: bool,
+        _is_invalid: bool,
         _style: &Self::Style,
     ) -> Self::Output {
     }
 }
 
+impl number_input::Renderer for Null {
+    type Style = ();
+
+    fn text_input_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _decrement_bounds: Rectangle,
+        _increment_bounds: Rectangle,
+        _cursor_position: Point,
+        _can_decrement: bool,
+        _can_increment: bool,
+        _is_disabled: bool,
+        _input: Self::Output,
+        _style: &(),
+    ) {
+    }
+}
+
 impl button::Renderer for Null {
     const DEFAULT_PADDING: Padding = Padding::ZERO;
 
@@ -174,6 +198,7 @@ impl radio::Renderer for Null {
         _bounds: Rectangle,
         _is_selected: bool,
         _is_mouse_over: bool,
+        _is_disabled: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -191,6 +216,7 @@ impl checkbox::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _is_disabled: bool,
         _label: Self::Output,
         _style: &Self::Style,
     ) {
@@ -209,6 +235,7 @@ impl slider::Renderer for Null {
         _range: std::ops::RangeInclusive<f32>,
         _value: f32,
         _is_dragging: bool,
+        _is_disabled: bool,
         _style_sheet: &Self::Style,
     ) {
     }
@@ -299,6 +326,7 @@ impl toggler::Renderer for Null {
         _bounds: Rectangle,
         _is_checked: bool,
         _is_mouse_over: bool,
+        _is_disabled: bool,
         _label: Option<Self::Output>,
         _style: &Self::Style,
     ) {
@@ -4,8 +4,8 @@ use crate::Hasher;
 
 /// A native runtime with a generic executor and receiver of results.
 ///
-/// It can be used by shells to easily spawn a [`Command`] or track a
-/// [`Subscription`].
+/// It can be used by shells to easily spawn the futures of a [`Command`] or
+/// track a [`Subscription`].
 ///
 /// [`Command`]: crate::Command
 /// [`Subscription`]: crate::Subscription
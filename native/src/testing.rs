@@ -0,0 +1,13 @@
+//! Test your user interfaces without a window.
+//!
+//! A [`Simulator`] runs a [`Program`] headlessly using the testing
+//! [`Renderer`]. It lets you find widgets by the text they display, simulate
+//! clicks, typing and key presses, and then assert on the messages produced
+//! and the resulting state of your [`Program`].
+//!
+//! [`Program`]: crate::Program
+mod renderer;
+mod simulator;
+
+pub use renderer::{Renderer, Text};
+pub use simulator::{simulator, Error, Simulator};
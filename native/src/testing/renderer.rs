@@ -0,0 +1,575 @@
+use crate::overlay::menu;
+use crate::widget::{
+    button, checkbox, column, combo_box, container, number_input, pane_grid,
+    pick_list, progress_bar, radio, row, rule, scrollable, slider, space, text,
+    text_input, toggler,
+};
+use crate::{
+    Color, Element, Font, HorizontalAlignment, Layout, Padding, Point,
+    Rectangle, Size, Vector, VerticalAlignment,
+};
+
+/// The width of a character relative to the size of the text.
+const CHARACTER_WIDTH: f32 = 0.5;
+
+/// A headless renderer that measures text and records where it is drawn.
+///
+/// Text is measured by assuming every character is half as wide as the size
+/// of the text, which makes layouts predictable in tests.
+///
+/// Instead of drawing anything, the [`Renderer`] produces a list of all the
+/// [`Text`] visible in the user interface, together with its bounds.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Renderer;
+
+impl Renderer {
+    /// Creates a new [`Renderer`].
+    pub fn new() -> Self {
+        Renderer
+    }
+}
+
+/// A piece of text drawn by the testing [`Renderer`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Text {
+    /// The content of the [`Text`].
+    pub content: String,
+
+    /// The bounds of the [`Text`].
+    ///
+    /// For interactive widgets, like a text input, these are the bounds of
+    /// the whole widget.
+    pub bounds: Rectangle,
+}
+
+fn text_width(content: &str, size: u16) -> f32 {
+    content.chars().count() as f32 * f32::from(size) * CHARACTER_WIDTH
+}
+
+impl crate::Renderer for Renderer {
+    type Output = Vec<Text>;
+    type Defaults = ();
+
+    fn overlay(
+        &mut self,
+        mut base: Vec<Text>,
+        overlay: Vec<Text>,
+        _overlay_bounds: Rectangle,
+    ) -> Vec<Text> {
+        base.extend(overlay);
+        base
+    }
+}
+
+impl column::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Self::Output {
+        content
+            .iter()
+            .zip(layout.children())
+            .flat_map(|(child, layout)| {
+                child.draw(self, defaults, layout, cursor_position, viewport)
+            })
+            .collect()
+    }
+}
+
+impl row::Renderer for Renderer {
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[Element<'_, Message, Self>],
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Self::Output {
+        content
+            .iter()
+            .zip(layout.children())
+            .flat_map(|(child, layout)| {
+                child.draw(self, defaults, layout, cursor_position, viewport)
+            })
+            .collect()
+    }
+}
+
+impl text::Renderer for Renderer {
+    type Font = Font;
+
+    fn default_size(&self) -> u16 {
+        20
+    }
+
+    fn measure(
+        &self,
+        content: &str,
+        size: u16,
+        _font: Font,
+        bounds: Size,
+    ) -> (f32, f32) {
+        let lines = content.lines();
+        let line_count = lines.clone().count().max(1);
+
+        let width = lines
+            .map(|line| text_width(line, size))
+            .fold(0.0, f32::max)
+            .min(bounds.width);
+
+        (width, line_count as f32 * f32::from(size))
+    }
+
+    fn draw(
+        &mut self,
+        _defaults: &Self::Defaults,
+        bounds: Rectangle,
+        content: &str,
+        _size: u16,
+        _font: Font,
+        _color: Option<Color>,
+        _horizontal_alignment: HorizontalAlignment,
+        _vertical_alignment: VerticalAlignment,
+    ) -> Self::Output {
+        vec![Text {
+            content: content.to_string(),
+            bounds,
+        }]
+    }
+}
+
+impl scrollable::Renderer for Renderer {
+    type Style = ();
+
+    fn scrollbar(
+        &self,
+        _bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _offset: u32,
+        _scrollbar_width: u16,
+        _scrollbar_margin: u16,
+        _scroller_width: u16,
+    ) -> Option<scrollable::Scrollbar> {
+        None
+    }
+
+    fn draw(
+        &mut self,
+        _scrollable: &scrollable::State,
+        bounds: Rectangle,
+        _content_bounds: Rectangle,
+        _is_mouse_over: bool,
+        _is_mouse_over_scrollbar: bool,
+        _scrollbar: Option<scrollable::Scrollbar>,
+        offset: u32,
+        _style: &Self::Style,
+        content: Self::Output,
+    ) -> Self::Output {
+        let translation = Vector::new(0.0, -(offset as f32));
+
+        content
+            .into_iter()
+            .map(|text| Text {
+                bounds: text.bounds + translation,
+                ..text
+            })
+            .filter(|text| bounds.intersection(&text.bounds).is_some())
+            .collect()
+    }
+}
+
+impl menu::Renderer for Renderer {
+    type Style = ();
+
+    fn decorate(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _style: &(),
+        primitive: Self::Output,
+    ) -> Self::Output {
+        primitive
+    }
+
+    fn draw<T: ToString>(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+        options: &[T],
+        _hovered_option: Option<usize>,
+        padding: Padding,
+        text_size: u16,
+        _font: Font,
+        _style: &(),
+    ) -> Self::Output {
+        let option_height = f32::from(text_size + padding.vertical());
+
+        options
+            .iter()
+            .enumerate()
+            .map(|(i, option)| Text {
+                content: option.to_string(),
+                bounds: Rectangle {
+                    y: bounds.y + option_height * i as f32,
+                    height: option_height,
+                    ..bounds
+                },
+            })
+            .collect()
+    }
+}
+
+impl pick_list::Renderer for Renderer {
+    const DEFAULT_PADDING: Padding = Padding::ZERO;
+
+    type Style = ();
+
+    fn menu_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _cursor_position: Point,
+        selected: Option<String>,
+        _padding: Padding,
+        _text_size: u16,
+        _font: Font,
+        _is_disabled: bool,
+        _style: &(),
+    ) -> Self::Output {
+        vec![Text {
+            content: selected.unwrap_or_default(),
+            bounds,
+        }]
+    }
+}
+
+impl combo_box::Renderer for Renderer {
+    type Style = ();
+
+    fn text_input_style(_style: &()) {}
+
+    fn menu_style(_style: &()) {}
+}
+
+impl text_input::Renderer for Renderer {
+    type Style = ();
+
+    fn measure_value(&self, value: &str, size: u16, _font: Font) -> f32 {
+        text_width(value, size)
+    }
+
+    fn offset(
+        &self,
+        _text_bounds: Rectangle,
+        _font: Font,
+        _size: u16,
+        _value: &text_input::Value,
+        _state: &text_input::State,
+    ) -> f32 {
+        0.0
+    }
+
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        _text_bounds: Rectangle,
+        _cursor_position: Point,
+        _font: Font,
+        _size: u16,
+        placeholder: &str,
+        value: &text_input::Value,
+        _mask: &str,
+        _state: &text_input::State,
+        _is_disabled: bool,
+        _is_read_only: bool,
+        _is_invalid: bool,
+        _style: &Self::Style,
+    ) -> Self::Output {
+        let content = if value.is_empty() {
+            placeholder.to_string()
+        } else {
+            value.to_string()
+        };
+
+        vec![Text { content, bounds }]
+    }
+}
+
+impl number_input::Renderer for Renderer {
+    type Style = ();
+
+    fn text_input_style(_style: &()) {}
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        increment_bounds: Rectangle,
+        _cursor_position: Point,
+        _can_decrement: bool,
+        _can_increment: bool,
+        _is_disabled: bool,
+        mut input: Self::Output,
+        _style: &(),
+    ) -> Self::Output {
+        input.push(Text {
+            content: String::from("-"),
+            bounds: decrement_bounds,
+        });
+
+        input.push(Text {
+            content: String::from("+"),
+            bounds: increment_bounds,
+        });
+
+        input
+    }
+}
+
+impl button::Renderer for Renderer {
+    const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        cursor_position: Point,
+        _is_disabled: bool,
+        _is_pressed: bool,
+        _style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        content.draw(self, defaults, content_layout, cursor_position, &bounds)
+    }
+}
+
+impl radio::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_selected: bool,
+        _is_mouse_over: bool,
+        _is_disabled: bool,
+        label: Self::Output,
+        _style: &Self::Style,
+    ) -> Self::Output {
+        label
+    }
+}
+
+impl checkbox::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+    const DEFAULT_SPACING: u16 = 15;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_checked: bool,
+        _is_mouse_over: bool,
+        _is_disabled: bool,
+        label: Self::Output,
+        _style: &Self::Style,
+    ) -> Self::Output {
+        label
+    }
+}
+
+impl toggler::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_SIZE: u16 = 20;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _is_checked: bool,
+        _is_mouse_over: bool,
+        _is_disabled: bool,
+        label: Option<Self::Output>,
+        _style: &Self::Style,
+    ) -> Self::Output {
+        label.unwrap_or_default()
+    }
+}
+
+impl slider::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 30;
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _cursor_position: Point,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _is_dragging: bool,
+        _is_disabled: bool,
+        _style_sheet: &Self::Style,
+    ) -> Self::Output {
+        Vec::new()
+    }
+}
+
+impl progress_bar::Renderer for Renderer {
+    type Style = ();
+
+    const DEFAULT_HEIGHT: u16 = 30;
+
+    fn draw(
+        &self,
+        _bounds: Rectangle,
+        _range: std::ops::RangeInclusive<f32>,
+        _value: f32,
+        _style: &Self::Style,
+    ) -> Self::Output {
+        Vec::new()
+    }
+}
+
+impl space::Renderer for Renderer {
+    fn draw(&mut self, _bounds: Rectangle) -> Self::Output {
+        Vec::new()
+    }
+}
+
+impl rule::Renderer for Renderer {
+    type Style = ();
+
+    fn draw(
+        &mut self,
+        _bounds: Rectangle,
+        _style: &Self::Style,
+        _is_horizontal: bool,
+    ) -> Self::Output {
+        Vec::new()
+    }
+}
+
+impl container::Renderer for Renderer {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        _style: &Self::Style,
+        content: &Element<'_, Message, Self>,
+        content_layout: Layout<'_>,
+    ) -> Self::Output {
+        content.draw(self, defaults, content_layout, cursor_position, viewport)
+    }
+}
+
+impl pane_grid::Renderer for Renderer {
+    type Style = ();
+
+    fn draw<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        content: &[(pane_grid::Pane, pane_grid::Content<'_, Message, Self>)],
+        _dragging: Option<(pane_grid::Pane, Point)>,
+        _resizing: Option<(pane_grid::Axis, Rectangle, bool)>,
+        layout: Layout<'_>,
+        _style: &<Self as pane_grid::Renderer>::Style,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Self::Output {
+        content
+            .iter()
+            .zip(layout.children())
+            .flat_map(|((_, pane), layout)| {
+                pane.draw(self, defaults, layout, cursor_position, viewport)
+            })
+            .collect()
+    }
+
+    fn draw_pane<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        bounds: Rectangle,
+        _style: &<Self as container::Renderer>::Style,
+        title_bar: Option<(
+            &pane_grid::TitleBar<'_, Message, Self>,
+            Layout<'_>,
+        )>,
+        body: (&Element<'_, Message, Self>, Layout<'_>),
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Self::Output {
+        let (body, body_layout) = body;
+
+        let mut output = match title_bar {
+            Some((title_bar, title_bar_layout)) => title_bar.draw(
+                self,
+                defaults,
+                title_bar_layout,
+                cursor_position,
+                viewport,
+                bounds.contains(cursor_position),
+            ),
+            None => Vec::new(),
+        };
+
+        output.extend(body.draw(
+            self,
+            defaults,
+            body_layout,
+            cursor_position,
+            viewport,
+        ));
+
+        output
+    }
+
+    fn draw_title_bar<Message>(
+        &mut self,
+        defaults: &Self::Defaults,
+        _bounds: Rectangle,
+        _style: &<Self as container::Renderer>::Style,
+        content: (&Element<'_, Message, Self>, Layout<'_>),
+        controls: Option<(&Element<'_, Message, Self>, Layout<'_>)>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) -> Self::Output {
+        let (content, content_layout) = content;
+
+        let mut output = content.draw(
+            self,
+            defaults,
+            content_layout,
+            cursor_position,
+            viewport,
+        );
+
+        if let Some((controls, controls_layout)) = controls {
+            output.extend(controls.draw(
+                self,
+                defaults,
+                controls_layout,
+                cursor_position,
+                viewport,
+            ));
+        }
+
+        output
+    }
+}
@@ -0,0 +1,588 @@
+use crate::clipboard;
+use crate::command::{self, Command};
+use crate::keyboard;
+use crate::mouse;
+use crate::program::Program;
+use crate::testing::{Renderer, Text};
+use crate::widget::{self, operation, Id};
+use crate::window;
+use crate::{Cache, Event, Point, Rectangle, Size, UserInterface};
+
+use iced_futures::futures::task::{self, ArcWake};
+use iced_futures::futures::{FutureExt, Stream, StreamExt};
+use iced_futures::Executor;
+
+use std::fmt;
+use std::rc::Rc;
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// A headless runner of a [`Program`] that can simulate user interactions.
+///
+/// A [`Simulator`] builds the user interface of a [`Program`] with the
+/// testing [`Renderer`], feeds it events, and updates the [`Program`] with
+/// every message produced. Any [`Command`] returned by the [`Program`] is run
+/// to completion on the current thread, which keeps tests deterministic.
+///
+/// A [`Command`] that does not finish within the [`timeout`] of the
+/// [`Simulator`], like an endless stream, produces an [`Error::Timeout`].
+/// Futures that need a specific runtime, like `tokio` timers, can be run by
+/// setting the [`executor`] of the [`Simulator`].
+///
+/// [`timeout`]: Self::timeout
+/// [`executor`]: Self::executor
+///
+/// All the messages produced are recorded, so you can assert on them together
+/// with the state of the [`Program`].
+#[allow(missing_debug_implementations)]
+pub struct Simulator<P>
+where
+    P: Program<Renderer = Renderer>,
+{
+    program: P,
+    clipboard: P::Clipboard,
+    renderer: Renderer,
+    size: Size,
+    cursor_position: Point,
+    cache: Option<Cache>,
+    output: Vec<Text>,
+    messages: Vec<P::Message>,
+    timeout: Duration,
+    enter: Rc<Enter>,
+}
+
+/// Runs a closure inside of an [`Executor`].
+type Enter = dyn Fn(&mut dyn FnMut());
+
+/// An error produced by a [`Simulator`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// No text with the given content is visible.
+    NotFound(String),
+    /// No widget with the given [`Id`] exists.
+    IdNotFound(Id),
+    /// A [`Command`] did not finish within the given time.
+    Timeout(Duration),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound(content) => {
+                write!(f, "no visible text matches {:?}", content)
+            }
+            Error::IdNotFound(id) => {
+                write!(f, "no widget has the id {:?}", id)
+            }
+            Error::Timeout(timeout) => {
+                write!(f, "a command did not finish within {:?}", timeout)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Creates a [`Simulator`] for the given [`Program`], using no clipboard and
+/// a window of 400x300 pixels.
+pub fn simulator<P>(program: P) -> Simulator<P>
+where
+    P: Program<Renderer = Renderer, Clipboard = clipboard::Null>,
+    P::Message: Clone,
+{
+    Simulator::new(program, clipboard::Null, Size::new(400.0, 300.0))
+}
+
+impl<P> Simulator<P>
+where
+    P: Program<Renderer = Renderer>,
+    P::Message: Clone,
+{
+    /// The default amount of time a [`Command`] can take to finish.
+    pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(5);
+
+    /// Creates a new [`Simulator`] for the given [`Program`], using the
+    /// provided clipboard and window size.
+    pub fn new(program: P, clipboard: P::Clipboard, size: Size) -> Self {
+        let mut simulator = Simulator {
+            program,
+            clipboard,
+            renderer: Renderer::new(),
+            size,
+            cursor_position: Point::new(-1.0, -1.0),
+            cache: Some(Cache::default()),
+            output: Vec::new(),
+            messages: Vec::new(),
+            timeout: Self::DEFAULT_TIMEOUT,
+            enter: Rc::new(|f| f()),
+        };
+
+        simulator.redraw();
+        simulator
+    }
+
+    /// Sets the maximum amount of time a [`Command`] can take to finish.
+    ///
+    /// Defaults to [`DEFAULT_TIMEOUT`].
+    ///
+    /// [`DEFAULT_TIMEOUT`]: Self::DEFAULT_TIMEOUT
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets the [`Executor`] the [`Program`] is updated in.
+    ///
+    /// Commands are still run on the current thread, but inside of the
+    /// [`Executor`]. This lets them use the timers of a specific runtime.
+    pub fn executor<E: Executor + 'static>(mut self, executor: E) -> Self {
+        self.enter = Rc::new(move |f| executor.enter(f));
+        self
+    }
+
+    /// Returns a reference to the [`Program`] of the [`Simulator`].
+    pub fn program(&self) -> &P {
+        &self.program
+    }
+
+    /// Returns all the messages produced so far.
+    pub fn messages(&self) -> &[P::Message] {
+        &self.messages
+    }
+
+    /// Takes all the messages produced so far, leaving none behind.
+    pub fn take_messages(&mut self) -> Vec<P::Message> {
+        std::mem::take(&mut self.messages)
+    }
+
+    /// Returns all the [`Text`] currently visible, in drawing order.
+    pub fn texts(&self) -> &[Text] {
+        &self.output
+    }
+
+    /// Finds the topmost visible [`Text`] with the given content.
+    pub fn find(&self, content: &str) -> Result<&Text, Error> {
+        self.output
+            .iter()
+            .rev()
+            .find(|text| text.content == content)
+            .ok_or_else(|| Error::NotFound(content.to_string()))
+    }
+
+    /// Returns the bounds of the widget with the given [`Id`].
+    pub fn bounds(&mut self, id: Id) -> Result<Rectangle, Error> {
+        self.operate(operation::bounds(id.clone()))
+            .ok_or(Error::IdNotFound(id))
+    }
+
+    /// Applies a [`widget::Operation`] to the user interface and returns its
+    /// result, if any.
+    pub fn operate<T>(
+        &mut self,
+        mut operation: impl widget::Operation<T>,
+    ) -> Option<T> {
+        self.apply(&mut operation);
+        self.redraw();
+
+        operation.finish()
+    }
+
+    /// Clicks the center of the topmost visible [`Text`] with the given
+    /// content.
+    pub fn click(&mut self, content: &str) -> Result<(), Error> {
+        let position = self.find(content)?.bounds.center();
+
+        self.click_at(position)
+    }
+
+    /// Moves the mouse cursor to the given position and clicks the left
+    /// mouse button.
+    pub fn click_at(&mut self, position: Point) -> Result<(), Error> {
+        self.simulate(vec![
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left)),
+        ])
+    }
+
+    /// Types the given text, one character at a time.
+    pub fn type_text(&mut self, text: &str) -> Result<(), Error> {
+        self.simulate(
+            text.chars()
+                .map(|c| Event::Keyboard(keyboard::Event::CharacterReceived(c)))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    /// Presses and releases the given key.
+    pub fn press_key(
+        &mut self,
+        key_code: keyboard::KeyCode,
+    ) -> Result<(), Error> {
+        let modifiers = keyboard::Modifiers::default();
+
+        self.simulate(vec![
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }),
+            Event::Keyboard(keyboard::Event::KeyReleased {
+                key_code,
+                modifiers,
+            }),
+        ])
+    }
+
+    /// Processes the given events in order.
+    ///
+    /// The user interface is rebuilt after every event, and the [`Program`]
+    /// is updated with any messages produced in the process. Window resize
+    /// events also change the size of the simulated window.
+    pub fn simulate(
+        &mut self,
+        events: impl IntoIterator<Item = Event>,
+    ) -> Result<(), Error> {
+        for event in events {
+            match event {
+                Event::Mouse(mouse::Event::CursorMoved { position }) => {
+                    self.cursor_position = position;
+                }
+                Event::Window(window::Event::Resized { width, height }) => {
+                    self.size = Size::new(width as f32, height as f32);
+                }
+                _ => {}
+            }
+
+            let mut messages = Vec::new();
+            let mut user_interface = UserInterface::build(
+                self.program.view(),
+                self.size,
+                self.cache.take().unwrap_or_default(),
+                &mut self.renderer,
+            );
+
+            let _ = user_interface.update(
+                &[event],
+                self.cursor_position,
+                &self.renderer,
+                &mut self.clipboard,
+                &mut messages,
+            );
+
+            self.cache = Some(user_interface.into_cache());
+            self.process(messages)?;
+            self.redraw();
+        }
+
+        Ok(())
+    }
+
+    /// Updates the [`Program`] with the given message, as if it was produced
+    /// by the user interface.
+    pub fn send(&mut self, message: P::Message) -> Result<(), Error> {
+        self.process(vec![message])?;
+        self.redraw();
+
+        Ok(())
+    }
+
+    fn process(&mut self, mut messages: Vec<P::Message>) -> Result<(), Error> {
+        let enter = self.enter.clone();
+        let mut result = Ok(());
+
+        enter(&mut || result = self.update(std::mem::take(&mut messages)));
+
+        result
+    }
+
+    fn update(&mut self, mut messages: Vec<P::Message>) -> Result<(), Error> {
+        while !messages.is_empty() {
+            for message in std::mem::take(&mut messages) {
+                self.messages.push(message.clone());
+
+                let command = self.program.update(message, &mut self.clipboard);
+
+                messages.extend(self.run(command)?);
+            }
+        }
+
+        Ok(())
+    }
+
+    fn run(
+        &mut self,
+        command: Command<P::Message>,
+    ) -> Result<Vec<P::Message>, Error> {
+        let mut messages = Vec::new();
+
+        for action in command.actions() {
+            match action {
+                command::Action::Future(future) => {
+                    messages.extend(self.collect(future.into_stream())?);
+                }
+                command::Action::Stream(stream) => {
+                    messages.extend(self.collect(stream)?);
+                }
+                command::Action::Window(action) => match action {
+                    window::Action::FetchSize(tag) => {
+                        messages.push(tag(self.size));
+                    }
+                    window::Action::FetchPosition(tag) => {
+                        messages.push(tag(None));
+                    }
+                    window::Action::FetchScaleFactor(tag) => {
+                        messages.push(tag(1.0));
+                    }
+                    _ => {}
+                },
+                command::Action::Widget(mut operation) => {
+                    self.apply(operation.as_mut());
+
+                    messages.extend(operation.finish());
+                }
+            }
+        }
+
+        Ok(messages)
+    }
+
+    /// Polls the given stream on the current thread until it ends, unless it
+    /// takes longer than the timeout of the [`Simulator`].
+    fn collect<T>(
+        &self,
+        mut stream: impl Stream<Item = T> + Unpin,
+    ) -> Result<Vec<T>, Error> {
+        struct Unpark(thread::Thread);
+
+        impl ArcWake for Unpark {
+            fn wake_by_ref(arc_self: &Arc<Self>) {
+                arc_self.0.unpark();
+            }
+        }
+
+        let deadline = Instant::now() + self.timeout;
+        let waker = task::waker(Arc::new(Unpark(thread::current())));
+        let mut context = task::Context::from_waker(&waker);
+        let mut items = Vec::new();
+
+        loop {
+            let now = Instant::now();
+
+            if now >= deadline {
+                return Err(Error::Timeout(self.timeout));
+            }
+
+            match stream.poll_next_unpin(&mut context) {
+                task::Poll::Ready(Some(item)) => items.push(item),
+                task::Poll::Ready(None) => return Ok(items),
+                task::Poll::Pending => thread::park_timeout(deadline - now),
+            }
+        }
+    }
+
+    fn apply<T>(&mut self, operation: &mut dyn widget::Operation<T>) {
+        let mut user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        user_interface.operate(operation);
+
+        self.cache = Some(user_interface.into_cache());
+    }
+
+    fn redraw(&mut self) {
+        let mut user_interface = UserInterface::build(
+            self.program.view(),
+            self.size,
+            self.cache.take().unwrap_or_default(),
+            &mut self.renderer,
+        );
+
+        self.output =
+            user_interface.draw(&mut self.renderer, self.cursor_position);
+        self.cache = Some(user_interface.into_cache());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::button;
+    use crate::text_input;
+    use crate::{Button, Column, Element, Text as Label, TextInput};
+
+    const NAME: &str = "name";
+
+    #[derive(Default)]
+    struct Greeter {
+        name: String,
+        greeting: Option<String>,
+        input: text_input::State,
+        submit: button::State,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        NameChanged(String),
+        Submit,
+        Greeted(String),
+    }
+
+    impl Program for Greeter {
+        type Renderer = Renderer;
+        type Message = Message;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            message: Message,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Message> {
+            match message {
+                Message::NameChanged(name) => {
+                    self.name = name;
+
+                    Command::none()
+                }
+                Message::Submit => {
+                    let name = self.name.clone();
+
+                    Command::perform(
+                        async move { format!("Hello, {}!", name) },
+                        Message::Greeted,
+                    )
+                }
+                Message::Greeted(greeting) => {
+                    self.greeting = Some(greeting);
+
+                    Command::none()
+                }
+            }
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Renderer> {
+            let greeting = self.greeting.clone().unwrap_or_default();
+
+            Column::new()
+                .push(
+                    TextInput::new(
+                        &mut self.input,
+                        "Name",
+                        &self.name,
+                        Message::NameChanged,
+                    )
+                    .id(Id::new(NAME)),
+                )
+                .push(
+                    Button::new(&mut self.submit, Label::new("Greet"))
+                        .on_press(Message::Submit),
+                )
+                .push(Label::new(greeting))
+                .into()
+        }
+    }
+
+    #[test]
+    fn simulates_typing_and_clicking() {
+        let mut simulator = simulator(Greeter::default());
+
+        simulator.click("Name").unwrap();
+        simulator.type_text("Iced").unwrap();
+        simulator.click("Greet").unwrap();
+
+        assert_eq!(
+            simulator.take_messages(),
+            vec![
+                Message::NameChanged(String::from("I")),
+                Message::NameChanged(String::from("Ic")),
+                Message::NameChanged(String::from("Ice")),
+                Message::NameChanged(String::from("Iced")),
+                Message::Submit,
+                Message::Greeted(String::from("Hello, Iced!")),
+            ]
+        );
+
+        assert!(simulator.find("Hello, Iced!").is_ok());
+        assert_eq!(simulator.program().name, "Iced");
+        assert_eq!(
+            simulator.click("Missing"),
+            Err(Error::NotFound(String::from("Missing")))
+        );
+    }
+
+    #[test]
+    fn operates_on_widgets_by_id() {
+        let mut simulator = simulator(Greeter::default());
+
+        assert_eq!(
+            simulator.bounds(Id::new(NAME)).map(|bounds| bounds.y),
+            Ok(0.0)
+        );
+        assert_eq!(
+            simulator.bounds(Id::new("missing")),
+            Err(Error::IdNotFound(Id::new("missing")))
+        );
+        assert_eq!(
+            simulator.operate(operation::focusable::find_focused()),
+            None
+        );
+
+        simulator.click("Name").unwrap();
+
+        assert_eq!(
+            simulator.operate(operation::focusable::find_focused()),
+            Some(Id::new(NAME))
+        );
+    }
+
+    #[test]
+    fn times_out_endless_commands() {
+        use iced_futures::futures::stream;
+
+        #[derive(Default)]
+        struct Ticker {
+            start: button::State,
+        }
+
+        #[derive(Debug, Clone)]
+        enum Message {
+            Start,
+            Tick,
+        }
+
+        impl Program for Ticker {
+            type Renderer = Renderer;
+            type Message = Message;
+            type Clipboard = clipboard::Null;
+
+            fn update(
+                &mut self,
+                message: Message,
+                _clipboard: &mut clipboard::Null,
+            ) -> Command<Message> {
+                match message {
+                    Message::Start => {
+                        Command::run(stream::repeat(()), |_| Message::Tick)
+                    }
+                    Message::Tick => Command::none(),
+                }
+            }
+
+            fn view(&mut self) -> Element<'_, Message, Renderer> {
+                Button::new(&mut self.start, Label::new("Start"))
+                    .on_press(Message::Start)
+                    .into()
+            }
+        }
+
+        let timeout = Duration::from_millis(10);
+        let mut simulator = simulator(Ticker::default()).timeout(timeout);
+
+        assert_eq!(simulator.click("Start"), Err(Error::Timeout(timeout)));
+    }
+}
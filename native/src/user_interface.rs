@@ -1,6 +1,7 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Size};
 
 use std::hash::Hasher;
@@ -257,6 +258,23 @@ where
             .collect()
     }
 
+    /// Returns the [`Layout`] of the [`UserInterface`].
+    pub fn layout(&self) -> Layout<'_> {
+        Layout::new(&self.base.layout)
+    }
+
+    /// Applies a [`widget::Operation`] to the [`UserInterface`].
+    ///
+    /// The operation traverses the whole widget tree, so it can query or
+    /// update the state of any widget. Use [`widget::Operation::finish`]
+    /// afterwards to obtain its result, if any.
+    pub fn operate<T>(&mut self, operation: &mut dyn widget::Operation<T>) {
+        self.root.widget.operate(
+            Layout::new(&self.base.layout),
+            &mut widget::operation::black_box(operation),
+        );
+    }
+
     /// Draws the [`UserInterface`] with the provided [`Renderer`].
     ///
     /// It returns the some [`Renderer::Output`]. You should update the icon of
@@ -335,6 +353,7 @@ where
         cursor_position: Point,
     ) -> Renderer::Output {
         let viewport = Rectangle::with_size(self.bounds);
+        let defaults = renderer.defaults();
 
         let overlay = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout))
@@ -350,7 +369,7 @@ where
 
             let overlay_primitives = overlay.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&layer.layout),
                 cursor_position,
             );
@@ -371,7 +390,7 @@ where
 
             let base_primitives = self.root.widget.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&self.base.layout),
                 base_cursor,
                 &viewport,
@@ -385,7 +404,7 @@ where
         } else {
             self.root.widget.draw(
                 renderer,
-                &Renderer::Defaults::default(),
+                &defaults,
                 Layout::new(&self.base.layout),
                 cursor_position,
                 &viewport,
@@ -22,8 +22,11 @@
 pub mod button;
 pub mod checkbox;
 pub mod column;
+pub mod combo_box;
 pub mod container;
 pub mod image;
+pub mod number_input;
+pub mod operation;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -39,6 +42,8 @@ pub mod text_input;
 pub mod toggler;
 pub mod tooltip;
 
+mod id;
+
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
@@ -46,10 +51,14 @@ pub use checkbox::Checkbox;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
+pub use combo_box::ComboBox;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
 pub use image::Image;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
@@ -78,10 +87,14 @@ pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
 
+pub use id::Id;
+#[doc(no_inline)]
+pub use operation::Operation;
+
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
-use crate::{Clipboard, Hasher, Layout, Length, Point, Rectangle};
+use crate::{Clipboard, Hasher, Layout, Length, Padding, Point, Rectangle};
 
 /// A component that displays information and allows interaction.
 ///
@@ -115,6 +128,16 @@ where
     /// Returns the height of the [`Widget`].
     fn height(&self) -> Length;
 
+    /// Returns the padding of the [`Widget`].
+    ///
+    /// It is only used to inspect the layout of the [`Widget`] while
+    /// debugging.
+    ///
+    /// By default, it returns [`Padding::ZERO`].
+    fn padding(&self) -> Padding {
+        Padding::ZERO
+    }
+
     /// Returns the [`Node`] of the [`Widget`].
     ///
     /// This [`Node`] is used by the runtime to compute the [`Layout`] of the
@@ -181,4 +204,13 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         None
     }
+
+    /// Applies an [`Operation`] to the [`Widget`] and its children.
+    ///
+    /// Widgets with some internal state or children should describe
+    /// themselves to the [`Operation`] here.
+    ///
+    /// By default, it does nothing.
+    fn operate(&mut self, _layout: Layout<'_>, _operation: &mut dyn Operation) {
+    }
 }
@@ -6,6 +6,7 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::touch;
+use crate::widget::{Id, Operation};
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
     Widget,
@@ -30,8 +31,8 @@ use std::hash::Hash;
 ///     .on_press(Message::ButtonPressed);
 /// ```
 ///
-/// If a [`Button::on_press`] handler is not set, the resulting [`Button`] will
-/// be disabled:
+/// If a [`Button::on_press`] handler is not set, or [`Button::disabled`] is
+/// used, the resulting [`Button`] will be disabled:
 ///
 /// ```
 /// # use iced_native::{button, Text};
@@ -51,6 +52,13 @@ use std::hash::Hash;
 /// fn enabled_button(state: &mut button::State) -> Button<'_, Message> {
 ///     disabled_button(state).on_press(Message::ButtonPressed)
 /// }
+///
+/// fn toggled_button(
+///     state: &mut button::State,
+///     is_enabled: bool,
+/// ) -> Button<'_, Message> {
+///     enabled_button(state).disabled(!is_enabled)
+/// }
 /// ```
 #[allow(missing_debug_implementations)]
 pub struct Button<'a, Message, Renderer: self::Renderer> {
@@ -62,6 +70,8 @@ pub struct Button<'a, Message, Renderer: self::Renderer> {
     min_width: u32,
     min_height: u32,
     padding: Padding,
+    is_disabled: bool,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
@@ -85,6 +95,8 @@ where
             min_width: 0,
             min_height: 0,
             padding: Renderer::DEFAULT_PADDING,
+            is_disabled: false,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -126,6 +138,21 @@ where
         self
     }
 
+    /// Sets whether the [`Button`] is disabled.
+    ///
+    /// A disabled [`Button`] ignores any interaction, even if it has an
+    /// [`on_press`](Self::on_press) message.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`Button`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Button`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -160,6 +187,10 @@ where
         self.height
     }
 
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -203,6 +234,12 @@ where
             return event::Status::Captured;
         }
 
+        if self.is_disabled {
+            self.state.is_pressed = false;
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -253,7 +290,7 @@ where
             defaults,
             layout.bounds(),
             cursor_position,
-            self.on_press.is_none(),
+            self.is_disabled || self.on_press.is_none(),
             self.state.is_pressed,
             &self.style,
             &self.content,
@@ -275,6 +312,18 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let content = &mut self.content;
+
+        operation.container(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut |operation| {
+                content.operate(layout.children().next().unwrap(), operation);
+            },
+        );
+    }
 }
 
 /// The renderer of a [`Button`].
@@ -316,3 +365,70 @@ where
         Element::new(button)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Button, State};
+
+    use crate::clipboard;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::{Command, Element, Text};
+
+    #[derive(Default)]
+    struct Counter {
+        count: u32,
+        state: State,
+        is_disabled: bool,
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    struct Increment;
+
+    impl Program for Counter {
+        type Renderer = Renderer;
+        type Message = Increment;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            _message: Increment,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Increment> {
+            self.count += 1;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Increment, Renderer> {
+            Button::new(&mut self.state, Text::new("Increment"))
+                .on_press(Increment)
+                .disabled(self.is_disabled)
+                .into()
+        }
+    }
+
+    #[test]
+    fn produces_a_message_when_pressed() {
+        let mut simulator = simulator(Counter::default());
+
+        simulator.click("Increment").unwrap();
+        simulator.click("Increment").unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![Increment, Increment]);
+        assert_eq!(simulator.program().count, 2);
+    }
+
+    #[test]
+    fn ignores_presses_while_disabled() {
+        let mut simulator = simulator(Counter {
+            is_disabled: true,
+            ..Counter::default()
+        });
+
+        simulator.click("Increment").unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert_eq!(simulator.program().count, 0);
+    }
+}
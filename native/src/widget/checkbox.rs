@@ -41,6 +41,7 @@ pub struct Checkbox<Message, Renderer: self::Renderer + text::Renderer> {
     font: Renderer::Font,
     text_color: Option<Color>,
     style: Renderer::Style,
+    is_disabled: bool,
 }
 
 impl<Message, Renderer: self::Renderer + text::Renderer>
@@ -69,6 +70,7 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             font: Renderer::Font::default(),
             text_color: None,
             style: Renderer::Style::default(),
+            is_disabled: false,
         }
     }
 
@@ -115,6 +117,14 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets whether the [`Checkbox`] is disabled.
+    ///
+    /// A disabled [`Checkbox`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer>
@@ -162,6 +172,10 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -213,6 +227,7 @@ where
             checkbox_bounds,
             self.is_checked,
             is_mouse_over,
+            self.is_disabled,
             label,
             &self.style,
         )
@@ -248,12 +263,14 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Checkbox`]
     ///   * whether the [`Checkbox`] is selected or not
     ///   * whether the mouse is over the [`Checkbox`] or not
+    ///   * whether the [`Checkbox`] is disabled or not
     ///   * the drawn label of the [`Checkbox`]
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_checked: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
@@ -271,3 +288,64 @@ where
         Element::new(checkbox)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Checkbox;
+
+    use crate::clipboard;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::{Command, Element};
+
+    #[derive(Default)]
+    struct Terms {
+        is_accepted: bool,
+        is_disabled: bool,
+    }
+
+    impl Program for Terms {
+        type Renderer = Renderer;
+        type Message = bool;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            is_accepted: bool,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<bool> {
+            self.is_accepted = is_accepted;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, bool, Renderer> {
+            Checkbox::new(self.is_accepted, "Accept", |is_accepted| is_accepted)
+                .disabled(self.is_disabled)
+                .into()
+        }
+    }
+
+    #[test]
+    fn toggles_when_clicked() {
+        let mut simulator = simulator(Terms::default());
+
+        simulator.click("Accept").unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![true]);
+        assert!(simulator.program().is_accepted);
+    }
+
+    #[test]
+    fn ignores_clicks_while_disabled() {
+        let mut simulator = simulator(Terms {
+            is_disabled: true,
+            ..Terms::default()
+        });
+
+        simulator.click("Accept").unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert!(!simulator.program().is_accepted);
+    }
+}
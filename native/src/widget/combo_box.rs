@@ -0,0 +1,696 @@
+//! Display a text input with a list of suggestions to choose from.
+//!
+//! A [`ComboBox`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::overlay::menu::{self, Menu};
+use crate::text_input::{self, TextInput, Value};
+use crate::touch;
+use crate::widget::{Id, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::borrow::Cow;
+
+/// A text input that suggests the options matching its contents, and lets
+/// the user pick one of them.
+///
+/// The suggestions can be navigated with the arrow keys, picked with the
+/// enter key, and dismissed with the escape key.
+///
+/// # Example
+/// ```
+/// # use iced_native::{combo_box, testing};
+/// #
+/// # pub type ComboBox<'a, T, Message> =
+/// #     iced_native::ComboBox<'a, T, Message, testing::Renderer>;
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CountrySelected(String),
+/// }
+///
+/// let mut state = combo_box::State::default();
+/// let countries = vec![String::from("Germany"), String::from("Spain")];
+///
+/// let combo_box = ComboBox::new(
+///     &mut state,
+///     "Type a country...",
+///     &countries[..],
+///     None,
+///     Message::CountrySelected,
+/// )
+/// .fuzzy(true);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ComboBox<'a, T, Message, Renderer: self::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    text_input: &'a mut text_input::State,
+    menu: &'a mut menu::State,
+    query: &'a mut Option<String>,
+    is_open: &'a mut bool,
+    hovered_option: &'a mut Option<usize>,
+    last_selection: &'a mut Option<T>,
+    placeholder: String,
+    options: Cow<'a, [T]>,
+    suggestions: Vec<T>,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    on_free_form: Option<Box<dyn Fn(String) -> Message>>,
+    is_fuzzy: bool,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    font: Renderer::Font,
+    style: <Renderer as self::Renderer>::Style,
+    is_disabled: bool,
+    id: Option<Id>,
+}
+
+/// The local state of a [`ComboBox`].
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    text_input: text_input::State,
+    menu: menu::State,
+    query: Option<String>,
+    is_open: bool,
+    hovered_option: Option<usize>,
+    last_selection: Option<T>,
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self {
+            text_input: text_input::State::default(),
+            menu: menu::State::default(),
+            query: Option::default(),
+            is_open: bool::default(),
+            hovered_option: Option::default(),
+            last_selection: Option::default(),
+        }
+    }
+}
+
+impl<T> State<T> {
+    /// Returns whether the [`ComboBox`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: self::Renderer>
+    ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// Creates a new [`ComboBox`] with the given [`State`], a placeholder,
+    /// a list of options, the current selected value, and the message to
+    /// produce when an option is selected.
+    pub fn new(
+        state: &'a mut State<T>,
+        placeholder: &str,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        let State {
+            text_input,
+            menu,
+            query,
+            is_open,
+            hovered_option,
+            last_selection,
+        } = state;
+
+        Self {
+            text_input,
+            menu,
+            query,
+            is_open,
+            hovered_option,
+            last_selection,
+            placeholder: String::from(placeholder),
+            options: options.into(),
+            suggestions: Vec::new(),
+            selected,
+            on_selected: Box::new(on_selected),
+            on_free_form: None,
+            is_fuzzy: false,
+            width: Length::Fill,
+            padding: Padding::ZERO,
+            size: None,
+            font: Default::default(),
+            style: Default::default(),
+            is_disabled: false,
+            id: None,
+        }
+    }
+
+    /// Allows entries of the [`ComboBox`] that are not among its options.
+    ///
+    /// When the enter key is pressed and no suggestion is highlighted, the
+    /// contents of the [`ComboBox`] are turned into a message with the given
+    /// function.
+    pub fn on_free_form(
+        mut self,
+        on_free_form: impl Fn(String) -> Message + 'static,
+    ) -> Self {
+        self.on_free_form = Some(Box::new(on_free_form));
+        self
+    }
+
+    /// Sets whether the [`ComboBox`] uses fuzzy matching.
+    ///
+    /// By default, only the options containing the contents of the
+    /// [`ComboBox`] are suggested. With fuzzy matching, the options
+    /// containing its characters in the same order are suggested, with the
+    /// closest matches first.
+    pub fn fuzzy(mut self, is_fuzzy: bool) -> Self {
+        self.is_fuzzy = is_fuzzy;
+        self
+    }
+
+    /// Sets the width of the [`ComboBox`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ComboBox`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ComboBox`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`ComboBox`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`ComboBox`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the [`ComboBox`] is disabled.
+    ///
+    /// A disabled [`ComboBox`] ignores any interaction and never shows its
+    /// suggestions.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`ComboBox`].
+    ///
+    /// Its field can be focused with [`text_input::focus`] by using the same
+    /// [`Id`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
+    fn value(&self) -> String {
+        match (&self.query, &self.selected) {
+            (Some(query), _) => query.clone(),
+            (None, Some(selected)) => selected.to_string(),
+            (None, None) => String::new(),
+        }
+    }
+
+    fn filter(&self) -> Vec<T> {
+        let query = match &self.query {
+            Some(query) if !query.is_empty() => query.to_lowercase(),
+            _ => return self.options.to_vec(),
+        };
+
+        if self.is_fuzzy {
+            let mut matches: Vec<_> = self
+                .options
+                .iter()
+                .filter_map(|option| {
+                    let label = option.to_string().to_lowercase();
+
+                    fuzzy_distance(&query, &label)
+                        .map(|distance| (distance, option.clone()))
+                })
+                .collect();
+
+            matches.sort_by_key(|(distance, _)| *distance);
+
+            matches.into_iter().map(|(_, option)| option).collect()
+        } else {
+            self.options
+                .iter()
+                .filter(|option| {
+                    option.to_string().to_lowercase().contains(&query)
+                })
+                .cloned()
+                .collect()
+        }
+    }
+
+    fn select(&mut self, option: T, messages: &mut Vec<Message>) {
+        messages.push((self.on_selected)(option));
+
+        *self.query = None;
+        *self.is_open = false;
+        *self.hovered_option = None;
+    }
+
+    fn close(&mut self) {
+        *self.query = None;
+        *self.is_open = false;
+        *self.hovered_option = None;
+        *self.last_selection = None;
+    }
+}
+
+/// Returns how far apart the characters of the query are in the label, if
+/// they all appear in it in the same order.
+fn fuzzy_distance(query: &str, label: &str) -> Option<usize> {
+    let mut label = label.chars().enumerate();
+    let mut distance = 0;
+    let mut last = None;
+
+    for c in query.chars() {
+        let (index, _) = label.find(|(_, candidate)| *candidate == c)?;
+
+        distance += last.map_or(index, |last| index - last - 1);
+        last = Some(index);
+    }
+
+    Some(distance)
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for ComboBox<'a, T, Message, Renderer>
+where
+    T: Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static,
+    Renderer: self::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits
+            .pad(self.padding)
+            .width(self.width)
+            .height(Length::Units(text_size));
+
+        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
+        text.move_to(Point::new(
+            self.padding.left.into(),
+            self.padding.top.into(),
+        ));
+
+        layout::Node::with_children(text.size().pad(self.padding), vec![text])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if self.is_disabled {
+            self.text_input.unfocus();
+            self.close();
+
+            return event::Status::Ignored;
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if let Some(selection) = self.last_selection.take() {
+                    self.select(selection, messages);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.text_input.is_focused() => match key_code {
+                keyboard::KeyCode::Down
+                    if !*self.is_open && !self.filter().is_empty() =>
+                {
+                    *self.is_open = true;
+                    *self.hovered_option = Some(0);
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Enter => {
+                    if let Some(selection) = self.last_selection.take() {
+                        self.select(selection, messages);
+                    } else if let Some(on_free_form) = &self.on_free_form {
+                        if let Some(query) = self.query.take() {
+                            messages.push(on_free_form(query));
+                        }
+
+                        *self.is_open = false;
+                        *self.hovered_option = None;
+                    }
+
+                    return event::Status::Captured;
+                }
+                keyboard::KeyCode::Escape if *self.is_open => {
+                    self.close();
+
+                    return event::Status::Captured;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+
+        let value = self.value();
+        let mut edits = Vec::new();
+
+        let status = {
+            let mut text_input = TextInput::new(
+                self.text_input,
+                &self.placeholder,
+                &value,
+                Edit::Changed,
+            )
+            .font(self.font);
+
+            if let Some(size) = self.size {
+                text_input = text_input.size(size);
+            }
+
+            text_input.on_event(
+                event,
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut edits,
+            )
+        };
+
+        for Edit::Changed(query) in edits {
+            *self.query = Some(query);
+            *self.is_open = true;
+            *self.hovered_option = None;
+        }
+
+        if !self.text_input.is_focused() {
+            self.close();
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        text_input::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            layout.children().next().unwrap().bounds(),
+            cursor_position,
+            self.font,
+            self.size.unwrap_or(renderer.default_size()),
+            &self.placeholder,
+            &Value::new(&self.value()),
+            "",
+            self.text_input,
+            self.is_disabled,
+            false,
+            false,
+            &Renderer::text_input_style(&self.style),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        use std::hash::Hash as _;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let bounds = layout.children().next().unwrap().bounds();
+
+        if !self.is_disabled {
+            operation.focusable(self.text_input, self.id.as_ref(), bounds);
+        }
+
+        operation.text_input(self.text_input, self.id.as_ref(), bounds);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !*self.is_open || !self.text_input.is_focused() || self.is_disabled {
+            return None;
+        }
+
+        self.suggestions = self.filter();
+
+        if self.suggestions.is_empty() {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let mut menu = Menu::new(
+            self.menu,
+            &self.suggestions,
+            self.hovered_option,
+            self.last_selection,
+        )
+        .width(bounds.width.round() as u16)
+        .padding(self.padding)
+        .font(self.font)
+        .style(Renderer::menu_style(&self.style));
+
+        if let Some(size) = self.size {
+            menu = menu.text_size(size);
+        }
+
+        Some(menu.overlay(layout.position(), bounds.height))
+    }
+}
+
+/// The renderer of a [`ComboBox`].
+///
+/// A [`ComboBox`] is drawn as a [`TextInput`] with a [`Menu`] of
+/// suggestions, so your [renderer] only needs to choose their styles.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text_input::Renderer + menu::Renderer {
+    /// The [`ComboBox`] style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the style of the [`TextInput`] of the [`ComboBox`].
+    fn text_input_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as text_input::Renderer>::Style;
+
+    /// Returns the style of the [`Menu`] of suggestions of the [`ComboBox`].
+    fn menu_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as menu::Renderer>::Style;
+}
+
+impl<'a, T: 'a, Message, Renderer> From<ComboBox<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'static,
+    Renderer: self::Renderer + 'a,
+{
+    fn from(
+        combo_box: ComboBox<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(combo_box)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fuzzy_distance, ComboBox, State};
+
+    use crate::clipboard;
+    use crate::keyboard::KeyCode;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::text_input;
+    use crate::widget::operation::focusable;
+    use crate::widget::Id;
+    use crate::{Command, Element};
+
+    const COUNTRY: &str = "country";
+
+    const COUNTRIES: &[&str] = &["Germany", "Japan", "Paraguay", "Spain"];
+
+    #[derive(Default)]
+    struct Travel {
+        country: Option<&'static str>,
+        countries: State<&'static str>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Selected(&'static str),
+        Entered(String),
+        Focus,
+    }
+
+    impl Program for Travel {
+        type Renderer = Renderer;
+        type Message = Message;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            message: Message,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Message> {
+            match message {
+                Message::Selected(country) => {
+                    self.country = Some(country);
+
+                    Command::none()
+                }
+                Message::Entered(_) => Command::none(),
+                Message::Focus => text_input::focus(Id::new(COUNTRY)),
+            }
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Renderer> {
+            ComboBox::new(
+                &mut self.countries,
+                "Country",
+                COUNTRIES,
+                self.country,
+                Message::Selected,
+            )
+            .on_free_form(Message::Entered)
+            .id(Id::new(COUNTRY))
+            .into()
+        }
+    }
+
+    #[test]
+    fn fuzzy_distance_ranks_closer_matches_first() {
+        assert_eq!(fuzzy_distance("ger", "germany"), Some(0));
+        assert_eq!(fuzzy_distance("gmy", "germany"), Some(4));
+        assert_eq!(fuzzy_distance("ymg", "germany"), None);
+        assert_eq!(fuzzy_distance("", "germany"), Some(0));
+    }
+
+    #[test]
+    fn suggests_and_selects_options() {
+        let mut simulator = simulator(Travel::default());
+
+        simulator.click("Country").unwrap();
+        simulator.type_text("pa").unwrap();
+
+        assert!(simulator.find("Japan").is_ok());
+        assert!(simulator.find("Germany").is_err());
+
+        simulator.press_key(KeyCode::Down).unwrap();
+        simulator.press_key(KeyCode::Down).unwrap();
+        simulator.press_key(KeyCode::Enter).unwrap();
+
+        assert_eq!(simulator.program().country, Some("Paraguay"));
+        assert!(simulator.find("Japan").is_err());
+
+        simulator.click("Paraguay").unwrap();
+        simulator.press_key(KeyCode::End).unwrap();
+
+        for _ in 0..6 {
+            simulator.press_key(KeyCode::Backspace).unwrap();
+        }
+
+        simulator.click("Spain").unwrap();
+
+        assert_eq!(simulator.program().country, Some("Spain"));
+
+        simulator.click("Spain").unwrap();
+        simulator.press_key(KeyCode::End).unwrap();
+        simulator.type_text("ia").unwrap();
+        simulator.press_key(KeyCode::Enter).unwrap();
+
+        assert_eq!(
+            simulator.take_messages().last(),
+            Some(&Message::Entered(String::from("Spainia")))
+        );
+    }
+
+    #[test]
+    fn suggests_options_when_focused_by_id() {
+        let mut simulator = simulator(Travel::default());
+
+        simulator.send(Message::Focus).unwrap();
+
+        assert_eq!(
+            simulator.operate(focusable::find_focused()),
+            Some(Id::new(COUNTRY))
+        );
+
+        simulator.type_text("sp").unwrap();
+        simulator.press_key(KeyCode::Down).unwrap();
+        simulator.press_key(KeyCode::Enter).unwrap();
+
+        assert_eq!(simulator.program().country, Some("Spain"));
+    }
+}
@@ -4,6 +4,7 @@ use std::hash::Hash;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget::{Id, Operation};
 use crate::{
     Align, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
     Rectangle, Widget,
@@ -23,6 +24,7 @@ pub struct Container<'a, Message, Renderer: self::Renderer> {
     max_height: u32,
     horizontal_alignment: Align,
     vertical_alignment: Align,
+    id: Option<Id>,
     style: Renderer::Style,
     content: Element<'a, Message, Renderer>,
 }
@@ -44,6 +46,7 @@ where
             max_height: u32::MAX,
             horizontal_alignment: Align::Start,
             vertical_alignment: Align::Start,
+            id: None,
             style: Renderer::Style::default(),
             content: content.into(),
         }
@@ -103,6 +106,12 @@ where
         self
     }
 
+    /// Sets the [`Id`] of the [`Container`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -123,6 +132,10 @@ where
         self.height
     }
 
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -205,6 +218,18 @@ where
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
         self.content.overlay(layout.children().next().unwrap())
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let content = &mut self.content;
+
+        operation.container(
+            self.id.as_ref(),
+            layout.bounds(),
+            &mut |operation| {
+                content.operate(layout.children().next().unwrap(), operation);
+            },
+        );
+    }
 }
 
 /// The renderer of a [`Container`].
@@ -0,0 +1,46 @@
+use std::borrow::Cow;
+use std::sync::atomic::{self, AtomicUsize};
+
+static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+/// The identifier of a widget.
+///
+/// An [`Id`] can be used to address a specific widget with an [`Operation`],
+/// like focusing a text input or scrolling a scrollable.
+///
+/// [`Operation`]: crate::widget::Operation
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Id(Internal);
+
+impl Id {
+    /// Creates a custom [`Id`].
+    pub fn new(id: impl Into<Cow<'static, str>>) -> Self {
+        Self(Internal::Custom(id.into()))
+    }
+
+    /// Creates a unique [`Id`].
+    ///
+    /// This function produces a different [`Id`] every time it is called.
+    pub fn unique() -> Self {
+        let id = NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed);
+
+        Self(Internal::Unique(id))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Internal {
+    Unique(usize),
+    Custom(Cow<'static, str>),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Id;
+
+    #[test]
+    fn unique_ids_are_different() {
+        assert_ne!(Id::unique(), Id::unique());
+        assert_eq!(Id::new("search"), Id::new(String::from("search")));
+    }
+}
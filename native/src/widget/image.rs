@@ -1,9 +1,16 @@
 //! Display images in your user interface.
+pub mod animated;
+pub mod texture;
 pub mod viewer;
+
+pub use animated::AnimatedImage;
+pub use texture::Texture;
 pub use viewer::Viewer;
 
 use crate::layout;
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+};
 
 use std::{
     hash::{Hash, Hasher as _},
@@ -27,6 +34,9 @@ pub struct Image {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    crop: Option<Rectangle<u32>>,
 }
 
 impl Image {
@@ -36,6 +46,9 @@ impl Image {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: FilterMethod::Linear,
+            crop: None,
         }
     }
 
@@ -50,6 +63,31 @@ impl Image {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`Image`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Crops the [`Image`] to the given region, in pixels.
+    ///
+    /// Only the pixels inside of the region will be displayed and laid out,
+    /// which is useful to draw a single sprite of a sprite sheet.
+    pub fn crop(mut self, region: Rectangle<u32>) -> Self {
+        self.crop = Some(region);
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Image
@@ -69,24 +107,15 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
-
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        let source = source(renderer.dimensions(&self.handle), self.crop);
+
+        layout(
+            limits,
+            source.size(),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
     }
 
     fn draw(
@@ -97,7 +126,16 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) -> Renderer::Output {
-        renderer.draw(self.handle.clone(), layout)
+        let source = source(renderer.dimensions(&self.handle), self.crop);
+
+        draw(
+            renderer,
+            self.handle.clone(),
+            source,
+            self.content_fit,
+            self.filter_method,
+            layout.bounds(),
+        )
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -107,6 +145,139 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
+        self.crop.hash(state);
+    }
+}
+
+/// The filtering method used to sample the pixels of an image when it is
+/// scaled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Bilinear interpolation. Best for photos and smooth content.
+    Linear,
+
+    /// Nearest neighbor. Keeps the edges of pixel art sharp.
+    Nearest,
+}
+
+impl Default for FilterMethod {
+    fn default() -> Self {
+        FilterMethod::Linear
+    }
+}
+
+/// Returns the region of an image with the given dimensions that remains
+/// visible after applying the given crop, in pixels.
+pub(crate) fn source(
+    (width, height): (u32, u32),
+    crop: Option<Rectangle<u32>>,
+) -> Rectangle {
+    let region = match crop {
+        Some(crop) => {
+            let x = crop.x.min(width.saturating_sub(1));
+            let y = crop.y.min(height.saturating_sub(1));
+
+            Rectangle {
+                x,
+                y,
+                width: crop.width.min(width - x).max(1),
+                height: crop.height.min(height - y).max(1),
+            }
+        }
+        None => Rectangle {
+            x: 0,
+            y: 0,
+            width: width.max(1),
+            height: height.max(1),
+        },
+    };
+
+    Rectangle {
+        x: region.x as f32,
+        y: region.y as f32,
+        width: region.width as f32,
+        height: region.height as f32,
+    }
+}
+
+/// Computes the layout of an image of the given size.
+pub(crate) fn layout(
+    limits: &layout::Limits,
+    image_size: Size,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+) -> layout::Node {
+    let raw_size = limits.width(width).height(height).resolve(image_size);
+    let full_size = content_fit.fit(image_size, raw_size);
+
+    let final_size = Size {
+        width: match width {
+            Length::Shrink => raw_size.width.min(full_size.width),
+            _ => raw_size.width,
+        },
+        height: match height {
+            Length::Shrink => raw_size.height.min(full_size.height),
+            _ => raw_size.height,
+        },
+    };
+
+    layout::Node::new(final_size)
+}
+
+/// Draws the given region of an image inside of some bounds, applying the
+/// [`ContentFit`].
+///
+/// The image is centered and any part of it that falls outside of the bounds
+/// is cropped.
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    handle: Handle,
+    source: Rectangle,
+    content_fit: ContentFit,
+    filter_method: FilterMethod,
+    bounds: Rectangle,
+) -> Renderer::Output
+where
+    Renderer: self::Renderer,
+{
+    let (source, bounds) = fit(source, content_fit, bounds);
+
+    renderer.draw(handle, filter_method, source, bounds)
+}
+
+fn fit(
+    source: Rectangle,
+    content_fit: ContentFit,
+    bounds: Rectangle,
+) -> (Rectangle, Rectangle) {
+    let size = content_fit.fit(source.size(), bounds.size());
+    let center = bounds.center();
+
+    let placement = Rectangle {
+        x: center.x - size.width / 2.0,
+        y: center.y - size.height / 2.0,
+        width: size.width,
+        height: size.height,
+    };
+
+    match placement.intersection(&bounds) {
+        Some(visible) => {
+            let scale_x = source.width / placement.width;
+            let scale_y = source.height / placement.height;
+
+            (
+                Rectangle {
+                    x: source.x + (visible.x - placement.x) * scale_x,
+                    y: source.y + (visible.y - placement.y) * scale_y,
+                    width: visible.width * scale_x,
+                    height: visible.height * scale_y,
+                },
+                visible,
+            )
+        }
+        None => (source, placement),
     }
 }
 
@@ -148,6 +319,14 @@ impl Handle {
         Self::from_data(Data::Bytes(bytes))
     }
 
+    /// Creates an image [`Handle`] that displays the given [`Texture`].
+    ///
+    /// The [`Handle`] keeps its identity when the [`Texture`] is updated, so
+    /// renderers will upload only the regions that changed.
+    pub fn from_texture(texture: Texture) -> Handle {
+        Self::from_data(Data::Texture(texture))
+    }
+
     fn from_data(data: Data) -> Handle {
         let mut hasher = Hasher::default();
         data.hash(&mut hasher);
@@ -202,6 +381,9 @@ pub enum Data {
         /// The pixels.
         pixels: Vec<u8>,
     },
+
+    /// A [`Texture`] that can be updated in place.
+    Texture(Texture),
 }
 
 impl std::fmt::Debug for Data {
@@ -212,6 +394,9 @@ impl std::fmt::Debug for Data {
             Data::Pixels { width, height, .. } => {
                 write!(f, "Pixels({} * {})", width, height)
             }
+            Data::Texture(texture) => {
+                write!(f, "Texture({} * {})", texture.width(), texture.height())
+            }
         }
     }
 }
@@ -227,7 +412,19 @@ pub trait Renderer: crate::Renderer {
     fn dimensions(&self, handle: &Handle) -> (u32, u32);
 
     /// Draws an [`Image`].
-    fn draw(&mut self, handle: Handle, layout: Layout<'_>) -> Self::Output;
+    ///
+    /// It receives:
+    /// - the [`Handle`] of the image
+    /// - the [`FilterMethod`] used to sample the image
+    /// - the region of the image to draw, in pixels
+    /// - the bounds where the region will be drawn
+    fn draw(
+        &mut self,
+        handle: Handle,
+        filter_method: FilterMethod,
+        source: Rectangle,
+        bounds: Rectangle,
+    ) -> Self::Output;
 }
 
 impl<'a, Message, Renderer> From<Image> for Element<'a, Message, Renderer>
@@ -238,3 +435,70 @@ where
         Element::new(image)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cover_crops_the_overflowing_source() {
+        let (source, bounds) = fit(
+            Rectangle {
+                x: 0.0,
+                y: 0.0,
+                width: 200.0,
+                height: 100.0,
+            },
+            ContentFit::Cover,
+            Rectangle {
+                x: 10.0,
+                y: 10.0,
+                width: 50.0,
+                height: 50.0,
+            },
+        );
+
+        assert_eq!(
+            bounds,
+            Rectangle {
+                x: 10.0,
+                y: 10.0,
+                width: 50.0,
+                height: 50.0,
+            }
+        );
+
+        assert_eq!(
+            source,
+            Rectangle {
+                x: 50.0,
+                y: 0.0,
+                width: 100.0,
+                height: 100.0,
+            }
+        );
+    }
+
+    #[test]
+    fn crop_is_clamped_to_the_image() {
+        let source = source(
+            (64, 32),
+            Some(Rectangle {
+                x: 48,
+                y: 16,
+                width: 32,
+                height: 32,
+            }),
+        );
+
+        assert_eq!(
+            source,
+            Rectangle {
+                x: 48.0,
+                y: 16.0,
+                width: 16.0,
+                height: 16.0,
+            }
+        );
+    }
+}
@@ -0,0 +1,387 @@
+//! Play animated images, like GIFs.
+use crate::event::{self, Event};
+use crate::image;
+use crate::layout;
+use crate::widget::Operation;
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Widget,
+};
+
+use std::hash::Hash;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// A frame that plays an animated image while keeping aspect ratio.
+///
+/// The frames of the image are decoded by the renderer. Renderers that do not
+/// support animations will simply display the image as a still.
+///
+/// # Example
+///
+/// ```
+/// # use iced_native::image::animated::{self, AnimatedImage};
+/// #
+/// let mut state = animated::State::new();
+/// let image = AnimatedImage::new(&mut state, "resources/loading.gif");
+/// ```
+#[derive(Debug)]
+pub struct AnimatedImage<'a> {
+    state: &'a mut State,
+    handle: image::Handle,
+    width: Length,
+    height: Length,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    looping: bool,
+}
+
+impl<'a> AnimatedImage<'a> {
+    /// Creates a new [`AnimatedImage`] with the given [`State`] and
+    /// [`Handle`].
+    ///
+    /// [`Handle`]: image::Handle
+    pub fn new<T: Into<image::Handle>>(
+        state: &'a mut State,
+        handle: T,
+    ) -> Self {
+        AnimatedImage {
+            state,
+            handle: handle.into(),
+            width: Length::Shrink,
+            height: Length::Shrink,
+            content_fit: ContentFit::Contain,
+            filter_method: image::FilterMethod::Linear,
+            looping: true,
+        }
+    }
+
+    /// Sets the width of the [`AnimatedImage`] boundaries.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`AnimatedImage`] boundaries.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`ContentFit`] of the [`AnimatedImage`].
+    ///
+    /// Defaults to [`ContentFit::Contain`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] of the [`AnimatedImage`].
+    ///
+    /// Defaults to [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Sets whether the [`AnimatedImage`] should start over once its last
+    /// frame is reached.
+    ///
+    /// By default, it loops.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for AnimatedImage<'a>
+where
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let frames = renderer.frames(&self.handle);
+
+        let dimensions = match frames.get(0) {
+            Some(frame) => renderer.dimensions(&frame.handle),
+            None => renderer.dimensions(&self.handle),
+        };
+
+        image::layout(
+            limits,
+            image::source(dimensions, None).size(),
+            self.width,
+            self.height,
+            self.content_fit,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let frames = renderer.frames(&self.handle);
+
+            // The next frame is requested in `operate`, once its delay has
+            // elapsed
+            self.state.advance(&frames, now, self.looping);
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let frames = renderer.frames(&self.handle);
+
+        let handle = frames
+            .get(self.state.current.min(frames.len().saturating_sub(1)))
+            .map(|frame| frame.handle.clone())
+            .unwrap_or_else(|| self.handle.clone());
+
+        let source = image::source(renderer.dimensions(&handle), None);
+
+        image::draw(
+            renderer,
+            handle,
+            source,
+            self.content_fit,
+            self.filter_method,
+            layout.bounds(),
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.handle.hash(state);
+        self.width.hash(state);
+        self.height.hash(state);
+        self.content_fit.hash(state);
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        if let Some(next_frame) = self.state.next_frame_at {
+            operation.animation(next_frame, None, layout.bounds());
+        }
+    }
+}
+
+/// The local state of an [`AnimatedImage`].
+///
+/// It keeps track of the current frame and lets you control the playback of
+/// the animation.
+#[derive(Debug, Clone, Copy)]
+pub struct State {
+    current: usize,
+    is_playing: bool,
+    next_frame_at: Option<Instant>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            current: 0,
+            is_playing: true,
+            next_frame_at: None,
+        }
+    }
+}
+
+impl State {
+    /// The delay used for frames that do not specify any.
+    ///
+    /// Many encoders produce frames with a delay of zero, which browsers
+    /// replace with a sensible default. We do the same.
+    pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+    /// Creates a new [`State`] that starts playing the animation.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Creates a new [`State`] that is paused on the first frame of the
+    /// animation.
+    pub fn paused() -> Self {
+        State {
+            is_playing: false,
+            ..State::default()
+        }
+    }
+
+    /// Resumes the playback of the animation.
+    pub fn play(&mut self) {
+        self.is_playing = true;
+    }
+
+    /// Pauses the playback of the animation on the current frame.
+    pub fn pause(&mut self) {
+        self.is_playing = false;
+        self.next_frame_at = None;
+    }
+
+    /// Moves the animation back to its first frame.
+    ///
+    /// The playback status is preserved.
+    pub fn restart(&mut self) {
+        self.current = 0;
+        self.next_frame_at = None;
+    }
+
+    /// Returns whether the animation is currently playing.
+    pub fn is_playing(&self) -> bool {
+        self.is_playing
+    }
+
+    /// Returns the index of the frame currently displayed.
+    pub fn current_frame(&self) -> usize {
+        self.current
+    }
+
+    /// Advances the animation to the frame that should be displayed at the
+    /// given moment in time.
+    fn advance(&mut self, frames: &Frames, now: Instant, looping: bool) {
+        if !self.is_playing || frames.len() < 2 {
+            return;
+        }
+
+        let delay = |index: usize| match frames.get(index) {
+            Some(frame) if frame.delay > Duration::from_secs(0) => frame.delay,
+            _ => Self::DEFAULT_DELAY,
+        };
+
+        if self.current >= frames.len() {
+            self.current = 0;
+        }
+
+        let mut next_frame_at = self
+            .next_frame_at
+            .unwrap_or_else(|| now + delay(self.current));
+
+        while now >= next_frame_at {
+            if self.current + 1 < frames.len() {
+                self.current += 1;
+            } else if looping {
+                self.current = 0;
+            } else {
+                self.pause();
+
+                return;
+            }
+
+            next_frame_at += delay(self.current);
+        }
+
+        self.next_frame_at = Some(next_frame_at);
+    }
+}
+
+/// The decoded frames of an animated image.
+///
+/// Cloning [`Frames`] is cheap.
+#[derive(Debug, Clone, Default)]
+pub struct Frames {
+    frames: Arc<Vec<Frame>>,
+}
+
+impl Frames {
+    /// Creates some [`Frames`] from a list of [`Frame`].
+    pub fn new(frames: Vec<Frame>) -> Self {
+        Frames {
+            frames: Arc::new(frames),
+        }
+    }
+
+    /// Returns the [`Frame`] with the given index, if any.
+    pub fn get(&self, index: usize) -> Option<&Frame> {
+        self.frames.get(index)
+    }
+
+    /// Returns the amount of [`Frame`] in the animation.
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    /// Returns whether the animation has no [`Frame`] at all.
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    /// Returns an iterator over the different [`Frame`] of the animation.
+    pub fn iter(&self) -> impl Iterator<Item = &Frame> {
+        self.frames.iter()
+    }
+}
+
+impl From<image::Handle> for Frames {
+    fn from(handle: image::Handle) -> Self {
+        Frames::new(vec![Frame {
+            handle,
+            delay: Duration::from_secs(0),
+        }])
+    }
+}
+
+/// A single frame of an animated image.
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The [`Handle`] of the decoded pixels of the [`Frame`].
+    ///
+    /// [`Handle`]: image::Handle
+    pub handle: image::Handle,
+
+    /// The amount of time the [`Frame`] should be displayed for.
+    pub delay: Duration,
+}
+
+/// The renderer of an [`AnimatedImage`].
+///
+/// Your [renderer] will need to implement this trait before being able to use
+/// an [`AnimatedImage`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: image::Renderer {
+    /// Returns the decoded [`Frames`] of the animated image with the given
+    /// [`Handle`].
+    ///
+    /// [`Handle`]: image::Handle
+    fn frames(&self, handle: &image::Handle) -> Frames;
+}
+
+impl<'a, Message, Renderer> From<AnimatedImage<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: self::Renderer,
+{
+    fn from(image: AnimatedImage<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(image)
+    }
+}
@@ -0,0 +1,259 @@
+//! Update the pixels of an image in place.
+use crate::Rectangle;
+
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{self, AtomicU64};
+use std::sync::{Arc, Mutex};
+
+/// A buffer of BGRA pixels that can be modified while keeping its identity.
+///
+/// Unlike an image created with [`Handle::from_pixels`], a [`Texture`] can be
+/// updated partially. Renderers will only upload the regions that changed
+/// since the last frame, which makes it a good fit for video playback, camera
+/// feeds, and other content that changes often.
+///
+/// Cloning a [`Texture`] is cheap. All of the clones share the same pixels.
+///
+/// [`Handle::from_pixels`]: super::Handle::from_pixels
+#[derive(Debug, Clone)]
+pub struct Texture {
+    id: u64,
+    width: u32,
+    height: u32,
+    state: Arc<Mutex<State>>,
+}
+
+#[derive(Debug)]
+struct State {
+    pixels: Vec<u8>,
+    dirty: Option<Rectangle<u32>>,
+}
+
+impl Texture {
+    /// Creates a new [`Texture`] with the given dimensions and BGRA pixels.
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the dimensions.
+    pub fn new(width: u32, height: u32, pixels: Vec<u8>) -> Texture {
+        static NEXT_ID: AtomicU64 = AtomicU64::new(0);
+
+        assert_eq!(
+            pixels.len(),
+            byte_length(width, height),
+            "The pixels of the texture do not match its dimensions"
+        );
+
+        Texture {
+            id: NEXT_ID.fetch_add(1, atomic::Ordering::Relaxed),
+            width,
+            height,
+            state: Arc::new(Mutex::new(State {
+                pixels,
+                dirty: None,
+            })),
+        }
+    }
+
+    /// Returns the unique identifier of the [`Texture`].
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// Returns the width of the [`Texture`].
+    pub fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the height of the [`Texture`].
+    pub fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Replaces the pixels in the given region of the [`Texture`].
+    ///
+    /// The provided BGRA pixels must cover the region exactly, row by row.
+    ///
+    /// # Panics
+    /// Panics if the region is out of bounds or if the amount of pixels does
+    /// not match its size.
+    pub fn update(&self, region: Rectangle<u32>, pixels: &[u8]) {
+        assert!(
+            fits(region.x, region.width, self.width)
+                && fits(region.y, region.height, self.height),
+            "The region is out of the bounds of the texture"
+        );
+
+        assert_eq!(
+            pixels.len(),
+            byte_length(region.width, region.height),
+            "The pixels do not match the size of the region"
+        );
+
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        let mut state = self.state.lock().expect("Lock texture state");
+        let row_length = region.width as usize * 4;
+
+        for (row, source) in pixels.chunks_exact(row_length).enumerate() {
+            let offset = (((region.y as usize + row) * self.width as usize)
+                + region.x as usize)
+                * 4;
+
+            state.pixels[offset..offset + row_length].copy_from_slice(source);
+        }
+
+        state.dirty = Some(match state.dirty {
+            Some(dirty) => union(dirty, region),
+            None => region,
+        });
+    }
+
+    /// Replaces all the pixels of the [`Texture`].
+    ///
+    /// # Panics
+    /// Panics if the amount of pixels does not match the dimensions of the
+    /// [`Texture`].
+    pub fn replace(&self, pixels: &[u8]) {
+        self.update(
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: self.width,
+                height: self.height,
+            },
+            pixels,
+        );
+    }
+
+    /// Returns a copy of all the pixels of the [`Texture`] and forgets about
+    /// any pending changes.
+    ///
+    /// This is meant to be used by renderers when uploading the [`Texture`]
+    /// for the first time.
+    pub fn snapshot(&self) -> Vec<u8> {
+        let mut state = self.state.lock().expect("Lock texture state");
+        state.dirty = None;
+
+        state.pixels.clone()
+    }
+
+    /// Returns the region that changed since the last upload, together with
+    /// its pixels, if any.
+    ///
+    /// This is meant to be used by renderers to upload changes incrementally.
+    pub fn take_changes(&self) -> Option<(Rectangle<u32>, Vec<u8>)> {
+        let mut state = self.state.lock().expect("Lock texture state");
+        let region = state.dirty.take()?;
+
+        let row_length = region.width as usize * 4;
+        let mut pixels =
+            Vec::with_capacity(row_length * region.height as usize);
+
+        for row in region.y..region.y + region.height {
+            let offset =
+                ((row as usize * self.width as usize) + region.x as usize) * 4;
+
+            pixels
+                .extend_from_slice(&state.pixels[offset..offset + row_length]);
+        }
+
+        Some((region, pixels))
+    }
+}
+
+impl Hash for Texture {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.id.hash(state);
+    }
+}
+
+/// Returns the amount of bytes of the BGRA pixels of an image with the given
+/// dimensions.
+fn byte_length(width: u32, height: u32) -> usize {
+    width as usize * height as usize * 4
+}
+
+/// Returns whether a span with the given start and length fits in the given
+/// size, without overflowing.
+fn fits(start: u32, length: u32, size: u32) -> bool {
+    match start.checked_add(length) {
+        Some(end) => end <= size,
+        None => false,
+    }
+}
+
+fn union(a: Rectangle<u32>, b: Rectangle<u32>) -> Rectangle<u32> {
+    let x = a.x.min(b.x);
+    let y = a.y.min(b.y);
+
+    Rectangle {
+        x,
+        y,
+        width: (a.x + a.width).max(b.x + b.width) - x,
+        height: (a.y + a.height).max(b.y + b.height) - y,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_are_merged_until_taken() {
+        let texture = Texture::new(4, 4, vec![0; 4 * 4 * 4]);
+
+        texture.update(
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 1,
+                height: 1,
+            },
+            &[1; 4],
+        );
+
+        texture.update(
+            Rectangle {
+                x: 2,
+                y: 1,
+                width: 1,
+                height: 2,
+            },
+            &[2; 8],
+        );
+
+        let (region, pixels) = texture.take_changes().unwrap();
+
+        assert_eq!(
+            region,
+            Rectangle {
+                x: 0,
+                y: 0,
+                width: 3,
+                height: 3,
+            }
+        );
+        assert_eq!(pixels.len(), 3 * 3 * 4);
+        assert_eq!(&pixels[0..4], &[1; 4]);
+        assert_eq!(&pixels[(3 + 2) * 4..(3 + 2) * 4 + 4], &[2; 4]);
+        assert!(texture.take_changes().is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of the bounds")]
+    fn rejects_overflowing_regions() {
+        let texture = Texture::new(4, 4, vec![0; 4 * 4 * 4]);
+
+        texture.update(
+            Rectangle {
+                x: u32::MAX,
+                y: 0,
+                width: 2,
+                height: 1,
+            },
+            &[0; 8],
+        );
+    }
+}
@@ -4,8 +4,8 @@ use crate::image;
 use crate::layout;
 use crate::mouse;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
-    Widget,
+    Clipboard, ContentFit, Element, Hasher, Layout, Length, Point, Rectangle,
+    Size, Vector, Widget,
 };
 
 use std::hash::Hash;
@@ -21,6 +21,9 @@ pub struct Viewer<'a> {
     max_scale: f32,
     scale_step: f32,
     handle: image::Handle,
+    content_fit: ContentFit,
+    filter_method: image::FilterMethod,
+    crop: Option<Rectangle<u32>>,
 }
 
 impl<'a> Viewer<'a> {
@@ -37,6 +40,9 @@ impl<'a> Viewer<'a> {
             max_scale: 10.0,
             scale_step: 0.10,
             handle,
+            content_fit: ContentFit::ScaleDown,
+            filter_method: image::FilterMethod::Linear,
+            crop: None,
         }
     }
 
@@ -83,33 +89,53 @@ impl<'a> Viewer<'a> {
         self
     }
 
+    /// Sets the [`ContentFit`] of the image of the [`Viewer`] before any
+    /// zoom is applied.
+    ///
+    /// Default is [`ContentFit::ScaleDown`]
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the [`FilterMethod`] used to sample the image of the [`Viewer`].
+    ///
+    /// Default is [`FilterMethod::Linear`]
+    ///
+    /// [`FilterMethod`]: image::FilterMethod
+    /// [`FilterMethod::Linear`]: image::FilterMethod::Linear
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Crops the image of the [`Viewer`] to the given region, in pixels.
+    pub fn crop(mut self, region: Rectangle<u32>) -> Self {
+        self.crop = Some(region);
+        self
+    }
+
+    /// Returns the region of the underlying image that is displayed, in
+    /// pixels.
+    fn source<Renderer>(&self, renderer: &Renderer) -> Rectangle
+    where
+        Renderer: image::Renderer,
+    {
+        image::source(renderer.dimensions(&self.handle), self.crop)
+    }
+
     /// Returns the bounds of the underlying image, given the bounds of
-    /// the [`Viewer`]. Scaling will be applied and original aspect ratio
-    /// will be respected.
+    /// the [`Viewer`]. The [`ContentFit`] and scaling will be applied.
     fn image_size<Renderer>(&self, renderer: &Renderer, bounds: Size) -> Size
     where
         Renderer: self::Renderer + image::Renderer,
     {
-        let (width, height) = renderer.dimensions(&self.handle);
-
-        let (width, height) = {
-            let dimensions = (width as f32, height as f32);
-
-            let width_ratio = bounds.width / dimensions.0;
-            let height_ratio = bounds.height / dimensions.1;
+        let size = self.content_fit.fit(self.source(renderer).size(), bounds);
 
-            let ratio = width_ratio.min(height_ratio);
-
-            let scale = self.state.scale;
-
-            if ratio < 1.0 {
-                (dimensions.0 * ratio * scale, dimensions.1 * ratio * scale)
-            } else {
-                (dimensions.0 * scale, dimensions.1 * scale)
-            }
-        };
-
-        Size::new(width, height)
+        Size::new(
+            size.width * self.state.scale,
+            size.height * self.state.scale,
+        )
     }
 }
 
@@ -130,21 +156,21 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
+        let Size { width, height } = self.source(renderer).size();
 
-        let aspect_ratio = width as f32 / height as f32;
+        let aspect_ratio = width / height;
 
         let mut size = limits
             .width(self.width)
             .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
+            .resolve(Size::new(width, height));
 
         let viewport_aspect_ratio = size.width / size.height;
 
         if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
+            size.width = width * size.height / height;
         } else {
-            size.height = height as f32 * size.width / width as f32;
+            size.height = height * size.width / width;
         }
 
         layout::Node::new(size)
@@ -291,6 +317,7 @@ where
         };
 
         let is_mouse_over = bounds.contains(cursor_position);
+        let source = self.source(renderer);
 
         self::Renderer::draw(
             renderer,
@@ -299,6 +326,8 @@ where
             image_size,
             translation,
             self.handle.clone(),
+            self.filter_method,
+            source,
             is_mouse_over,
         )
     }
@@ -312,6 +341,7 @@ where
         self.padding.hash(state);
 
         self.handle.hash(state);
+        self.crop.hash(state);
     }
 }
 
@@ -377,9 +407,13 @@ pub trait Renderer: crate::Renderer + Sized {
     /// - the [`Size`] of the scaled [`Viewer`] image
     /// - the translation of the clipped image
     /// - the [`Handle`] to the underlying image
+    /// - the [`FilterMethod`] used to sample the image
+    /// - the region of the image to display, in pixels
     /// - whether the mouse is over the [`Viewer`] or not
     ///
     /// [`Handle`]: image::Handle
+    /// [`FilterMethod`]: image::FilterMethod
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         state: &State,
@@ -387,6 +421,8 @@ pub trait Renderer: crate::Renderer + Sized {
         image_size: Size,
         translation: Vector,
         handle: image::Handle,
+        filter_method: image::FilterMethod,
+        source: Rectangle,
         is_mouse_over: bool,
     ) -> Self::Output;
 }
@@ -0,0 +1,817 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::text_input::{self, TextInput, Value};
+use crate::touch;
+use crate::widget::{Id, Operation};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Size, Widget,
+};
+
+use std::fmt::Display;
+use std::hash::Hash;
+use std::str::FromStr;
+
+/// A field that can be filled with numbers, with a couple of buttons that
+/// decrement and increment its value.
+///
+/// The value can also be stepped with the arrow keys and the mouse wheel
+/// while the field is focused. Otherwise, scrolling over the [`NumberInput`]
+/// is left to its parents, like a [`Scrollable`].
+///
+/// The [`NumberInput`] is generic over numeric values and its step size
+/// defaults to 1 unit. Values are shown with their [`Display`] implementation
+/// and parsed with their [`FromStr`] one, so an integer [`NumberInput`] only
+/// accepts integers.
+///
+/// # Example
+/// ```
+/// # use iced_native::{number_input, renderer::Null};
+/// #
+/// # pub type NumberInput<'a, T, Message> = iced_native::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     QuantityChanged(f32),
+/// }
+///
+/// let state = &mut number_input::State::new();
+/// let value = 2.5;
+///
+/// NumberInput::new(state, value, Message::QuantityChanged)
+///     .min(0.0)
+///     .max(10.0)
+///     .step(0.5)
+///     .precision(1);
+/// ```
+///
+/// [`Scrollable`]: crate::Scrollable
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: self::Renderer> {
+    state: &'a mut State,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    precision: Option<usize>,
+    on_change: Box<dyn Fn(T) -> Message>,
+    font: Renderer::Font,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    style: <Renderer as self::Renderer>::Style,
+    is_disabled: bool,
+    id: Option<Id>,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Renderer: self::Renderer,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`NumberInput`]
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        NumberInput {
+            state,
+            value,
+            min: None,
+            max: None,
+            step: T::from(1),
+            precision: None,
+            on_change: Box::new(on_change),
+            font: Default::default(),
+            width: Length::Fill,
+            padding: Padding::ZERO,
+            size: None,
+            style: Default::default(),
+            is_disabled: false,
+            id: None,
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the number of decimal places shown by the [`NumberInput`].
+    ///
+    /// Stepping the value rounds it to the same number of decimal places.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: crate::widget::text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the field of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style: impl Into<<Renderer as self::Renderer>::Style>,
+    ) -> Self {
+        self.style = style.into();
+        self
+    }
+
+    /// Sets whether the [`NumberInput`] is disabled.
+    ///
+    /// A disabled [`NumberInput`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets the [`Id`] of the [`NumberInput`].
+    ///
+    /// Its field can be focused with [`text_input::focus`] by using the same
+    /// [`Id`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Display + FromStr + Into<f64> + num_traits::FromPrimitive,
+    Renderer: self::Renderer,
+{
+    fn format(&self, value: T) -> String {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        }
+    }
+
+    fn text(&self) -> String {
+        match &self.state.text {
+            Some(text) if self.state.input.is_focused() => text.clone(),
+            _ => self.format(self.value),
+        }
+    }
+
+    fn parse(&self, text: &str) -> Option<T> {
+        let parsed = text.trim().parse::<T>().ok()?;
+        let value = parsed.into();
+
+        if let Some(min) = self.min {
+            if value < min.into() {
+                return None;
+            }
+        }
+
+        if let Some(max) = self.max {
+            if value > max.into() {
+                return None;
+            }
+        }
+
+        Some(parsed)
+    }
+
+    fn can_decrement(&self) -> bool {
+        match self.min {
+            Some(min) => self.value.into() > min.into(),
+            None => true,
+        }
+    }
+
+    fn can_increment(&self) -> bool {
+        match self.max {
+            Some(max) => self.value.into() < max.into(),
+            None => true,
+        }
+    }
+
+    fn change_by(&mut self, steps: f64, messages: &mut Vec<Message>) {
+        let mut value = self.value.into() + steps * self.step.into();
+
+        if let Some(precision) = self.precision {
+            let factor = 10f64.powi(precision as i32);
+
+            value = (value * factor).round() / factor;
+        }
+
+        if let Some(min) = self.min {
+            value = value.max(min.into());
+        }
+
+        if let Some(max) = self.max {
+            value = value.min(max.into());
+        }
+
+        self.state.text = None;
+
+        if let Some(value) = T::from_f64(value) {
+            messages.push((self.on_change)(value));
+        }
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    input: text_input::State,
+    text: Option<String>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.input.is_focused()
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + Display + FromStr + Into<f64> + num_traits::FromPrimitive,
+    Renderer: self::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+        let height = text_size + self.padding.vertical();
+
+        let limits = limits.width(self.width).height(Length::Units(height));
+        let size = limits.resolve(Size::ZERO);
+
+        let button = f32::from(height);
+        let input_width = (size.width - 2.0 * button).max(0.0);
+
+        let mut text = layout::Node::new(Size::new(
+            (input_width - f32::from(self.padding.horizontal())).max(0.0),
+            f32::from(text_size),
+        ));
+        text.move_to(Point::new(
+            self.padding.left.into(),
+            self.padding.top.into(),
+        ));
+
+        let input = layout::Node::with_children(
+            Size::new(input_width, button),
+            vec![text],
+        );
+
+        let mut decrement = layout::Node::new(Size::new(button, button));
+        decrement.move_to(Point::new(input_width, 0.0));
+
+        let mut increment = layout::Node::new(Size::new(button, button));
+        increment.move_to(Point::new(input_width + button, 0.0));
+
+        layout::Node::with_children(
+            Size::new(input_width + 2.0 * button, button),
+            vec![input, decrement, increment],
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        messages: &mut Vec<Message>,
+    ) -> event::Status {
+        if self.is_disabled {
+            self.state.input.unfocus();
+            self.state.text = None;
+
+            return event::Status::Ignored;
+        }
+
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_bounds = children.next().unwrap().bounds();
+        let increment_bounds = children.next().unwrap().bounds();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if decrement_bounds.contains(cursor_position)
+                    || increment_bounds.contains(cursor_position) =>
+            {
+                if decrement_bounds.contains(cursor_position) {
+                    if self.can_decrement() {
+                        self.change_by(-1.0, messages);
+                    }
+                } else if self.can_increment() {
+                    self.change_by(1.0, messages);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if self.state.input.is_focused()
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 && self.can_increment() {
+                    self.change_by(1.0, messages);
+
+                    return event::Status::Captured;
+                } else if y < 0.0 && self.can_decrement() {
+                    self.change_by(-1.0, messages);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                ..
+            }) if self.state.input.is_focused() => {
+                if self.can_increment() {
+                    self.change_by(1.0, messages);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if self.state.input.is_focused() => {
+                if self.can_decrement() {
+                    self.change_by(-1.0, messages);
+                }
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let text = self.text();
+        let mut edits = Vec::new();
+
+        let status = {
+            let mut input =
+                TextInput::new(&mut self.state.input, "", &text, Edit::Changed)
+                    .font(self.font)
+                    .mask(|c: char| c.is_ascii_digit() || c == '.' || c == '-')
+                    .on_submit(Edit::Submitted);
+
+            if let Some(size) = self.size {
+                input = input.size(size);
+            }
+
+            input.on_event(
+                event,
+                input_layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                &mut edits,
+            )
+        };
+
+        for edit in edits {
+            match edit {
+                Edit::Changed(text) => {
+                    if let Some(value) = self.parse(&text) {
+                        messages.push((self.on_change)(value));
+                    }
+
+                    self.state.text = Some(text);
+                }
+                Edit::Submitted => {
+                    self.state.text = None;
+                }
+            }
+        }
+
+        if !self.state.input.is_focused() {
+            self.state.text = None;
+        }
+
+        status
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _defaults: &Renderer::Defaults,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) -> Renderer::Output {
+        let mut children = layout.children();
+        let input_layout = children.next().unwrap();
+        let decrement_bounds = children.next().unwrap().bounds();
+        let increment_bounds = children.next().unwrap().bounds();
+
+        let text = self.text();
+        let is_invalid =
+            self.state.text.is_some() && self.parse(&text).is_none();
+
+        let input = text_input::Renderer::draw(
+            renderer,
+            input_layout.bounds(),
+            input_layout.children().next().unwrap().bounds(),
+            cursor_position,
+            self.font,
+            self.size.unwrap_or(renderer.default_size()),
+            "",
+            &Value::new(&text),
+            "",
+            &self.state.input,
+            self.is_disabled,
+            false,
+            is_invalid,
+            &Renderer::text_input_style(&self.style),
+        );
+
+        self::Renderer::draw(
+            renderer,
+            layout.bounds(),
+            decrement_bounds,
+            increment_bounds,
+            cursor_position,
+            self.can_decrement(),
+            self.can_increment(),
+            self.is_disabled,
+            input,
+            &self.style,
+        )
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.size.hash(state);
+    }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let bounds = layout.children().next().unwrap().bounds();
+
+        if !self.is_disabled {
+            operation.focusable(
+                &mut self.state.input,
+                self.id.as_ref(),
+                bounds,
+            );
+        }
+
+        operation.text_input(&mut self.state.input, self.id.as_ref(), bounds);
+    }
+}
+
+/// The renderer of a [`NumberInput`].
+///
+/// Your [renderer] will need to implement this trait before being
+/// able to use a [`NumberInput`] in your user interface.
+///
+/// [renderer]: crate::renderer
+pub trait Renderer: text_input::Renderer {
+    /// The [`NumberInput`] style supported by this renderer.
+    type Style: Default;
+
+    /// Returns the style of the field of the [`NumberInput`].
+    fn text_input_style(
+        style: &<Self as Renderer>::Style,
+    ) -> <Self as text_input::Renderer>::Style;
+
+    /// Draws a [`NumberInput`].
+    ///
+    /// It receives:
+    ///   * the bounds of the [`NumberInput`]
+    ///   * the bounds of the decrement button
+    ///   * the bounds of the increment button
+    ///   * the current cursor position
+    ///   * whether the value can be decremented or not
+    ///   * whether the value can be incremented or not
+    ///   * whether the [`NumberInput`] is disabled or not
+    ///   * the drawn field of the [`NumberInput`]
+    #[allow(clippy::too_many_arguments)]
+    fn draw(
+        &mut self,
+        bounds: Rectangle,
+        decrement_bounds: Rectangle,
+        increment_bounds: Rectangle,
+        cursor_position: Point,
+        can_decrement: bool,
+        can_increment: bool,
+        is_disabled: bool,
+        input: Self::Output,
+        style: &<Self as Renderer>::Style,
+    ) -> Self::Output;
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Display + FromStr + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a,
+    Renderer: 'a + self::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NumberInput, State};
+
+    use crate::clipboard;
+    use crate::keyboard::KeyCode;
+    use crate::mouse;
+    use crate::program::Program;
+    use crate::scrollable;
+    use crate::testing::{simulator, Renderer};
+    use crate::text_input;
+    use crate::widget::operation::focusable;
+    use crate::widget::Id;
+    use crate::{Command, Element, Event, Length, Point, Scrollable, Space};
+
+    const COUNT: &str = "count";
+
+    #[derive(Default)]
+    struct Quantity {
+        value: f32,
+        state: State,
+        precision: Option<usize>,
+    }
+
+    impl Program for Quantity {
+        type Renderer = Renderer;
+        type Message = f32;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            value: f32,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<f32> {
+            self.value = value;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, f32, Renderer> {
+            let input =
+                NumberInput::new(&mut self.state, self.value, |value| value)
+                    .max(2.0)
+                    .min(0.0)
+                    .step(0.5);
+
+            match self.precision {
+                Some(precision) => input.precision(precision).into(),
+                None => input.into(),
+            }
+        }
+    }
+
+    #[derive(Default)]
+    struct Count {
+        value: u8,
+        state: State,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(u8),
+        Focus,
+    }
+
+    impl Program for Count {
+        type Renderer = Renderer;
+        type Message = Message;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            message: Message,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Message> {
+            match message {
+                Message::Changed(value) => {
+                    self.value = value;
+
+                    Command::none()
+                }
+                Message::Focus => text_input::focus(Id::new(COUNT)),
+            }
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Renderer> {
+            NumberInput::new(&mut self.state, self.value, Message::Changed)
+                .id(Id::new(COUNT))
+                .into()
+        }
+    }
+
+    /// A [`NumberInput`] in the middle of a tall [`Scrollable`].
+    #[derive(Default)]
+    struct Form {
+        value: u8,
+        state: State,
+        scrollable: scrollable::State,
+    }
+
+    impl Program for Form {
+        type Renderer = Renderer;
+        type Message = u8;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            value: u8,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<u8> {
+            self.value = value;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, u8, Renderer> {
+            Scrollable::new(&mut self.scrollable)
+                .push(Space::with_height(Length::Units(100)))
+                .push(NumberInput::new(&mut self.state, self.value, |value| {
+                    value
+                }))
+                .push(Space::with_height(Length::Units(1000)))
+                .into()
+        }
+    }
+
+    fn scroll_down_at(position: Point) -> Vec<Event> {
+        vec![
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Event::Mouse(mouse::Event::WheelScrolled {
+                delta: mouse::ScrollDelta::Lines { x: 0.0, y: -1.0 },
+            }),
+        ]
+    }
+
+    #[test]
+    fn steps_and_edits_numbers() {
+        let mut simulator = simulator(Quantity {
+            precision: Some(1),
+            ..Quantity::default()
+        });
+
+        simulator.click("+").unwrap();
+        simulator.click("+").unwrap();
+        simulator.click("-").unwrap();
+
+        assert_eq!(simulator.program().value, 0.5);
+
+        simulator.click("0.5").unwrap();
+        simulator.press_key(KeyCode::Up).unwrap();
+        simulator.press_key(KeyCode::Up).unwrap();
+        simulator.press_key(KeyCode::Up).unwrap();
+
+        assert_eq!(simulator.program().value, 2.0);
+
+        simulator.press_key(KeyCode::End).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.type_text("1.x5").unwrap();
+
+        assert_eq!(simulator.program().value, 1.5);
+
+        simulator.press_key(KeyCode::Home).unwrap();
+        simulator.type_text("3").unwrap();
+
+        assert_eq!(simulator.program().value, 1.5);
+        assert!(simulator.find("31.5").is_ok());
+
+        simulator.press_key(KeyCode::Escape).unwrap();
+
+        assert!(simulator.find("1.5").is_ok());
+    }
+
+    #[test]
+    fn displays_values_as_they_are() {
+        let simulator = simulator(Quantity {
+            value: 0.1,
+            ..Quantity::default()
+        });
+
+        assert!(simulator.find("0.1").is_ok());
+    }
+
+    #[test]
+    fn rejects_fractions_of_integers() {
+        let mut simulator = simulator(Count::default());
+
+        simulator.send(Message::Focus).unwrap();
+        simulator.press_key(KeyCode::End).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.type_text("1.5").unwrap();
+
+        assert_eq!(
+            simulator.take_messages(),
+            vec![Message::Focus, Message::Changed(1)]
+        );
+        assert_eq!(
+            simulator.operate(focusable::find_focused()),
+            Some(Id::new(COUNT))
+        );
+    }
+
+    #[test]
+    fn steps_with_the_mouse_wheel_only_while_focused() {
+        let mut simulator = simulator(Form {
+            value: 5,
+            ..Form::default()
+        });
+
+        let position = simulator.find("5").unwrap().bounds.center();
+        simulator.simulate(scroll_down_at(position)).unwrap();
+
+        let scrolled = simulator.find("5").unwrap().bounds.center();
+
+        assert_eq!(simulator.program().value, 5);
+        assert!(scrolled.y < position.y);
+
+        simulator.click("5").unwrap();
+        simulator.simulate(scroll_down_at(scrolled)).unwrap();
+
+        assert_eq!(simulator.program().value, 4);
+        assert_eq!(simulator.find("4").unwrap().bounds.center(), scrolled);
+    }
+}
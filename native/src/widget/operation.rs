@@ -0,0 +1,298 @@
+//! Query or update the internal state of widgets.
+pub mod focusable;
+pub mod scrollable;
+pub mod text_input;
+
+pub use focusable::Focusable;
+pub use scrollable::Scrollable;
+pub use text_input::TextInput;
+
+use crate::widget::Id;
+use crate::Rectangle;
+
+use std::time::Instant;
+
+/// A piece of logic that can traverse the widget tree of an application in
+/// order to query or update some widget state.
+///
+/// Widgets describe themselves to an [`Operation`] by calling the method that
+/// matches their nature. For instance, a text input will call
+/// [`Operation::focusable`] and [`Operation::text_input`] with its own state.
+pub trait Operation<T = ()> {
+    /// Operates on a widget that contains other widgets.
+    ///
+    /// The `operate_on_children` function can be called to traverse the
+    /// children of the widget.
+    fn container(
+        &mut self,
+        id: Option<&Id>,
+        bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    );
+
+    /// Operates on a widget that can be focused.
+    fn focusable(
+        &mut self,
+        _state: &mut dyn Focusable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Operates on a widget that can be scrolled.
+    fn scrollable(
+        &mut self,
+        _state: &mut dyn Scrollable,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Operates on a widget that has some editable text.
+    fn text_input(
+        &mut self,
+        _state: &mut dyn TextInput,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Operates on a widget that is animating.
+    ///
+    /// The given [`Instant`] is the moment the widget needs to be redrawn at
+    /// in order to keep animating.
+    fn animation(
+        &mut self,
+        _next_frame: Instant,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+    }
+
+    /// Returns the result of the [`Operation`], if any.
+    fn finish(&self) -> Option<T> {
+        None
+    }
+}
+
+/// Maps the result of an [`Operation`] with the given function.
+pub fn map<A, B>(
+    operation: Box<dyn Operation<A>>,
+    f: impl Fn(A) -> B + 'static,
+) -> impl Operation<B>
+where
+    A: 'static,
+    B: 'static,
+{
+    struct Map<A, F> {
+        operation: Box<dyn Operation<A>>,
+        f: F,
+    }
+
+    impl<A, B, F> Operation<B> for Map<A, F>
+    where
+        F: Fn(A) -> B,
+    {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            self.operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut black_box(operation));
+            });
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.scrollable(state, id, bounds);
+        }
+
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn animation(
+            &mut self,
+            next_frame: Instant,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.animation(next_frame, id, bounds);
+        }
+
+        fn finish(&self) -> Option<B> {
+            self.operation.finish().map(&self.f)
+        }
+    }
+
+    Map { operation, f }
+}
+
+/// Wraps an [`Operation`] so it can traverse widgets of any result type.
+///
+/// The resulting [`Operation`] forwards every call, but never produces a
+/// result by itself.
+pub fn black_box<'a, A, B>(
+    operation: &'a mut dyn Operation<A>,
+) -> impl Operation<B> + 'a {
+    struct BlackBox<'a, A> {
+        operation: &'a mut dyn Operation<A>,
+    }
+
+    impl<'a, A, B> Operation<B> for BlackBox<'a, A> {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<B>),
+        ) {
+            self.operation.container(id, bounds, &mut |operation| {
+                operate_on_children(&mut black_box(operation));
+            });
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.focusable(state, id, bounds);
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.scrollable(state, id, bounds);
+        }
+
+        fn text_input(
+            &mut self,
+            state: &mut dyn TextInput,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.text_input(state, id, bounds);
+        }
+
+        fn animation(
+            &mut self,
+            next_frame: Instant,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            self.operation.animation(next_frame, id, bounds);
+        }
+    }
+
+    BlackBox { operation }
+}
+
+/// Produces an [`Operation`] that returns the bounds of the widget with the
+/// given [`Id`], if found.
+pub fn bounds(target: Id) -> impl Operation<Rectangle> {
+    struct Bounds {
+        target: Id,
+        bounds: Option<Rectangle>,
+    }
+
+    impl Operation<Rectangle> for Bounds {
+        fn container(
+            &mut self,
+            id: Option<&Id>,
+            bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Rectangle>),
+        ) {
+            if self.bounds.is_some() {
+                return;
+            }
+
+            if id == Some(&self.target) {
+                self.bounds = Some(bounds);
+                return;
+            }
+
+            operate_on_children(self);
+        }
+
+        fn focusable(
+            &mut self,
+            _state: &mut dyn Focusable,
+            id: Option<&Id>,
+            bounds: Rectangle,
+        ) {
+            if self.bounds.is_none() && id == Some(&self.target) {
+                self.bounds = Some(bounds);
+            }
+        }
+
+        fn finish(&self) -> Option<Rectangle> {
+            self.bounds
+        }
+    }
+
+    Bounds {
+        target,
+        bounds: None,
+    }
+}
+
+/// Produces an [`Operation`] that returns the earliest moment any widget
+/// needs to be redrawn at in order to keep animating, if any.
+pub fn next_frame() -> impl Operation<Instant> {
+    struct NextFrame {
+        next_frame: Option<Instant>,
+    }
+
+    impl Operation<Instant> for NextFrame {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Instant>),
+        ) {
+            operate_on_children(self);
+        }
+
+        fn animation(
+            &mut self,
+            next_frame: Instant,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            self.next_frame = Some(match self.next_frame {
+                Some(current) => current.min(next_frame),
+                None => next_frame,
+            });
+        }
+
+        fn finish(&self) -> Option<Instant> {
+            self.next_frame
+        }
+    }
+
+    NextFrame { next_frame: None }
+}
@@ -0,0 +1,113 @@
+//! Operate on widgets that can be focused.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::Rectangle;
+
+/// The internal state of a widget that can be focused.
+pub trait Focusable {
+    /// Returns whether the widget is focused or not.
+    fn is_focused(&self) -> bool;
+
+    /// Focuses the widget.
+    fn focus(&mut self);
+
+    /// Unfocuses the widget.
+    fn unfocus(&mut self);
+}
+
+/// Produces an [`Operation`] that focuses the widget with the given [`Id`]
+/// and unfocuses any other.
+pub fn focus<T>(target: Id) -> impl Operation<T> {
+    struct Focus {
+        target: Id,
+    }
+
+    impl<T> Operation<T> for Focus {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            if id == Some(&self.target) {
+                state.focus();
+            } else {
+                state.unfocus();
+            }
+        }
+    }
+
+    Focus { target }
+}
+
+/// Produces an [`Operation`] that unfocuses every widget.
+pub fn unfocus<T>() -> impl Operation<T> {
+    struct Unfocus;
+
+    impl<T> Operation<T> for Unfocus {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            state.unfocus();
+        }
+    }
+
+    Unfocus
+}
+
+/// Produces an [`Operation`] that returns the [`Id`] of the focused widget,
+/// if any.
+pub fn find_focused() -> impl Operation<Id> {
+    struct FindFocused {
+        focused: Option<Id>,
+    }
+
+    impl Operation<Id> for FindFocused {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<Id>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn focusable(
+            &mut self,
+            state: &mut dyn Focusable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            if state.is_focused() {
+                self.focused = id.cloned();
+            }
+        }
+
+        fn finish(&self) -> Option<Id> {
+            self.focused.clone()
+        }
+    }
+
+    FindFocused { focused: None }
+}
@@ -0,0 +1,86 @@
+//! Operate on widgets that can be scrolled.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::Rectangle;
+
+/// The internal state of a widget that can be scrolled.
+pub trait Scrollable {
+    /// Snaps the scroll position of the widget to a relative amount.
+    ///
+    /// `0` represents the top of the contents, while `1` represents the
+    /// bottom.
+    fn snap_to(&mut self, percentage: f32);
+
+    /// Scrolls the widget to the given absolute offset, in logical pixels.
+    fn scroll_to(&mut self, offset: f32);
+
+    /// Returns the current absolute offset of the widget, in logical pixels,
+    /// given its bounds and the bounds of its contents.
+    fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> f32;
+}
+
+/// Produces an [`Operation`] that snaps the widget with the given [`Id`] to
+/// the provided `percentage`.
+pub fn snap_to<T>(target: Id, percentage: f32) -> impl Operation<T> {
+    struct SnapTo {
+        target: Id,
+        percentage: f32,
+    }
+
+    impl<T> Operation<T> for SnapTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            if id == Some(&self.target) {
+                state.snap_to(self.percentage);
+            }
+        }
+    }
+
+    SnapTo { target, percentage }
+}
+
+/// Produces an [`Operation`] that scrolls the widget with the given [`Id`] to
+/// the provided absolute `offset`.
+pub fn scroll_to<T>(target: Id, offset: f32) -> impl Operation<T> {
+    struct ScrollTo {
+        target: Id,
+        offset: f32,
+    }
+
+    impl<T> Operation<T> for ScrollTo {
+        fn container(
+            &mut self,
+            _id: Option<&Id>,
+            _bounds: Rectangle,
+            operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+        ) {
+            operate_on_children(self)
+        }
+
+        fn scrollable(
+            &mut self,
+            state: &mut dyn Scrollable,
+            id: Option<&Id>,
+            _bounds: Rectangle,
+        ) {
+            if id == Some(&self.target) {
+                state.scroll_to(self.offset);
+            }
+        }
+    }
+
+    ScrollTo { target, offset }
+}
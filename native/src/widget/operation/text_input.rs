@@ -0,0 +1,83 @@
+//! Operate on widgets that have some editable text.
+use crate::widget::operation::Operation;
+use crate::widget::Id;
+use crate::Rectangle;
+
+/// The internal state of a widget that has some editable text.
+pub trait TextInput {
+    /// Moves the cursor of the text input to the front of the text.
+    fn move_cursor_to_front(&mut self);
+
+    /// Moves the cursor of the text input to the end of the text.
+    fn move_cursor_to_end(&mut self);
+
+    /// Moves the cursor of the text input to an arbitrary location.
+    fn move_cursor_to(&mut self, position: usize);
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the front.
+pub fn move_cursor_to_front<T>(target: Id) -> impl Operation<T> {
+    MoveCursor {
+        target,
+        position: Position::Front,
+    }
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the end.
+pub fn move_cursor_to_end<T>(target: Id) -> impl Operation<T> {
+    MoveCursor {
+        target,
+        position: Position::End,
+    }
+}
+
+/// Produces an [`Operation`] that moves the cursor of the widget with the
+/// given [`Id`] to the provided position.
+pub fn move_cursor_to<T>(target: Id, position: usize) -> impl Operation<T> {
+    MoveCursor {
+        target,
+        position: Position::At(position),
+    }
+}
+
+struct MoveCursor {
+    target: Id,
+    position: Position,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Position {
+    Front,
+    End,
+    At(usize),
+}
+
+impl<T> Operation<T> for MoveCursor {
+    fn container(
+        &mut self,
+        _id: Option<&Id>,
+        _bounds: Rectangle,
+        operate_on_children: &mut dyn FnMut(&mut dyn Operation<T>),
+    ) {
+        operate_on_children(self)
+    }
+
+    fn text_input(
+        &mut self,
+        state: &mut dyn TextInput,
+        id: Option<&Id>,
+        _bounds: Rectangle,
+    ) {
+        if id != Some(&self.target) {
+            return;
+        }
+
+        match self.position {
+            Position::Front => state.move_cursor_to_front(),
+            Position::End => state.move_cursor_to_end(),
+            Position::At(position) => state.move_cursor_to(position),
+        }
+    }
+}
@@ -34,6 +34,7 @@ use crate::mouse;
 use crate::overlay;
 use crate::row;
 use crate::touch;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Size, Vector,
     Widget,
@@ -556,6 +557,18 @@ where
         }
     }
 
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let elements = &mut self.elements;
+
+        operation.container(None, layout.bounds(), &mut |operation| {
+            elements.iter_mut().zip(layout.children()).for_each(
+                |((_, pane), layout)| {
+                    pane.operate(layout, operation);
+                },
+            );
+        });
+    }
+
     fn overlay(
         &mut self,
         layout: Layout<'_>,
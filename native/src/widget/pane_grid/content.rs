@@ -3,6 +3,7 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid::{self, TitleBar};
+use crate::widget::Operation;
 use crate::{Clipboard, Element, Hasher, Layout, Point, Rectangle, Size};
 
 /// The content of a [`Pane`].
@@ -181,6 +182,24 @@ where
         event_status.merge(body_status)
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation,
+    ) {
+        let body_layout = if let Some(title_bar) = &mut self.title_bar {
+            let mut children = layout.children();
+
+            title_bar.operate(children.next().unwrap(), operation);
+
+            children.next().unwrap()
+        } else {
+            layout
+        };
+
+        self.body.operate(body_layout, operation);
+    }
+
     pub(crate) fn hash_layout(&self, state: &mut Hasher) {
         if let Some(title_bar) = &self.title_bar {
             title_bar.hash_layout(state);
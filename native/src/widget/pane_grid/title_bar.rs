@@ -3,6 +3,7 @@ use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
 use crate::pane_grid;
+use crate::widget::Operation;
 use crate::{
     Clipboard, Element, Hasher, Layout, Padding, Point, Rectangle, Size,
 };
@@ -244,6 +245,24 @@ where
         control_status.merge(title_status)
     }
 
+    pub(crate) fn operate(
+        &mut self,
+        layout: Layout<'_>,
+        operation: &mut dyn Operation,
+    ) {
+        let mut children = layout.children();
+        let padded = children.next().unwrap();
+
+        let mut children = padded.children();
+        let title_layout = children.next().unwrap();
+
+        self.content.operate(title_layout, operation);
+
+        if let Some(controls) = &mut self.controls {
+            controls.operate(children.next().unwrap(), operation);
+        }
+    }
+
     pub(crate) fn overlay(
         &mut self,
         layout: Layout<'_>,
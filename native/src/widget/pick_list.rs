@@ -1,5 +1,6 @@
 //! Display a dropdown list of selectable values.
 use crate::event::{self, Event};
+use crate::keyboard;
 use crate::layout;
 use crate::mouse;
 use crate::overlay;
@@ -14,6 +15,9 @@ use crate::{
 use std::borrow::Cow;
 
 /// A widget for selecting a single value from a list of options.
+///
+/// Once clicked, a [`PickList`] is focused and can be opened again with the
+/// space bar, the enter key, or `Alt` and the down arrow key.
 #[allow(missing_debug_implementations)]
 pub struct PickList<'a, T, Message, Renderer: self::Renderer>
 where
@@ -21,6 +25,7 @@ where
 {
     menu: &'a mut menu::State,
     is_open: &'a mut bool,
+    is_focused: &'a mut bool,
     hovered_option: &'a mut Option<usize>,
     last_selection: &'a mut Option<T>,
     on_selected: Box<dyn Fn(T) -> Message>,
@@ -31,6 +36,7 @@ where
     text_size: Option<u16>,
     font: Renderer::Font,
     style: <Renderer as self::Renderer>::Style,
+    is_disabled: bool,
 }
 
 /// The local state of a [`PickList`].
@@ -38,6 +44,7 @@ where
 pub struct State<T> {
     menu: menu::State,
     is_open: bool,
+    is_focused: bool,
     hovered_option: Option<usize>,
     last_selection: Option<T>,
 }
@@ -47,6 +54,7 @@ impl<T> Default for State<T> {
         Self {
             menu: menu::State::default(),
             is_open: bool::default(),
+            is_focused: bool::default(),
             hovered_option: Option::default(),
             last_selection: Option::default(),
         }
@@ -71,6 +79,7 @@ where
         let State {
             menu,
             is_open,
+            is_focused,
             hovered_option,
             last_selection,
         } = state;
@@ -78,6 +87,7 @@ where
         Self {
             menu,
             is_open,
+            is_focused,
             hovered_option,
             last_selection,
             on_selected: Box::new(on_selected),
@@ -88,6 +98,7 @@ where
             padding: Renderer::DEFAULT_PADDING,
             font: Default::default(),
             style: Default::default(),
+            is_disabled: false,
         }
     }
 
@@ -123,6 +134,24 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets whether the [`PickList`] is disabled.
+    ///
+    /// A disabled [`PickList`] ignores any interaction and cannot be opened.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    fn open(&mut self) {
+        let selected = self.selected.as_ref();
+
+        *self.is_open = true;
+        *self.hovered_option = self
+            .options
+            .iter()
+            .position(|option| Some(option) == selected);
+    }
 }
 
 impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
@@ -141,6 +170,10 @@ where
         Length::Shrink
     }
 
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -215,6 +248,14 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            *self.is_open = false;
+            *self.is_focused = false;
+            *self.last_selection = None;
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -222,19 +263,18 @@ where
                     // TODO: Encode cursor availability in the type system
                     *self.is_open =
                         cursor_position.x < 0.0 || cursor_position.y < 0.0;
+                    *self.is_focused = *self.is_open
+                        || layout.bounds().contains(cursor_position);
 
                     event::Status::Captured
                 } else if layout.bounds().contains(cursor_position) {
-                    let selected = self.selected.as_ref();
-
-                    *self.is_open = true;
-                    *self.hovered_option = self
-                        .options
-                        .iter()
-                        .position(|option| Some(option) == selected);
+                    *self.is_focused = true;
+                    self.open();
 
                     event::Status::Captured
                 } else {
+                    *self.is_focused = false;
+
                     event::Status::Ignored
                 };
 
@@ -248,6 +288,38 @@ where
                     event_status
                 }
             }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if *self.is_focused => match key_code {
+                keyboard::KeyCode::Space | keyboard::KeyCode::Enter
+                    if !*self.is_open =>
+                {
+                    self.open();
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Down if modifiers.alt && !*self.is_open => {
+                    self.open();
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Enter => {
+                    if let Some(last_selection) = self.last_selection.take() {
+                        messages.push((self.on_selected)(last_selection));
+
+                        *self.is_open = false;
+                    }
+
+                    event::Status::Captured
+                }
+                keyboard::KeyCode::Escape if *self.is_open => {
+                    *self.is_open = false;
+
+                    event::Status::Captured
+                }
+                _ => event::Status::Ignored,
+            },
             _ => event::Status::Ignored,
         }
     }
@@ -268,6 +340,7 @@ where
             self.padding,
             self.text_size.unwrap_or(renderer.default_size()),
             self.font,
+            self.is_disabled,
             &self.style,
         )
     }
@@ -276,7 +349,7 @@ where
         &mut self,
         layout: Layout<'_>,
     ) -> Option<overlay::Element<'_, Message, Renderer>> {
-        if *self.is_open {
+        if *self.is_open && !self.is_disabled {
             let bounds = layout.bounds();
 
             let mut menu = Menu::new(
@@ -285,6 +358,7 @@ where
                 &mut self.hovered_option,
                 &mut self.last_selection,
             )
+            .searchable(true)
             .width(bounds.width.round() as u16)
             .padding(self.padding)
             .font(self.font)
@@ -328,6 +402,7 @@ pub trait Renderer: text::Renderer + menu::Renderer {
         padding: Padding,
         text_size: u16,
         font: Self::Font,
+        is_disabled: bool,
         style: &<Self as Renderer>::Style,
     ) -> Self::Output;
 }
@@ -344,3 +419,124 @@ where
         Element::new(self)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{PickList, State};
+
+    use crate::clipboard;
+    use crate::keyboard::KeyCode;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer, Simulator};
+    use crate::{Command, Element, Size};
+
+    const MONTHS: &[&str] = &[
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    #[derive(Default)]
+    struct Calendar {
+        month: Option<&'static str>,
+        months: State<&'static str>,
+        is_disabled: bool,
+    }
+
+    impl Program for Calendar {
+        type Renderer = Renderer;
+        type Message = &'static str;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            month: &'static str,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<&'static str> {
+            self.month = Some(month);
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, &'static str, Renderer> {
+            PickList::new(&mut self.months, MONTHS, self.month, |month| month)
+                .disabled(self.is_disabled)
+                .into()
+        }
+    }
+
+    #[test]
+    fn navigates_with_the_keyboard() {
+        let mut simulator = Simulator::new(
+            Calendar {
+                month: Some("March"),
+                ..Calendar::default()
+            },
+            clipboard::Null,
+            // Only a few months fit in the menu
+            Size::new(400.0, 200.0),
+        );
+
+        simulator.click("March").unwrap();
+        assert!(simulator.find("January").is_ok());
+
+        simulator.press_key(KeyCode::Escape).unwrap();
+        assert!(simulator.find("January").is_err());
+
+        simulator.press_key(KeyCode::Space).unwrap();
+        simulator.press_key(KeyCode::End).unwrap();
+
+        assert!(simulator.find("December").is_ok());
+        assert!(simulator.find("January").is_err());
+
+        simulator.press_key(KeyCode::Home).unwrap();
+
+        assert!(simulator.find("January").is_ok());
+        assert!(simulator.find("December").is_err());
+
+        simulator.type_text("ju").unwrap();
+        simulator.press_key(KeyCode::Down).unwrap();
+        simulator.press_key(KeyCode::Enter).unwrap();
+
+        assert_eq!(simulator.program().month, Some("July"));
+        assert!(simulator.find("January").is_err());
+
+        simulator.press_key(KeyCode::Enter).unwrap();
+        simulator.type_text("j").unwrap();
+        simulator.press_key(KeyCode::Enter).unwrap();
+
+        assert_eq!(simulator.program().month, Some("January"));
+
+        simulator.press_key(KeyCode::Enter).unwrap();
+        simulator.press_key(KeyCode::PageDown).unwrap();
+
+        assert!(simulator.find("February").is_err());
+        assert!(simulator.find("December").is_ok());
+    }
+
+    #[test]
+    fn ignores_clicks_while_disabled() {
+        let mut simulator = simulator(Calendar {
+            month: Some("March"),
+            is_disabled: true,
+            ..Calendar::default()
+        });
+
+        simulator.click("March").unwrap();
+        assert!(simulator.find("January").is_err());
+
+        simulator.press_key(KeyCode::Down).unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert_eq!(simulator.program().month, Some("March"));
+    }
+}
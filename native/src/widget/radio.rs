@@ -50,6 +50,7 @@ pub struct Radio<Message, Renderer: self::Renderer + text::Renderer> {
     text_color: Option<Color>,
     font: Renderer::Font,
     style: Renderer::Style,
+    is_disabled: bool,
 }
 
 impl<Message, Renderer: self::Renderer + text::Renderer>
@@ -86,6 +87,7 @@ where
             text_color: None,
             font: Default::default(),
             style: Renderer::Style::default(),
+            is_disabled: false,
         }
     }
 
@@ -130,6 +132,14 @@ where
         self.style = style.into();
         self
     }
+
+    /// Sets whether the [`Radio`] is disabled.
+    ///
+    /// A disabled [`Radio`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Radio<Message, Renderer>
@@ -176,6 +186,10 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -225,6 +239,7 @@ where
             radio_bounds,
             self.is_selected,
             is_mouse_over,
+            self.is_disabled,
             label,
             &self.style,
         )
@@ -260,12 +275,14 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Radio`]
     ///   * whether the [`Radio`] is selected or not
     ///   * whether the mouse is over the [`Radio`] or not
+    ///   * whether the [`Radio`] is disabled or not
     ///   * the drawn label of the [`Radio`]
     fn draw(
         &mut self,
         bounds: Rectangle,
         is_selected: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Self::Output,
         style: &Self::Style,
     ) -> Self::Output;
@@ -281,3 +298,83 @@ where
         Element::new(radio)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Radio;
+
+    use crate::clipboard;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::{Column, Command, Element};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum Shipping {
+        Standard,
+        Express,
+    }
+
+    #[derive(Default)]
+    struct Order {
+        shipping: Option<Shipping>,
+        is_disabled: bool,
+    }
+
+    impl Program for Order {
+        type Renderer = Renderer;
+        type Message = Shipping;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            shipping: Shipping,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Shipping> {
+            self.shipping = Some(shipping);
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, Shipping, Renderer> {
+            let is_disabled = self.is_disabled;
+
+            [
+                (Shipping::Standard, "Standard"),
+                (Shipping::Express, "Express"),
+            ]
+            .iter()
+            .fold(Column::new(), |column, (shipping, label)| {
+                column.push(
+                    Radio::new(*shipping, *label, self.shipping, |shipping| {
+                        shipping
+                    })
+                    .disabled(is_disabled),
+                )
+            })
+            .into()
+        }
+    }
+
+    #[test]
+    fn selects_the_clicked_choice() {
+        let mut simulator = simulator(Order::default());
+
+        simulator.click("Express").unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![Shipping::Express]);
+        assert_eq!(simulator.program().shipping, Some(Shipping::Express));
+    }
+
+    #[test]
+    fn ignores_clicks_while_disabled() {
+        let mut simulator = simulator(Order {
+            is_disabled: true,
+            ..Order::default()
+        });
+
+        simulator.click("Express").unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert_eq!(simulator.program().shipping, None);
+    }
+}
@@ -2,6 +2,7 @@
 use crate::event::{self, Event};
 use crate::layout;
 use crate::overlay;
+use crate::widget::Operation;
 use crate::{
     Align, Clipboard, Element, Hasher, Layout, Length, Padding, Point,
     Rectangle, Widget,
@@ -114,6 +115,10 @@ where
         self.height
     }
 
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -205,6 +210,15 @@ where
             .filter_map(|(child, layout)| child.widget.overlay(layout))
             .next()
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        operation.container(None, layout.bounds(), &mut |operation| {
+            self.children
+                .iter_mut()
+                .zip(layout.children())
+                .for_each(|(child, layout)| child.operate(layout, operation));
+        });
+    }
 }
 
 /// The renderer of a [`Row`].
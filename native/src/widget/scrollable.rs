@@ -5,9 +5,11 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
-    Align, Clipboard, Column, Element, Hasher, Layout, Length, Padding, Point,
-    Rectangle, Size, Vector, Widget,
+    Align, Clipboard, Column, Command, Element, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Size, Vector, Widget,
 };
 
 use std::{f32, hash::Hash, u32};
@@ -24,9 +26,25 @@ pub struct Scrollable<'a, Message, Renderer: self::Renderer> {
     scroller_width: u16,
     content: Column<'a, Message, Renderer>,
     on_scroll: Option<Box<dyn Fn(f32) -> Message>>,
+    id: Option<Id>,
     style: Renderer::Style,
 }
 
+/// Produces a [`Command`] that scrolls the [`Scrollable`] with the given
+/// [`Id`] to the provided absolute `offset`.
+pub fn scroll_to<Message: 'static>(id: Id, offset: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::scroll_to(id, offset))
+}
+
+/// Produces a [`Command`] that snaps the [`Scrollable`] with the given [`Id`]
+/// to the provided `percentage`.
+///
+/// `0` represents scrollbar at the top, while `1` represents scrollbar at
+/// the bottom.
+pub fn snap_to<Message: 'static>(id: Id, percentage: f32) -> Command<Message> {
+    Command::widget(operation::scrollable::snap_to(id, percentage))
+}
+
 impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
     /// Creates a new [`Scrollable`] with the given [`State`].
     pub fn new(state: &'a mut State) -> Self {
@@ -39,6 +57,7 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
             scroller_width: 10,
             content: Column::new(),
             on_scroll: None,
+            id: None,
             style: Renderer::Style::default(),
         }
     }
@@ -119,6 +138,12 @@ impl<'a, Message, Renderer: self::Renderer> Scrollable<'a, Message, Renderer> {
         self
     }
 
+    /// Sets the [`Id`] of the [`Scrollable`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Sets the style of the [`Scrollable`] .
     pub fn style(mut self, style: impl Into<Renderer::Style>) -> Self {
         self.style = style.into();
@@ -461,6 +486,16 @@ where
                 overlay.translate(Vector::new(0.0, -(offset as f32)))
             })
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let bounds = layout.bounds();
+        let content = &mut self.content;
+
+        operation.scrollable(self.state, self.id.as_ref(), bounds);
+        operation.container(self.id.as_ref(), bounds, &mut |operation| {
+            content.operate(layout.children().next().unwrap(), operation);
+        });
+    }
 }
 
 /// The local state of a [`Scrollable`].
@@ -575,6 +610,20 @@ impl State {
     }
 }
 
+impl operation::Scrollable for State {
+    fn snap_to(&mut self, percentage: f32) {
+        State::snap_to(self, percentage);
+    }
+
+    fn scroll_to(&mut self, offset: f32) {
+        self.offset = Offset::Absolute(offset.max(0.0));
+    }
+
+    fn offset(&self, bounds: Rectangle, content_bounds: Rectangle) -> f32 {
+        self.offset.absolute(bounds, content_bounds)
+    }
+}
+
 /// The scrollbar of a [`Scrollable`].
 #[derive(Debug)]
 pub struct Scrollbar {
@@ -47,6 +47,7 @@ pub struct Slider<'a, T, Message, Renderer: self::Renderer> {
     width: Length,
     height: u16,
     style: Renderer::Style,
+    is_disabled: bool,
 }
 
 impl<'a, T, Message, Renderer> Slider<'a, T, Message, Renderer>
@@ -95,6 +96,7 @@ where
             width: Length::Fill,
             height: Renderer::DEFAULT_HEIGHT,
             style: Renderer::Style::default(),
+            is_disabled: false,
         }
     }
 
@@ -132,6 +134,14 @@ where
         self.step = step;
         self
     }
+
+    /// Sets whether the [`Slider`] is disabled.
+    ///
+    /// A disabled [`Slider`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 /// The local state of a [`Slider`].
@@ -184,6 +194,12 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            self.state.is_dragging = false;
+
+            return event::Status::Ignored;
+        }
+
         let mut change = || {
             let bounds = layout.bounds();
             if cursor_position.x <= bounds.x {
@@ -260,6 +276,7 @@ where
             start.into() as f32..=end.into() as f32,
             self.value.into() as f32,
             self.state.is_dragging,
+            self.is_disabled,
             &self.style,
         )
     }
@@ -293,6 +310,9 @@ pub trait Renderer: crate::Renderer {
     ///   * the local state of the [`Slider`]
     ///   * the range of values of the [`Slider`]
     ///   * the current value of the [`Slider`]
+    ///   * whether the [`Slider`] is being dragged or not
+    ///   * whether the [`Slider`] is disabled or not
+    #[allow(clippy::too_many_arguments)]
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -300,6 +320,7 @@ pub trait Renderer: crate::Renderer {
         range: RangeInclusive<f32>,
         value: f32,
         is_dragging: bool,
+        is_disabled: bool,
         style: &Self::Style,
     ) -> Self::Output;
 }
@@ -317,3 +338,65 @@ where
         Element::new(slider)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Slider, State};
+
+    use crate::clipboard;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::{Command, Element, Point};
+
+    #[derive(Default)]
+    struct Volume {
+        level: u8,
+        slider: State,
+        is_disabled: bool,
+    }
+
+    impl Program for Volume {
+        type Renderer = Renderer;
+        type Message = u8;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            level: u8,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<u8> {
+            self.level = level;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, u8, Renderer> {
+            Slider::new(&mut self.slider, 0..=100, self.level, |level| level)
+                .disabled(self.is_disabled)
+                .into()
+        }
+    }
+
+    #[test]
+    fn changes_the_value_when_clicked() {
+        let mut simulator = simulator(Volume::default());
+
+        simulator.click_at(Point::new(300.0, 15.0)).unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![75]);
+        assert_eq!(simulator.program().level, 75);
+    }
+
+    #[test]
+    fn ignores_clicks_while_disabled() {
+        let mut simulator = simulator(Volume {
+            is_disabled: true,
+            ..Volume::default()
+        });
+
+        simulator.click_at(Point::new(300.0, 15.0)).unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert_eq!(simulator.program().level, 0);
+    }
+}
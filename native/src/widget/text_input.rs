@@ -5,8 +5,10 @@ mod editor;
 mod value;
 
 pub mod cursor;
+pub mod mask;
 
 pub use cursor::Cursor;
+pub use mask::{Mask, Pattern};
 pub use value::Value;
 
 use editor::Editor;
@@ -17,9 +19,11 @@ use crate::layout;
 use crate::mouse::{self, click};
 use crate::text;
 use crate::touch;
+use crate::widget::operation::{self, Operation};
+use crate::widget::Id;
 use crate::{
-    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
-    Size, Widget,
+    Clipboard, Command, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Size, Widget,
 };
 
 use std::u32;
@@ -51,9 +55,12 @@ use std::u32;
 #[allow(missing_debug_implementations)]
 pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     state: &'a mut State,
+    id: Option<Id>,
     placeholder: String,
     value: Value,
     is_secure: bool,
+    is_read_only: bool,
+    mask: Option<Box<dyn Mask + 'a>>,
     font: Renderer::Font,
     width: Length,
     max_width: u32,
@@ -62,6 +69,8 @@ pub struct TextInput<'a, Message, Renderer: self::Renderer> {
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
     style: Renderer::Style,
+    is_disabled: bool,
+    is_invalid: bool,
 }
 
 impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
@@ -87,9 +96,12 @@ where
     {
         TextInput {
             state,
+            id: None,
             placeholder: String::from(placeholder),
             value: Value::new(value),
             is_secure: false,
+            is_read_only: false,
+            mask: None,
             font: Default::default(),
             width: Length::Fill,
             max_width: u32::MAX,
@@ -98,15 +110,41 @@ where
             on_change: Box::new(on_change),
             on_submit: None,
             style: Renderer::Style::default(),
+            is_disabled: false,
+            is_invalid: false,
         }
     }
 
+    /// Sets the [`Id`] of the [`TextInput`].
+    pub fn id(mut self, id: Id) -> Self {
+        self.id = Some(id);
+        self
+    }
+
     /// Converts the [`TextInput`] into a secure password input.
     pub fn password(mut self) -> Self {
         self.is_secure = true;
         self
     }
 
+    /// Converts the [`TextInput`] into a read-only input.
+    ///
+    /// The value of a read-only [`TextInput`] can be selected and copied, but
+    /// not edited. Its `on_change` function is never called.
+    pub fn read_only(mut self) -> Self {
+        self.is_read_only = true;
+        self
+    }
+
+    /// Sets the [`Mask`] of the [`TextInput`].
+    ///
+    /// Any edit is conformed to the [`Mask`] before the `on_change` function
+    /// is called, keeping the cursor in place.
+    pub fn mask(mut self, mask: impl Mask + 'a) -> Self {
+        self.mask = Some(Box::new(mask));
+        self
+    }
+
     /// Sets the [`Font`] of the [`Text`].
     ///
     /// [`Font`]: crate::widget::text::Renderer::Font
@@ -152,6 +190,23 @@ where
         self
     }
 
+    /// Sets whether the [`TextInput`] is disabled.
+    ///
+    /// A disabled [`TextInput`] ignores any interaction and cannot be
+    /// focused.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the value of the [`TextInput`] is invalid.
+    ///
+    /// An invalid [`TextInput`] is drawn with the `invalid` style.
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
+
     /// Returns the current [`State`] of the [`TextInput`].
     pub fn state(&self) -> &State {
         self.state
@@ -185,10 +240,20 @@ where
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
                 &value.secure(),
+                "",
                 &self.state,
+                self.is_disabled,
+                self.is_read_only,
+                self.is_invalid,
                 &self.style,
             )
         } else {
+            let mask = self
+                .mask
+                .as_ref()
+                .map(|mask| mask.placeholder(&value.to_string()))
+                .unwrap_or_default();
+
             self::Renderer::draw(
                 renderer,
                 bounds,
@@ -198,7 +263,11 @@ where
                 self.size.unwrap_or(renderer.default_size()),
                 &self.placeholder,
                 value,
+                &mask,
                 &self.state,
+                self.is_disabled,
+                self.is_read_only,
+                self.is_invalid,
                 &self.style,
             )
         }
@@ -219,6 +288,10 @@ where
         Length::Shrink
     }
 
+    fn padding(&self) -> Padding {
+        self.padding
+    }
+
     fn layout(
         &self,
         renderer: &Renderer,
@@ -250,6 +323,13 @@ where
         clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            self.state.is_focused = false;
+            self.state.is_dragging = false;
+
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
             | Event::Touch(touch::Event::FingerPressed { .. }) => {
@@ -361,12 +441,16 @@ where
             }
             Event::Keyboard(keyboard::Event::CharacterReceived(c))
                 if self.state.is_focused
+                    && !self.is_read_only
                     && self.state.is_pasting.is_none()
                     && !self.state.keyboard_modifiers.is_command_pressed()
                     && !c.is_control() =>
             {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
+                let mut editor = Editor::new(
+                    &mut self.value,
+                    &mut self.state.cursor,
+                    self.mask.as_deref(),
+                );
 
                 editor.insert(c);
 
@@ -386,7 +470,7 @@ where
                             messages.push(on_submit);
                         }
                     }
-                    keyboard::KeyCode::Backspace => {
+                    keyboard::KeyCode::Backspace if !self.is_read_only => {
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -408,6 +492,7 @@ where
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            self.mask.as_deref(),
                         );
 
                         editor.backspace();
@@ -415,7 +500,7 @@ where
                         let message = (self.on_change)(editor.contents());
                         messages.push(message);
                     }
-                    keyboard::KeyCode::Delete => {
+                    keyboard::KeyCode::Delete if !self.is_read_only => {
                         if platform::is_jump_modifier_pressed(modifiers)
                             && self
                                 .state
@@ -439,6 +524,7 @@ where
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            self.mask.as_deref(),
                         );
 
                         editor.delete();
@@ -523,7 +609,8 @@ where
                         if self
                             .state
                             .keyboard_modifiers
-                            .is_command_pressed() =>
+                            .is_command_pressed()
+                            && !self.is_read_only =>
                     {
                         match self.state.cursor.selection(&self.value) {
                             Some((start, end)) => {
@@ -537,6 +624,7 @@ where
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            self.mask.as_deref(),
                         );
 
                         editor.delete();
@@ -544,7 +632,7 @@ where
                         let message = (self.on_change)(editor.contents());
                         messages.push(message);
                     }
-                    keyboard::KeyCode::V => {
+                    keyboard::KeyCode::V if !self.is_read_only => {
                         if self.state.keyboard_modifiers.is_command_pressed() {
                             let content = match self.state.is_pasting.take() {
                                 Some(content) => content,
@@ -563,6 +651,7 @@ where
                             let mut editor = Editor::new(
                                 &mut self.value,
                                 &mut self.state.cursor,
+                                self.mask.as_deref(),
                             );
 
                             editor.paste(content.clone());
@@ -640,6 +729,16 @@ where
         self.padding.hash(state);
         self.size.hash(state);
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        let bounds = layout.bounds();
+
+        if !self.is_disabled {
+            operation.focusable(self.state, self.id.as_ref(), bounds);
+        }
+
+        operation.text_input(self.state, self.id.as_ref(), bounds);
+    }
 }
 
 /// The renderer of a [`TextInput`].
@@ -677,7 +776,11 @@ pub trait Renderer: text::Renderer + Sized {
     /// - the cursor position
     /// - the placeholder to show when the value is empty
     /// - the current [`Value`]
+    /// - the placeholder characters of the [`Mask`] to show after the value
     /// - the current [`State`]
+    /// - whether the [`TextInput`] is disabled or not
+    /// - whether the [`TextInput`] is read-only or not
+    /// - whether the value of the [`TextInput`] is invalid or not
     fn draw(
         &mut self,
         bounds: Rectangle,
@@ -687,7 +790,11 @@ pub trait Renderer: text::Renderer + Sized {
         size: u16,
         placeholder: &str,
         value: &Value,
+        mask: &str,
         state: &State,
+        is_disabled: bool,
+        is_read_only: bool,
+        is_invalid: bool,
         style: &Self::Style,
     ) -> Self::Output;
 
@@ -731,6 +838,32 @@ where
     }
 }
 
+/// Produces a [`Command`] that focuses the [`TextInput`] with the given [`Id`].
+pub fn focus<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::focusable::focus(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the end.
+pub fn move_cursor_to_end<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to_end(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the front.
+pub fn move_cursor_to_front<Message: 'static>(id: Id) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to_front(id))
+}
+
+/// Produces a [`Command`] that moves the cursor of the [`TextInput`] with the
+/// given [`Id`] to the provided position.
+pub fn move_cursor_to<Message: 'static>(
+    id: Id,
+    position: usize,
+) -> Command<Message> {
+    Command::widget(operation::text_input::move_cursor_to(id, position))
+}
+
 /// The state of a [`TextInput`].
 #[derive(Debug, Default, Clone)]
 pub struct State {
@@ -797,6 +930,34 @@ impl State {
     }
 }
 
+impl operation::Focusable for State {
+    fn is_focused(&self) -> bool {
+        State::is_focused(self)
+    }
+
+    fn focus(&mut self) {
+        State::focus(self)
+    }
+
+    fn unfocus(&mut self) {
+        State::unfocus(self)
+    }
+}
+
+impl operation::TextInput for State {
+    fn move_cursor_to_front(&mut self) {
+        State::move_cursor_to_front(self)
+    }
+
+    fn move_cursor_to_end(&mut self) {
+        State::move_cursor_to_end(self)
+    }
+
+    fn move_cursor_to(&mut self, position: usize) {
+        State::move_cursor_to(self, position)
+    }
+}
+
 // TODO: Reduce allocations
 fn find_cursor_position<Renderer: self::Renderer>(
     renderer: &Renderer,
@@ -864,3 +1025,180 @@ mod platform {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{focus, Pattern, State, TextInput};
+
+    use crate::clipboard;
+    use crate::keyboard::KeyCode;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::widget::operation::focusable;
+    use crate::widget::Id;
+    use crate::{Command, Element};
+
+    const INPUT: &str = "input";
+
+    #[derive(Default)]
+    struct Input {
+        value: String,
+        state: State,
+        is_disabled: bool,
+        is_read_only: bool,
+        mask: Option<Pattern>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Changed(String),
+        Focus,
+    }
+
+    impl Program for Input {
+        type Renderer = Renderer;
+        type Message = Message;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            message: Message,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<Message> {
+            match message {
+                Message::Changed(value) => {
+                    self.value = value;
+
+                    Command::none()
+                }
+                Message::Focus => focus(Id::new(INPUT)),
+            }
+        }
+
+        fn view(&mut self) -> Element<'_, Message, Renderer> {
+            let mut input = TextInput::new(
+                &mut self.state,
+                "Value",
+                &self.value,
+                Message::Changed,
+            )
+            .id(Id::new(INPUT))
+            .disabled(self.is_disabled);
+
+            if self.is_read_only {
+                input = input.read_only();
+            }
+
+            if let Some(mask) = self.mask.clone() {
+                input = input.mask(mask);
+            }
+
+            input.into()
+        }
+    }
+
+    #[test]
+    fn focuses_by_id() {
+        let mut simulator = simulator(Input::default());
+
+        simulator.type_text("A").unwrap();
+        assert!(simulator.messages().is_empty());
+
+        simulator.send(Message::Focus).unwrap();
+        simulator.type_text("B").unwrap();
+
+        assert_eq!(
+            simulator.take_messages(),
+            vec![Message::Focus, Message::Changed(String::from("B"))]
+        );
+        assert_eq!(
+            simulator.operate(focusable::find_focused()),
+            Some(Id::new(INPUT))
+        );
+    }
+
+    #[test]
+    fn ignores_interactions_while_disabled() {
+        let mut simulator = simulator(Input {
+            is_disabled: true,
+            ..Input::default()
+        });
+
+        simulator.click("Value").unwrap();
+        simulator.type_text("Iced").unwrap();
+
+        simulator.send(Message::Focus).unwrap();
+        simulator.type_text("Iced").unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![Message::Focus]);
+        assert_eq!(simulator.operate(focusable::find_focused()), None);
+    }
+
+    #[test]
+    fn rejects_edits_while_read_only() {
+        let mut simulator = simulator(Input {
+            value: String::from("Iced"),
+            is_read_only: true,
+            ..Input::default()
+        });
+
+        simulator.click("Iced").unwrap();
+        simulator.type_text("Ice").unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.press_key(KeyCode::Delete).unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert_eq!(simulator.program().value, "Iced");
+        assert_eq!(
+            simulator.operate(focusable::find_focused()),
+            Some(Id::new(INPUT))
+        );
+    }
+
+    #[test]
+    fn conforms_edits_to_the_mask() {
+        let mut simulator = simulator(Input {
+            mask: Some(Pattern::new("99/99/9999")),
+            ..Input::default()
+        });
+
+        simulator.click("Value").unwrap();
+        simulator.type_text("12x03").unwrap();
+
+        assert_eq!(simulator.program().value, "12/03");
+
+        simulator.type_text("2024").unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+        simulator.press_key(KeyCode::Home).unwrap();
+        simulator.type_text("0").unwrap();
+
+        assert_eq!(simulator.program().value, "01/20/320");
+    }
+
+    #[test]
+    fn skips_literals_of_the_mask_when_deleting() {
+        let mut simulator = simulator(Input {
+            value: String::from("12/03/2024"),
+            mask: Some(Pattern::new("99/99/9999")),
+            ..Input::default()
+        });
+
+        simulator.click("12/03/2024").unwrap();
+        simulator.press_key(KeyCode::Home).unwrap();
+        simulator.press_key(KeyCode::Right).unwrap();
+        simulator.press_key(KeyCode::Right).unwrap();
+        simulator.press_key(KeyCode::Delete).unwrap();
+
+        assert_eq!(simulator.program().value, "12/32/024");
+
+        simulator.press_key(KeyCode::Right).unwrap();
+        simulator.press_key(KeyCode::Backspace).unwrap();
+
+        assert_eq!(simulator.program().value, "12/20/24");
+
+        simulator.press_key(KeyCode::Backspace).unwrap();
+
+        assert_eq!(simulator.program().value, "12/02/4");
+    }
+}
@@ -1,13 +1,22 @@
-use crate::text_input::{Cursor, Value};
+use crate::text_input::{Cursor, Mask, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    mask: Option<&'a dyn Mask>,
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        mask: Option<&'a dyn Mask>,
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            mask,
+        }
     }
 
     pub fn contents(&self) -> String {
@@ -25,6 +34,7 @@ impl<'a> Editor<'a> {
 
         self.value.insert(self.cursor.end(self.value), character);
         self.cursor.move_right(self.value);
+        self.conform();
     }
 
     pub fn paste(&mut self, content: Value) {
@@ -41,6 +51,7 @@ impl<'a> Editor<'a> {
         self.value.insert_many(self.cursor.end(self.value), content);
 
         self.cursor.move_right_by_amount(self.value, length);
+        self.conform();
     }
 
     pub fn backspace(&mut self) {
@@ -48,13 +59,24 @@ impl<'a> Editor<'a> {
             Some((start, end)) => {
                 self.cursor.move_left(self.value);
                 self.value.remove_many(start, end);
+                self.conform();
             }
             None => {
-                let start = self.cursor.start(self.value);
+                let contents = self.contents();
+                let mut start = self.cursor.start(self.value);
 
-                if start > 0 {
-                    self.cursor.move_left(self.value);
+                // Removing a literal of the mask is undone when conforming,
+                // so we skip it and remove the character before it instead
+                while start > 0 {
+                    self.cursor.move_to(start - 1);
                     self.value.remove(start - 1);
+                    self.conform();
+
+                    if self.contents() != contents {
+                        break;
+                    }
+
+                    start -= 1;
                 }
             }
         }
@@ -66,12 +88,34 @@ impl<'a> Editor<'a> {
                 self.backspace();
             }
             None => {
-                let end = self.cursor.end(self.value);
+                let contents = self.contents();
+                let mut end = self.cursor.end(self.value);
 
-                if end < self.value.len() {
+                // Literals of the mask are skipped, like when backspacing
+                while end < self.value.len() {
+                    self.cursor.move_to(end);
                     self.value.remove(end);
+                    self.conform();
+
+                    if self.contents() != contents {
+                        break;
+                    }
+
+                    end += 1;
                 }
             }
         }
     }
+
+    /// Applies the mask, if any, to the value and keeps the cursor after the
+    /// same characters.
+    fn conform(&mut self) {
+        if let Some(mask) = self.mask {
+            let end = self.cursor.end(self.value);
+            let before = mask.apply(&self.value.until(end).to_string());
+
+            *self.value = Value::new(&mask.apply(&self.value.to_string()));
+            self.cursor.move_to(Value::new(&before).len());
+        }
+    }
 }
@@ -0,0 +1,201 @@
+//! Constrain the value of a text input.
+
+/// A set of rules that constrains the value of a text input.
+///
+/// A [`Mask`] is applied every time the value of a text input is edited,
+/// before its `on_change` function is called.
+///
+/// Any `Fn(char) -> bool` is a [`Mask`] that only keeps the characters it
+/// accepts.
+pub trait Mask {
+    /// Conforms the given input to the [`Mask`], dropping any characters
+    /// that do not fit.
+    ///
+    /// Applying the [`Mask`] to a prefix of some input must produce a prefix
+    /// of the result of applying it to the whole input.
+    fn apply(&self, input: &str) -> String;
+
+    /// Returns the placeholder characters that should be shown after the
+    /// given conforming value.
+    fn placeholder(&self, _value: &str) -> String {
+        String::new()
+    }
+}
+
+impl<F> Mask for F
+where
+    F: Fn(char) -> bool,
+{
+    fn apply(&self, input: &str) -> String {
+        input.chars().filter(|c| self(*c)).collect()
+    }
+}
+
+/// A [`Mask`] that lays the input over a fixed pattern.
+///
+/// The characters of the pattern are either slots or literals:
+///   * `9` is a slot that accepts an ASCII digit.
+///   * `a` is a slot that accepts an alphabetic character.
+///   * `*` is a slot that accepts an alphanumeric character.
+///   * `\` turns the next character into a literal.
+///   * Any other character is a literal.
+///
+/// Literals are inserted automatically as the user types, and the slots that
+/// have not been filled yet are shown as placeholder characters.
+///
+/// # Example
+/// ```
+/// use iced_native::text_input::{Mask, Pattern};
+///
+/// let date = Pattern::new("99/99/9999");
+///
+/// assert_eq!(date.apply("1203x2024"), "12/03/2024");
+/// assert_eq!(date.placeholder("12/0"), "_/____");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pattern {
+    slots: Vec<Slot>,
+    placeholder: char,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(_) => false,
+        }
+    }
+}
+
+impl Pattern {
+    /// Creates a new [`Pattern`] from its textual representation.
+    pub fn new(pattern: &str) -> Self {
+        let mut slots = Vec::new();
+        let mut chars = pattern.chars();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '9' => Slot::Digit,
+                'a' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => match chars.next() {
+                    Some(literal) => Slot::Literal(literal),
+                    None => Slot::Literal('\\'),
+                },
+                literal => Slot::Literal(literal),
+            });
+        }
+
+        Pattern {
+            slots,
+            placeholder: '_',
+        }
+    }
+
+    /// Sets the character shown for the slots of the [`Pattern`] that have
+    /// not been filled yet. By default, it is `_`.
+    pub fn placeholder_char(mut self, placeholder: char) -> Self {
+        self.placeholder = placeholder;
+        self
+    }
+}
+
+impl Mask for Pattern {
+    fn apply(&self, input: &str) -> String {
+        let mut result = String::new();
+        let mut next = 0;
+
+        for c in input.chars() {
+            let mut index = next;
+            let mut is_accepted = false;
+
+            while let Some(slot) = self.slots.get(index) {
+                index += 1;
+
+                match slot {
+                    Slot::Literal(literal) if *literal == c => {
+                        is_accepted = true;
+                        break;
+                    }
+                    Slot::Literal(_) => {}
+                    slot => {
+                        is_accepted = slot.accepts(c);
+                        break;
+                    }
+                }
+            }
+
+            if is_accepted {
+                result.extend(self.slots[next..index].iter().map(|slot| {
+                    match slot {
+                        Slot::Literal(literal) => *literal,
+                        _ => c,
+                    }
+                }));
+
+                next = index;
+            }
+
+            if next == self.slots.len() {
+                break;
+            }
+        }
+
+        result
+    }
+
+    fn placeholder(&self, value: &str) -> String {
+        self.slots
+            .iter()
+            .skip(value.chars().count())
+            .map(|slot| match slot {
+                Slot::Literal(literal) => *literal,
+                _ => self.placeholder,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pattern_inserts_literals() {
+        let phone = Pattern::new("(999) 999-9999");
+
+        assert_eq!(phone.apply("5551234567"), "(555) 123-4567");
+        assert_eq!(phone.apply("(555) 12"), "(555) 12");
+        assert_eq!(phone.apply("555-1"), "(555) 1");
+        assert_eq!(phone.apply("555-"), "(555");
+        assert_eq!(phone.apply("55512345678"), "(555) 123-4567");
+    }
+
+    #[test]
+    fn pattern_drops_characters_that_do_not_fit() {
+        let iban = Pattern::new("aa99 ****");
+
+        assert_eq!(iban.apply("DE4x4 12ab"), "DE44 12ab");
+        assert_eq!(iban.apply("1DE"), "DE");
+        assert_eq!(Pattern::new("\\9-9").apply("97"), "9-7");
+    }
+
+    #[test]
+    fn pattern_shows_placeholder_characters() {
+        let date = Pattern::new("99/99/9999").placeholder_char('-');
+
+        assert_eq!(date.placeholder(""), "--/--/----");
+        assert_eq!(date.placeholder("12/"), "--/----");
+        assert_eq!(date.placeholder("12/03/2024"), "");
+    }
+}
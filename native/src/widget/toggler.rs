@@ -34,6 +34,7 @@ pub struct Toggler<Message, Renderer: self::Renderer + text::Renderer> {
     spacing: u16,
     font: Renderer::Font,
     style: Renderer::Style,
+    is_disabled: bool,
 }
 
 impl<Message, Renderer: self::Renderer + text::Renderer>
@@ -66,6 +67,7 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
             spacing: 0,
             font: Renderer::Font::default(),
             style: Renderer::Style::default(),
+            is_disabled: false,
         }
     }
 
@@ -110,6 +112,14 @@ impl<Message, Renderer: self::Renderer + text::Renderer>
         self.style = style.into();
         self
     }
+
+    /// Sets whether the [`Toggler`] is disabled.
+    ///
+    /// A disabled [`Toggler`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Toggler<Message, Renderer>
@@ -162,6 +172,10 @@ where
         _clipboard: &mut dyn Clipboard,
         messages: &mut Vec<Message>,
     ) -> event::Status {
+        if self.is_disabled {
+            return event::Status::Ignored;
+        }
+
         match event {
             Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)) => {
                 let mouse_over = layout.bounds().contains(cursor_position);
@@ -219,6 +233,7 @@ where
             toggler_bounds,
             self.is_active,
             is_mouse_over,
+            self.is_disabled,
             label,
             &self.style,
         )
@@ -251,6 +266,7 @@ pub trait Renderer: crate::Renderer {
     ///   * the bounds of the [`Toggler`]
     ///   * whether the [`Toggler`] is activated or not
     ///   * whether the mouse is over the [`Toggler`] or not
+    ///   * whether the [`Toggler`] is disabled or not
     ///   * the drawn label of the [`Toggler`]
     ///   * the style of the [`Toggler`]
     fn draw(
@@ -258,6 +274,7 @@ pub trait Renderer: crate::Renderer {
         bounds: Rectangle,
         is_active: bool,
         is_mouse_over: bool,
+        is_disabled: bool,
         label: Option<Self::Output>,
         style: &Self::Style,
     ) -> Self::Output;
@@ -275,3 +292,66 @@ where
         Element::new(toggler)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Toggler;
+
+    use crate::clipboard;
+    use crate::program::Program;
+    use crate::testing::{simulator, Renderer};
+    use crate::{Command, Element};
+
+    #[derive(Default)]
+    struct Settings {
+        is_dark: bool,
+        is_disabled: bool,
+    }
+
+    impl Program for Settings {
+        type Renderer = Renderer;
+        type Message = bool;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            is_dark: bool,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<bool> {
+            self.is_dark = is_dark;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, bool, Renderer> {
+            Toggler::new(self.is_dark, String::from("Dark mode"), |is_dark| {
+                is_dark
+            })
+            .disabled(self.is_disabled)
+            .into()
+        }
+    }
+
+    #[test]
+    fn toggles_when_clicked() {
+        let mut simulator = simulator(Settings::default());
+
+        simulator.click("Dark mode").unwrap();
+
+        assert_eq!(simulator.take_messages(), vec![true]);
+        assert!(simulator.program().is_dark);
+    }
+
+    #[test]
+    fn ignores_clicks_while_disabled() {
+        let mut simulator = simulator(Settings {
+            is_disabled: true,
+            ..Settings::default()
+        });
+
+        simulator.click("Dark mode").unwrap();
+
+        assert!(simulator.messages().is_empty());
+        assert!(!simulator.program().is_dark);
+    }
+}
@@ -5,6 +5,7 @@ use iced_core::Rectangle;
 
 use crate::widget::container;
 use crate::widget::text::{self, Text};
+use crate::widget::Operation;
 use crate::{
     event, layout, Clipboard, Element, Event, Hasher, Layout, Length, Point,
     Widget,
@@ -163,6 +164,10 @@ where
 
         self.content.hash_layout(state);
     }
+
+    fn operate(&mut self, layout: Layout<'_>, operation: &mut dyn Operation) {
+        self.content.operate(layout, operation);
+    }
 }
 
 /// The renderer of a [`Tooltip`].
@@ -1,4 +1,104 @@
 //! Build window-based GUI applications.
+mod action;
 mod event;
+mod theme;
 
+pub use action::Action;
 pub use event::Event;
+pub use theme::Theme;
+
+use crate::command::{self, Command};
+use crate::subscription::{self, Subscription};
+use crate::{Point, Size};
+
+use std::time::Instant;
+
+/// Returns a [`Subscription`] that produces a message every time a frame is
+/// about to be drawn.
+///
+/// The resulting [`Instant`] is the frame time, which can be fed to an
+/// [`Animation`] to drive it.
+///
+/// A frame is drawn every time your application is updated. Thus, shells will
+/// keep drawing new frames for as long as you keep reacting to this
+/// [`Subscription`]. Therefore, you should only listen to it while something
+/// is actually animating.
+///
+/// [`Animation`]: crate::animation::Animation
+pub fn frames() -> Subscription<Instant> {
+    subscription::events_with(|event, _status| match event {
+        crate::Event::Window(Event::RedrawRequested(at)) => Some(at),
+        _ => None,
+    })
+}
+
+/// Resizes the window to the given logical dimensions.
+pub fn resize<Message>(width: u32, height: u32) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Resize { width, height }))
+}
+
+/// Moves the window to the given logical coordinates.
+pub fn move_to<Message>(x: i32, y: i32) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Move { x, y }))
+}
+
+/// Sets whether the window is minimized.
+pub fn minimize<Message>(minimized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Minimize(minimized)))
+}
+
+/// Sets whether the window is maximized.
+pub fn maximize<Message>(maximized: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Maximize(maximized)))
+}
+
+/// Sets whether the window is in borderless fullscreen.
+pub fn fullscreen<Message>(fullscreen: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::Fullscreen(fullscreen)))
+}
+
+/// Changes the title of the window.
+///
+/// The title will be overwritten the next time the title of the application
+/// changes.
+pub fn set_title<Message>(title: impl Into<String>) -> Command<Message> {
+    Command::single(command::Action::Window(Action::SetTitle(title.into())))
+}
+
+/// Starts moving the window with the mouse.
+///
+/// This is useful to drag undecorated windows around. It should be issued
+/// right after the left mouse button is pressed.
+pub fn drag<Message>() -> Command<Message> {
+    Command::single(command::Action::Window(Action::Drag))
+}
+
+/// Sets whether the cursor is confined to the window.
+pub fn grab_cursor<Message>(grabbed: bool) -> Command<Message> {
+    Command::single(command::Action::Window(Action::GrabCursor(grabbed)))
+}
+
+/// Fetches the current logical inner size of the window.
+pub fn fetch_size<Message>(
+    f: impl FnOnce(Size) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchSize(Box::new(f))))
+}
+
+/// Fetches the current logical position of the window.
+///
+/// The position may be unavailable in some platforms, like Wayland.
+pub fn fetch_position<Message>(
+    f: impl FnOnce(Option<Point>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchPosition(Box::new(f))))
+}
+
+/// Fetches the scale factor of the monitor of the window.
+pub fn fetch_scale_factor<Message>(
+    f: impl FnOnce(f64) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Window(Action::FetchScaleFactor(
+        Box::new(f),
+    )))
+}
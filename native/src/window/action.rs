@@ -0,0 +1,108 @@
+use crate::{Point, Size};
+
+use std::fmt;
+
+/// An operation to be performed on the window of an application.
+pub enum Action<T> {
+    /// Resize the window to the given logical size.
+    Resize {
+        /// The new logical width of the window
+        width: u32,
+        /// The new logical height of the window
+        height: u32,
+    },
+    /// Move the window to the given logical position.
+    ///
+    /// Unsupported on Wayland.
+    Move {
+        /// The new logical x location of the window
+        x: i32,
+        /// The new logical y location of the window
+        y: i32,
+    },
+    /// Set whether the window is minimized.
+    Minimize(bool),
+    /// Set whether the window is maximized.
+    Maximize(bool),
+    /// Set whether the window is in borderless fullscreen in its current
+    /// monitor.
+    Fullscreen(bool),
+    /// Change the title of the window.
+    SetTitle(String),
+    /// Move the window with the left mouse button until the button is
+    /// released.
+    ///
+    /// There's no guarantee that this will work unless the left mouse
+    /// button was pressed immediately before this function is called.
+    Drag,
+    /// Set whether the cursor is confined to the window.
+    GrabCursor(bool),
+    /// Fetch the current logical inner size of the window.
+    FetchSize(Box<dyn FnOnce(Size) -> T + 'static>),
+    /// Fetch the current logical position of the window, if available.
+    FetchPosition(Box<dyn FnOnce(Option<Point>) -> T + 'static>),
+    /// Fetch the scale factor of the monitor of the window.
+    FetchScaleFactor(Box<dyn FnOnce(f64) -> T + 'static>),
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a window [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Resize { width, height } => Action::Resize { width, height },
+            Self::Move { x, y } => Action::Move { x, y },
+            Self::Minimize(minimized) => Action::Minimize(minimized),
+            Self::Maximize(maximized) => Action::Maximize(maximized),
+            Self::Fullscreen(fullscreen) => Action::Fullscreen(fullscreen),
+            Self::SetTitle(title) => Action::SetTitle(title),
+            Self::Drag => Action::Drag,
+            Self::GrabCursor(grabbed) => Action::GrabCursor(grabbed),
+            Self::FetchSize(o) => {
+                Action::FetchSize(Box::new(move |size| f(o(size))))
+            }
+            Self::FetchPosition(o) => {
+                Action::FetchPosition(Box::new(move |position| f(o(position))))
+            }
+            Self::FetchScaleFactor(o) => {
+                Action::FetchScaleFactor(Box::new(move |scale_factor| {
+                    f(o(scale_factor))
+                }))
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Resize { width, height } => write!(
+                f,
+                "Action::Resize {{ width: {}, height: {} }}",
+                width, height
+            ),
+            Self::Move { x, y } => {
+                write!(f, "Action::Move {{ x: {}, y: {} }}", x, y)
+            }
+            Self::Minimize(minimized) => {
+                write!(f, "Action::Minimize({})", minimized)
+            }
+            Self::Maximize(maximized) => {
+                write!(f, "Action::Maximize({})", maximized)
+            }
+            Self::Fullscreen(fullscreen) => {
+                write!(f, "Action::Fullscreen({})", fullscreen)
+            }
+            Self::SetTitle(title) => write!(f, "Action::SetTitle({:?})", title),
+            Self::Drag => write!(f, "Action::Drag"),
+            Self::GrabCursor(grabbed) => {
+                write!(f, "Action::GrabCursor({})", grabbed)
+            }
+            Self::FetchSize(_) => write!(f, "Action::FetchSize"),
+            Self::FetchPosition(_) => write!(f, "Action::FetchPosition"),
+            Self::FetchScaleFactor(_) => write!(f, "Action::FetchScaleFactor"),
+        }
+    }
+}
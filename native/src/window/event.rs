@@ -1,7 +1,15 @@
+use crate::window::Theme;
+
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
 pub enum Event {
     /// A window was resized.
     Resized {
@@ -12,18 +20,72 @@ pub enum Event {
         height: u32,
     },
 
+    /// A window was moved.
+    Moved {
+        /// The new logical x location of the window
+        x: i32,
+
+        /// The new logical y location of the window
+        y: i32,
+    },
+
+    /// The scale factor of a window has changed.
+    ///
+    /// This happens when the user changes their display settings or moves the
+    /// window to a monitor with a different pixel density. A [`Resized`]
+    /// event will also be emitted, if necessary.
+    ///
+    /// [`Resized`]: Self::Resized
+    ScaleFactorChanged(f64),
+
+    /// The system theme of a window has changed.
+    ///
+    /// Shells also produce this event on startup with the initial theme of the
+    /// system, when it is known.
+    ThemeChanged(Theme),
+
     /// The user has requested for the window to close.
     ///
     /// Usually, you will want to terminate the execution whenever this event
     /// occurs.
     CloseRequested,
 
+    /// A window is about to be redrawn.
+    ///
+    /// It carries the frame time of the redraw, which can be used to drive
+    /// animations. See [`frames`] to learn more.
+    ///
+    /// Shells produce this event every time the window is redrawn, which
+    /// happens after any other events or messages are processed. Widgets that
+    /// need another frame as soon as possible should capture this event, while
+    /// widgets that need one later on should report it to
+    /// [`Operation::animation`].
+    ///
+    /// This event cannot be serialized, since it carries an [`Instant`].
+    ///
+    /// [`frames`]: crate::window::frames
+    /// [`Operation::animation`]: crate::widget::Operation::animation
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    RedrawRequested(Instant),
+
     /// A window was focused.
     Focused,
 
     /// A window was unfocused.
     Unfocused,
 
+    /// A window was minimized.
+    Minimized,
+
+    /// A window was restored after being minimized.
+    Restored,
+
+    /// The visibility of a window has changed.
+    ///
+    /// It carries whether the window is now completely hidden from view, in
+    /// which case drawing it is wasted effort.
+    Occluded(bool),
+
     /// A file is being hovered over the window.
     ///
     /// When the user hovers multiple files at once, this event will be emitted
@@ -0,0 +1,14 @@
+/// The theme of a window, as reported by the operating system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(crate = "serde")
+)]
+pub enum Theme {
+    /// A light theme.
+    Light,
+
+    /// A dark theme.
+    Dark,
+}
@@ -0,0 +1,2 @@
+//! Animate values over time.
+pub use crate::runtime::animation::{Animation, Easing, Interpolate};
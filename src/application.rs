@@ -1,6 +1,6 @@
 use crate::window;
 use crate::{
-    Clipboard, Color, Command, Element, Executor, Settings, Subscription,
+    Clipboard, Color, Command, Element, Executor, Settings, Subscription, Theme,
 };
 
 /// An interactive cross-platform application.
@@ -164,11 +164,28 @@ pub trait Application: Sized {
         window::Mode::Windowed
     }
 
+    /// Returns the [`Theme`] of the [`Application`].
+    ///
+    /// The [`Theme`] is used by all the built-in widgets and passed to their
+    /// style sheets. Iced ships with a [`Theme::light`], a [`Theme::dark`] and
+    /// a [`Theme::high_contrast`] theme, but you can also create your own
+    /// with [`Theme::custom`].
+    ///
+    /// You can follow the light or dark appearance of the operating system
+    /// by listening to [`window::theme_changes`] in your
+    /// [`Application::subscription`].
+    ///
+    /// By default, it returns the light [`Theme`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the background color of the [`Application`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// By default, it returns the background color of the [`Theme`] of the
+    /// [`Application`].
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Application`].
@@ -191,6 +208,18 @@ pub trait Application: Sized {
         false
     }
 
+    /// Returns a snapshot of the current state of the [`Application`].
+    ///
+    /// When the `debug` feature is enabled, snapshots let you travel back in
+    /// time through the states of your [`Application`] from the debug view.
+    /// If your [`Application`] implements `Clone`, you can simply return
+    /// `Some(self.clone())`.
+    ///
+    /// By default, it returns `None`, which disables time travel.
+    fn snapshot(&self) -> Option<Self> {
+        None
+    }
+
     /// Runs the [`Application`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -285,6 +314,10 @@ where
         self.0.subscription()
     }
 
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
+
     fn background_color(&self) -> Color {
         self.0.background_color()
     }
@@ -296,6 +329,10 @@ where
     fn should_exit(&self) -> bool {
         self.0.should_exit()
     }
+
+    fn snapshot(&self) -> Option<Self> {
+        self.0.snapshot().map(Instance)
+    }
 }
 
 #[cfg(target_arch = "wasm32")]
@@ -332,4 +369,8 @@ where
     fn view(&mut self) -> Element<'_, Self::Message> {
         self.0.view()
     }
+
+    fn theme(&self) -> Theme {
+        self.0.theme()
+    }
 }
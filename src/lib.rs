@@ -183,6 +183,8 @@ mod error;
 mod result;
 mod sandbox;
 
+#[cfg(not(target_arch = "wasm32"))]
+pub mod animation;
 pub mod executor;
 pub mod keyboard;
 pub mod mouse;
@@ -233,6 +235,16 @@ use iced_glow as renderer;
 #[cfg(target_arch = "wasm32")]
 use iced_web as runtime;
 
+#[cfg(all(
+    not(target_arch = "wasm32"),
+    any(feature = "sheet", feature = "glow_sheet")
+))]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "sheet", feature = "glow_sheet")))
+)]
+pub use renderer::sheet;
+
 #[doc(no_inline)]
 pub use widget::*;
 
@@ -245,7 +257,7 @@ pub use sandbox::Sandbox;
 pub use settings::Settings;
 
 pub use runtime::{
-    futures, Align, Background, Clipboard, Color, Command, Font,
-    HorizontalAlignment, Length, Point, Rectangle, Size, Subscription, Vector,
-    VerticalAlignment,
+    futures, Align, Background, Clipboard, Color, Command, ContentFit, Font,
+    HorizontalAlignment, Length, Point, Rectangle, Size, Subscription, Theme,
+    Vector, VerticalAlignment,
 };
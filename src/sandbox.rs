@@ -1,6 +1,6 @@
 use crate::{
     Application, Clipboard, Color, Command, Element, Error, Settings,
-    Subscription,
+    Subscription, Theme,
 };
 
 /// A sandboxed [`Application`].
@@ -112,11 +112,19 @@ pub trait Sandbox {
     /// These widgets can produce __messages__ based on user interaction.
     fn view(&mut self) -> Element<'_, Self::Message>;
 
+    /// Returns the [`Theme`] of the [`Sandbox`].
+    ///
+    /// By default, it returns the light [`Theme`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the background color of the [`Sandbox`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// By default, it returns the background color of the [`Theme`] of the
+    /// [`Sandbox`].
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Sandbox`].
@@ -132,6 +140,21 @@ pub trait Sandbox {
         1.0
     }
 
+    /// Returns a snapshot of the current state of the [`Sandbox`].
+    ///
+    /// When the `debug` feature is enabled, snapshots let you travel back in
+    /// time through the states of your [`Sandbox`] from the debug view. If
+    /// your [`Sandbox`] implements `Clone`, you can simply return
+    /// `Some(self.clone())`.
+    ///
+    /// By default, it returns `None`, which disables time travel.
+    fn snapshot(&self) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
     /// Runs the [`Sandbox`].
     ///
     /// On native platforms, this method will take control of the current thread
@@ -180,6 +203,10 @@ where
         T::view(self)
     }
 
+    fn theme(&self) -> Theme {
+        T::theme(self)
+    }
+
     fn background_color(&self) -> Color {
         T::background_color(self)
     }
@@ -187,4 +214,8 @@ where
     fn scale_factor(&self) -> f64 {
         T::scale_factor(self)
     }
+
+    fn snapshot(&self) -> Option<Self> {
+        T::snapshot(self)
+    }
 }
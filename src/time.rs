@@ -1,12 +1,51 @@
 //! Listen and react to time.
-use crate::Subscription;
+use crate::{Command, Subscription};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 /// Returns a [`Subscription`] that produces messages at a set interval.
 ///
 /// The first message is produced after a `duration`, and then continues to
 /// produce more messages every `duration` after that.
-pub fn every(
-    duration: std::time::Duration,
-) -> Subscription<std::time::Instant> {
+pub fn every(duration: Duration) -> Subscription<Instant> {
     iced_futures::time::every(duration)
 }
+
+/// Returns a [`Command`] that produces the current [`Instant`] once the given
+/// `duration` has elapsed.
+pub fn after(duration: Duration) -> Command<Instant> {
+    Command::from(iced_futures::time::after(duration))
+}
+
+/// Debounces the given [`Subscription`], keeping only the latest message of
+/// every burst once `duration` has elapsed without new ones.
+///
+/// The `id` identifies the resulting [`Subscription`].
+pub fn debounce<I, Message>(
+    id: I,
+    duration: Duration,
+    subscription: Subscription<Message>,
+) -> Subscription<Message>
+where
+    I: Hash + Clone + 'static,
+    Message: Send + 'static,
+{
+    iced_futures::time::debounce(id, duration, subscription)
+}
+
+/// Throttles the given [`Subscription`], producing at most one message every
+/// `duration`.
+///
+/// The `id` identifies the resulting [`Subscription`].
+pub fn throttle<I, Message>(
+    id: I,
+    duration: Duration,
+    subscription: Subscription<Message>,
+) -> Subscription<Message>
+where
+    I: Hash + Clone + 'static,
+    Message: Send + 'static,
+{
+    iced_futures::time::throttle(id, duration, subscription)
+}
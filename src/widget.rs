@@ -16,9 +16,9 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, toggler, tooltip, Column, Row,
-        Space, Text,
+        button, checkbox, combo_box, container, number_input, pane_grid,
+        pick_list, progress_bar, radio, rule, scrollable, shader, slider,
+        text_input, toggler, tooltip, Column, Row, Space, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -38,8 +38,10 @@ mod platform {
     #[cfg_attr(docsrs, doc(cfg(feature = "image")))]
     pub mod image {
         //! Display images in your user interface.
-        pub use crate::runtime::image::viewer;
-        pub use crate::runtime::image::{Handle, Image, Viewer};
+        pub use crate::runtime::image::{animated, texture, viewer};
+        pub use crate::runtime::image::{
+            AnimatedImage, FilterMethod, Handle, Image, Texture, Viewer,
+        };
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -50,10 +52,12 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
+        button::Button, checkbox::Checkbox, combo_box::ComboBox,
+        container::Container, image::Image, number_input::NumberInput,
         pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        radio::Radio, rule::Rule, scrollable::Scrollable, shader::Shader,
+        slider::Slider, svg::Svg, text_input::TextInput, toggler::Toggler,
+        tooltip::Tooltip,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -7,3 +7,12 @@ pub mod icon;
 pub use icon::Icon;
 pub use mode::Mode;
 pub use settings::Settings;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::window::{
+    drag, fetch_position, fetch_scale_factor, fetch_size, frames, fullscreen,
+    grab_cursor, maximize, minimize, move_to, resize, set_title, Action, Theme,
+};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::appearance::theme_changes;
@@ -1,4 +1,5 @@
 //! Allow your users to perform actions by pressing a button.
+use crate::Theme;
 use iced_core::{Background, Color, Vector};
 
 /// The appearance of a button.
@@ -27,10 +28,10 @@ impl std::default::Default for Style {
 
 /// A set of rules that dictate the style of a button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: active.shadow_offset + Vector::new(0.0, 1.0),
@@ -38,15 +39,15 @@ pub trait StyleSheet {
         }
     }
 
-    fn pressed(&self) -> Style {
+    fn pressed(&self, theme: &Theme) -> Style {
         Style {
             shadow_offset: Vector::default(),
-            ..self.active()
+            ..self.active(theme)
         }
     }
 
-    fn disabled(&self) -> Style {
-        let active = self.active();
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             shadow_offset: Vector::default(),
@@ -68,14 +69,16 @@ pub trait StyleSheet {
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
         Style {
             shadow_offset: Vector::new(0.0, 0.0),
-            background: Some(Background::Color([0.87, 0.87, 0.87].into())),
+            background: Some(Background::Color(background.weak.color)),
             border_radius: 2.0,
             border_width: 1.0,
-            border_color: [0.7, 0.7, 0.7].into(),
-            text_color: Color::BLACK,
+            border_color: background.strong.color,
+            text_color: background.weak.text,
         }
     }
 }
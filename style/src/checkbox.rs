@@ -1,4 +1,5 @@
 //! Show toggle controls using checkboxes.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a checkbox.
@@ -13,28 +14,54 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a checkbox.
 pub trait StyleSheet {
-    fn active(&self, is_checked: bool) -> Style;
+    fn active(&self, theme: &Theme, is_checked: bool) -> Style;
 
-    fn hovered(&self, is_checked: bool) -> Style;
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style;
+
+    fn disabled(&self, theme: &Theme, is_checked: bool) -> Style {
+        let active = self.active(theme, is_checked);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+            },
+            checkmark_color: Color {
+                a: active.checkmark_color.a * 0.5,
+                ..active.checkmark_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self, _is_checked: bool) -> Style {
+    fn active(&self, theme: &Theme, _is_checked: bool) -> Style {
+        let palette = theme.extended_palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            checkmark_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(palette.background.base.color),
+            checkmark_color: palette.primary.base.color,
             border_radius: 5.0,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: palette.background.strong.color,
         }
     }
 
-    fn hovered(&self, is_checked: bool) -> Style {
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> Style {
+        let palette = theme.extended_palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active(is_checked)
+            background: Background::Color(palette.background.weak.color),
+            ..self.active(theme, is_checked)
         }
     }
 }
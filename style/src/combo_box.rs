@@ -0,0 +1,39 @@
+//! Display a text input with a list of suggestions to choose from.
+use crate::menu;
+use crate::text_input;
+
+/// A set of rules that dictate the style of a combo box.
+pub trait StyleSheet {
+    /// Produces the style sheet of the text input of a combo box.
+    fn text_input(&self) -> Box<dyn text_input::StyleSheet>;
+
+    /// Produces the style sheet of the menu of suggestions of a combo box.
+    fn menu(&self) -> Box<dyn menu::StyleSheet>;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn text_input(&self) -> Box<dyn text_input::StyleSheet> {
+        std::default::Default::default()
+    }
+
+    fn menu(&self) -> Box<dyn menu::StyleSheet> {
+        std::default::Default::default()
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
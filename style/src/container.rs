@@ -1,4 +1,5 @@
 //! Decorate content and apply alignment.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a container.
@@ -26,13 +27,13 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a container.
 pub trait StyleSheet {
     /// Produces the style of a container.
-    fn style(&self) -> Style;
+    fn style(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn style(&self) -> Style {
+    fn style(&self, _theme: &Theme) -> Style {
         Style {
             text_color: None,
             background: None,
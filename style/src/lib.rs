@@ -6,10 +6,14 @@
 //! ![The foundations of the Iced ecosystem](https://github.com/hecrj/iced/blob/0525d76ff94e828b7b21634fa94a747022001c83/docs/graphs/foundations.png?raw=true)
 pub use iced_core::{Background, Color};
 
+pub mod theme;
+
 pub mod button;
 pub mod checkbox;
+pub mod combo_box;
 pub mod container;
 pub mod menu;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
@@ -19,3 +23,9 @@ pub mod scrollable;
 pub mod slider;
 pub mod text_input;
 pub mod toggler;
+
+#[cfg(feature = "sheet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sheet")))]
+pub mod sheet;
+
+pub use theme::Theme;
@@ -1,3 +1,5 @@
+//! Build and show dropdown menus.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a menu.
@@ -23,3 +25,49 @@ impl std::default::Default for Style {
         }
     }
 }
+
+/// A set of rules that dictate the style of a menu.
+pub trait StyleSheet {
+    /// Produces the style of a menu.
+    fn style(&self, theme: &Theme) -> Style;
+}
+
+/// A fixed [`Style`] can be used as a [`StyleSheet`] that ignores the
+/// [`Theme`].
+impl StyleSheet for Style {
+    fn style(&self, _theme: &Theme) -> Style {
+        *self
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self, theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
+
+        Style {
+            text_color: palette.background.weak.text,
+            background: Background::Color(palette.background.weak.color),
+            border_width: 1.0,
+            border_color: palette.background.strong.color,
+            selected_text_color: palette.primary.base.text,
+            selected_background: Background::Color(palette.primary.base.color),
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
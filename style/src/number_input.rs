@@ -0,0 +1,99 @@
+//! Display fields that can be filled with numbers.
+use crate::text_input;
+use crate::Theme;
+use iced_core::{Background, Color};
+
+/// The appearance of the buttons of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub button_background: Background,
+    pub button_border_radius: f32,
+    pub icon_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            button_background: Background::Color([0.87, 0.87, 0.87].into()),
+            button_border_radius: 0.0,
+            icon_color: Color::BLACK,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a number input.
+pub trait StyleSheet {
+    /// Produces the style sheet of the field of a number input.
+    fn text_input(&self) -> Box<dyn text_input::StyleSheet>;
+
+    /// Produces the style of the buttons of an active number input.
+    fn active(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of a hovered button of a number input.
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.active(theme)
+    }
+
+    /// Produces the style of the buttons of a disabled number input, and
+    /// of a button that cannot step the value any further.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            button_background: match active.button_background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+            },
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                ..active.icon_color
+            },
+            ..active
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn text_input(&self) -> Box<dyn text_input::StyleSheet> {
+        std::default::Default::default()
+    }
+
+    fn active(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
+        Style {
+            button_background: Background::Color(background.weak.color),
+            button_border_radius: 5.0,
+            icon_color: background.weak.text,
+        }
+    }
+
+    fn hovered(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
+        Style {
+            button_background: Background::Color(background.strong.color),
+            icon_color: background.strong.text,
+            ..self.active(theme)
+        }
+    }
+}
+
+impl std::default::Default for Box<dyn StyleSheet> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<T> From<T> for Box<dyn StyleSheet>
+where
+    T: 'static + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -1,14 +1,15 @@
 //! Let your users split regions of your application and organize layout
 //! dynamically.
+use crate::Theme;
 use iced_core::Color;
 
 /// A set of rules that dictate the style of a container.
 pub trait StyleSheet {
     /// The [`Line`] to draw when a split is picked.
-    fn picked_split(&self) -> Option<Line>;
+    fn picked_split(&self, theme: &Theme) -> Option<Line>;
 
     /// The [`Line`] to draw when a split is hovered.
-    fn hovered_split(&self) -> Option<Line>;
+    fn hovered_split(&self, theme: &Theme) -> Option<Line>;
 }
 
 /// A line.
@@ -26,11 +27,11 @@ pub struct Line {
 struct Default;
 
 impl StyleSheet for Default {
-    fn picked_split(&self) -> Option<Line> {
+    fn picked_split(&self, _theme: &Theme) -> Option<Line> {
         None
     }
 
-    fn hovered_split(&self) -> Option<Line> {
+    fn hovered_split(&self, _theme: &Theme) -> Option<Line> {
         None
     }
 }
@@ -1,4 +1,5 @@
 use crate::menu;
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a pick list.
@@ -27,29 +28,59 @@ impl std::default::Default for Style {
 
 /// A set of rules that dictate the style of a container.
 pub trait StyleSheet {
-    fn menu(&self) -> menu::Style;
+    fn menu(&self) -> Box<dyn menu::StyleSheet>;
 
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a container.
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of a disabled pick list.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            text_color: Color {
+                a: active.text_color.a * 0.5,
+                ..active.text_color
+            },
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn menu(&self) -> menu::Style {
-        menu::Style::default()
+    fn menu(&self) -> Box<dyn menu::StyleSheet> {
+        std::default::Default::default()
     }
 
-    fn active(&self) -> Style {
-        Style::default()
+    fn active(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
+        Style {
+            text_color: background.weak.text,
+            background: Background::Color(background.weak.color),
+            border_color: background.strong.color,
+            ..Style::default()
+        }
     }
 
-    fn hovered(&self) -> Style {
+    fn hovered(&self, theme: &Theme) -> Style {
         Style {
-            border_color: Color::BLACK,
-            ..self.active()
+            border_color: theme.palette().text,
+            ..self.active(theme)
         }
     }
 }
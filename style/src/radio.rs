@@ -1,4 +1,5 @@
 //! Create choices using radio buttons.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a radio button.
@@ -12,27 +13,53 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a radio button.
 pub trait StyleSheet {
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
+
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+
+        Style {
+            background: match active.background {
+                Background::Color(color) => Background::Color(Color {
+                    a: color.a * 0.5,
+                    ..color
+                }),
+            },
+            dot_color: Color {
+                a: active.dot_color.a * 0.5,
+                ..active.dot_color
+            },
+            border_color: Color {
+                a: active.border_color.a * 0.5,
+                ..active.border_color
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.95, 0.95, 0.95)),
-            dot_color: Color::from_rgb(0.3, 0.3, 0.3),
+            background: Background::Color(palette.background.base.color),
+            dot_color: palette.primary.base.color,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.6, 0.6, 0.6),
+            border_color: palette.background.strong.color,
         }
     }
 
-    fn hovered(&self) -> Style {
+    fn hovered(&self, theme: &Theme) -> Style {
+        let palette = theme.extended_palette();
+
         Style {
-            background: Background::Color(Color::from_rgb(0.90, 0.90, 0.90)),
-            ..self.active()
+            background: Background::Color(palette.background.weak.color),
+            ..self.active(theme)
         }
     }
 }
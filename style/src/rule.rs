@@ -1,5 +1,6 @@
 //! Display a horizontal or vertical rule for dividing content.
 
+use crate::Theme;
 use iced_core::Color;
 
 /// The fill mode of a rule.
@@ -93,14 +94,20 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a rule.
 pub trait StyleSheet {
     /// Produces the style of a rule.
-    fn style(&self) -> Style;
+    fn style(&self, theme: &Theme) -> Style;
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn style(&self) -> Style {
-        Style::default()
+    fn style(&self, theme: &Theme) -> Style {
+        Style {
+            color: Color {
+                a: 0.51,
+                ..theme.extended_palette().background.strong.color
+            },
+            ..Style::default()
+        }
     }
 }
 
@@ -0,0 +1,1172 @@
+//! Load style sheets from files.
+//!
+//! A [`Sheet`] is a set of style classes, grouped by widget and keyed by
+//! name, that can be loaded from a TOML or RON file:
+//!
+//! ```toml
+//! [button.primary.active]
+//! background = "#3366ff"
+//! text_color = "#ffffff"
+//! border_radius = 4.0
+//!
+//! [button.primary.hovered]
+//! background = "#4477ff"
+//!
+//! [container.card]
+//! padding = [10, 20]
+//!
+//! [container.card.active]
+//! background = "#ffffff"
+//! border_radius = 8.0
+//! ```
+//!
+//! Every state of a [`Class`] only needs the properties it changes. Missing
+//! properties are inherited from the `active` state, and then from the default
+//! style sheet of the [`Theme`].
+//!
+//! In RON files, optional properties can be written without `Some`.
+//!
+//! A [`Class`] implements the style sheet of its widget, so it can be used
+//! directly:
+//!
+//! ```ignore
+//! let primary = self.sheet.button("primary");
+//!
+//! Button::new(&mut self.button, Text::new("Submit"))
+//!     .padding(primary.padding().unwrap_or(Padding::new(5)))
+//!     .style(primary)
+//! ```
+use crate::{
+    button, checkbox, container, menu, pane_grid, pick_list, progress_bar,
+    radio, rule, scrollable, slider, text_input, toggler, Theme,
+};
+use iced_core::{Background, Color, Vector};
+
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fmt;
+use std::io;
+use std::path::Path;
+use std::sync::Arc;
+
+/// A set of style classes loaded from a file.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Sheet {
+    button: HashMap<String, Class<Button>>,
+    checkbox: HashMap<String, Class<Checkbox>>,
+    container: HashMap<String, Class<Container>>,
+    menu: HashMap<String, Class<Menu>>,
+    pane_grid: HashMap<String, Class<Split>>,
+    pick_list: HashMap<String, Class<PickList>>,
+    progress_bar: HashMap<String, Class<ProgressBar>>,
+    radio: HashMap<String, Class<Radio>>,
+    rule: HashMap<String, Class<Rule>>,
+    scrollable: HashMap<String, Class<Scrollbar>>,
+    slider: HashMap<String, Class<Slider>>,
+    text_input: HashMap<String, Class<TextInput>>,
+    toggler: HashMap<String, Class<Toggler>>,
+}
+
+impl Sheet {
+    /// Loads a [`Sheet`] from the file at the given path.
+    ///
+    /// The format of the file is chosen by its extension, which can be either
+    /// `toml` or `ron`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path)
+            .map_err(|error| Error::Io(Arc::new(error)))?;
+
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("toml") => Self::from_toml(&source),
+            Some("ron") => Self::from_ron(&source),
+            _ => Err(Error::UnknownFormat),
+        }
+    }
+
+    /// Parses a [`Sheet`] written in TOML.
+    pub fn from_toml(source: &str) -> Result<Self, Error> {
+        toml::from_str(source).map_err(|error| Error::Parse(error.to_string()))
+    }
+
+    /// Parses a [`Sheet`] written in RON.
+    ///
+    /// The `implicit_some` extension is always enabled.
+    pub fn from_ron(source: &str) -> Result<Self, Error> {
+        // The extensions of RON can only be enabled with attributes
+        let source = format!("#![enable(implicit_some)] {}", source);
+
+        ron::de::from_str(&source)
+            .map_err(|error| Error::Parse(error.to_string()))
+    }
+
+    /// Returns the button [`Class`] with the given name.
+    ///
+    /// An empty [`Class`] is returned if it does not exist.
+    pub fn button(&self, name: &str) -> Class<Button> {
+        class(&self.button, name)
+    }
+
+    /// Returns the checkbox [`Class`] with the given name.
+    pub fn checkbox(&self, name: &str) -> Class<Checkbox> {
+        class(&self.checkbox, name)
+    }
+
+    /// Returns the container [`Class`] with the given name.
+    pub fn container(&self, name: &str) -> Class<Container> {
+        class(&self.container, name)
+    }
+
+    /// Returns the menu [`Class`] with the given name.
+    pub fn menu(&self, name: &str) -> Class<Menu> {
+        class(&self.menu, name)
+    }
+
+    /// Returns the pane grid [`Class`] with the given name.
+    pub fn pane_grid(&self, name: &str) -> Class<Split> {
+        class(&self.pane_grid, name)
+    }
+
+    /// Returns the pick list [`Class`] with the given name.
+    pub fn pick_list(&self, name: &str) -> Class<PickList> {
+        class(&self.pick_list, name)
+    }
+
+    /// Returns the progress bar [`Class`] with the given name.
+    pub fn progress_bar(&self, name: &str) -> Class<ProgressBar> {
+        class(&self.progress_bar, name)
+    }
+
+    /// Returns the radio [`Class`] with the given name.
+    pub fn radio(&self, name: &str) -> Class<Radio> {
+        class(&self.radio, name)
+    }
+
+    /// Returns the rule [`Class`] with the given name.
+    pub fn rule(&self, name: &str) -> Class<Rule> {
+        class(&self.rule, name)
+    }
+
+    /// Returns the scrollable [`Class`] with the given name.
+    pub fn scrollable(&self, name: &str) -> Class<Scrollbar> {
+        class(&self.scrollable, name)
+    }
+
+    /// Returns the slider [`Class`] with the given name.
+    pub fn slider(&self, name: &str) -> Class<Slider> {
+        class(&self.slider, name)
+    }
+
+    /// Returns the text input [`Class`] with the given name.
+    pub fn text_input(&self, name: &str) -> Class<TextInput> {
+        class(&self.text_input, name)
+    }
+
+    /// Returns the toggler [`Class`] with the given name.
+    pub fn toggler(&self, name: &str) -> Class<Toggler> {
+        class(&self.toggler, name)
+    }
+}
+
+fn class<P: Clone + Default>(
+    classes: &HashMap<String, Class<P>>,
+    name: &str,
+) -> Class<P> {
+    classes.get(name).cloned().unwrap_or_default()
+}
+
+/// An error produced when loading a [`Sheet`].
+#[derive(Debug, Clone)]
+pub enum Error {
+    /// The file could not be read.
+    Io(Arc<io::Error>),
+
+    /// The file is not a valid [`Sheet`].
+    Parse(String),
+
+    /// The extension of the file is neither `toml` nor `ron`.
+    UnknownFormat,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(error) => {
+                write!(f, "the style sheet could not be read: {}", error)
+            }
+            Error::Parse(error) => {
+                write!(f, "the style sheet is invalid: {}", error)
+            }
+            Error::UnknownFormat => {
+                write!(f, "the style sheet must be a TOML or RON file")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// A style class, with a [`Patch`] for every state of a widget.
+///
+/// The `active` patch applies to all the states. The patches of the states a
+/// widget does not have are ignored.
+///
+/// A [`Class`] can also set the padding of its widget, which cannot change
+/// between states.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Class<P> {
+    padding: Option<Padding>,
+    active: P,
+    hovered: P,
+    pressed: P,
+    focused: P,
+    dragging: P,
+    disabled: P,
+    read_only: P,
+    invalid: P,
+    checked: P,
+}
+
+impl<P> Class<P> {
+    /// Returns the padding of the [`Class`], if it sets one.
+    pub fn padding(&self) -> Option<iced_core::Padding> {
+        self.padding.map(iced_core::Padding::from)
+    }
+}
+
+impl<P: Patch> Class<P> {
+    /// Applies the `active` patch and then the patch of the given state to a
+    /// style.
+    pub fn resolve(&self, state: &P, style: P::Style) -> P::Style {
+        state.apply(self.active.apply(style))
+    }
+}
+
+/// A partial style that overrides some properties of a complete one.
+pub trait Patch {
+    /// The complete style.
+    type Style;
+
+    /// Overrides the properties of the given style that are present in the
+    /// [`Patch`].
+    fn apply(&self, style: Self::Style) -> Self::Style;
+}
+
+/// A value of a [`Patch`] that overrides a property of a style.
+trait Property<T> {
+    fn replace(&self, current: T) -> T;
+}
+
+impl Property<f32> for f32 {
+    fn replace(&self, _current: f32) -> f32 {
+        *self
+    }
+}
+
+impl Property<u16> for u16 {
+    fn replace(&self, _current: u16) -> u16 {
+        *self
+    }
+}
+
+macro_rules! patch {
+    (
+        $(#[$meta:meta])*
+        $name:ident => $style:ty {
+            $($field:ident: $property:ty,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+        #[serde(default, deny_unknown_fields)]
+        pub struct $name {
+            $($field: Option<$property>,)*
+        }
+
+        impl Patch for $name {
+            type Style = $style;
+
+            fn apply(&self, mut style: $style) -> $style {
+                $(
+                    if let Some(property) = &self.$field {
+                        style.$field = property.replace(style.$field);
+                    }
+                )*
+
+                style
+            }
+        }
+
+        impl Property<$style> for $name {
+            fn replace(&self, current: $style) -> $style {
+                Patch::apply(self, current)
+            }
+        }
+    };
+}
+
+/// A color written in hexadecimal, like `"#3366ff"` or `"#3366ff80"`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(try_from = "String")]
+pub struct Hex(pub Color);
+
+impl TryFrom<String> for Hex {
+    type Error = String;
+
+    fn try_from(hex: String) -> Result<Self, String> {
+        let digits = hex.trim_start_matches('#');
+
+        let channel = |i: usize| {
+            digits
+                .get(i * 2..i * 2 + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+
+        let color = match digits.len() {
+            6 | 8 if digits.is_ascii() => (|| {
+                let alpha = if digits.len() == 8 { channel(3)? } else { 255 };
+
+                Some(Color::from_rgba8(
+                    channel(0)?,
+                    channel(1)?,
+                    channel(2)?,
+                    f32::from(alpha) / 255.0,
+                ))
+            })(),
+            _ => None,
+        };
+
+        color
+            .map(Hex)
+            .ok_or_else(|| format!("invalid hexadecimal color: {}", hex))
+    }
+}
+
+impl Property<Color> for Hex {
+    fn replace(&self, _current: Color) -> Color {
+        self.0
+    }
+}
+
+impl Property<Option<Color>> for Hex {
+    fn replace(&self, _current: Option<Color>) -> Option<Color> {
+        Some(self.0)
+    }
+}
+
+impl Property<Background> for Hex {
+    fn replace(&self, _current: Background) -> Background {
+        Background::Color(self.0)
+    }
+}
+
+impl Property<Option<Background>> for Hex {
+    fn replace(&self, _current: Option<Background>) -> Option<Background> {
+        Some(Background::Color(self.0))
+    }
+}
+
+/// An offset written as `[x, y]`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Offset(pub f32, pub f32);
+
+impl Property<Vector> for Offset {
+    fn replace(&self, _current: Vector) -> Vector {
+        Vector::new(self.0, self.1)
+    }
+}
+
+/// A padding written as `4`, `[vertical, horizontal]` or
+/// `[top, right, bottom, left]`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(untagged)]
+pub enum Padding {
+    /// The same padding on all the sides.
+    Uniform(u16),
+
+    /// A vertical and a horizontal padding.
+    Axes([u16; 2]),
+
+    /// A padding for every side.
+    Sides([u16; 4]),
+}
+
+impl From<Padding> for iced_core::Padding {
+    fn from(padding: Padding) -> Self {
+        match padding {
+            Padding::Uniform(padding) => padding.into(),
+            Padding::Axes(padding) => padding.into(),
+            Padding::Sides(padding) => padding.into(),
+        }
+    }
+}
+
+/// The colors of the rail of a slider, written as `["#left", "#right"]`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Rail(pub Hex, pub Hex);
+
+impl Property<(Color, Color)> for Rail {
+    fn replace(&self, _current: (Color, Color)) -> (Color, Color) {
+        (self.0 .0, self.1 .0)
+    }
+}
+
+/// The shape of the handle of a slider.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+pub enum Shape {
+    /// A circular handle.
+    Circle {
+        /// The radius of the circle.
+        radius: f32,
+    },
+
+    /// A rectangular handle.
+    Rectangle {
+        /// The width of the rectangle.
+        width: u16,
+
+        /// The border radius of the corners of the rectangle.
+        border_radius: f32,
+    },
+}
+
+impl Property<slider::HandleShape> for Shape {
+    fn replace(&self, _current: slider::HandleShape) -> slider::HandleShape {
+        match *self {
+            Shape::Circle { radius } => slider::HandleShape::Circle { radius },
+            Shape::Rectangle {
+                width,
+                border_radius,
+            } => slider::HandleShape::Rectangle {
+                width,
+                border_radius,
+            },
+        }
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`button::Style`].
+    Button => button::Style {
+        shadow_offset: Offset,
+        background: Hex,
+        border_radius: f32,
+        border_width: f32,
+        border_color: Hex,
+        text_color: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`checkbox::Style`].
+    Checkbox => checkbox::Style {
+        background: Hex,
+        checkmark_color: Hex,
+        border_radius: f32,
+        border_width: f32,
+        border_color: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`container::Style`].
+    Container => container::Style {
+        text_color: Hex,
+        background: Hex,
+        border_radius: f32,
+        border_width: f32,
+        border_color: Hex,
+    }
+}
+
+/// The fill mode of a rule, like `"full"` or `{ percent = 90.0 }`.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Fill {
+    /// The rule fills all the available space.
+    Full,
+
+    /// The rule fills a percentage of the available space, centered.
+    Percent(f32),
+
+    /// The rule leaves the same padding at both of its ends.
+    Padded(u16),
+
+    /// The rule leaves a different padding at each of its ends.
+    AsymmetricPadding(u16, u16),
+}
+
+impl Property<rule::FillMode> for Fill {
+    fn replace(&self, _current: rule::FillMode) -> rule::FillMode {
+        match *self {
+            Fill::Full => rule::FillMode::Full,
+            Fill::Percent(percent) => rule::FillMode::Percent(percent),
+            Fill::Padded(padding) => rule::FillMode::Padded(padding),
+            Fill::AsymmetricPadding(first, second) => {
+                rule::FillMode::AsymmetricPadding(first, second)
+            }
+        }
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`menu::Style`].
+    Menu => menu::Style {
+        text_color: Hex,
+        background: Hex,
+        border_width: f32,
+        border_color: Hex,
+        selected_text_color: Hex,
+        selected_background: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`progress_bar::Style`].
+    ProgressBar => progress_bar::Style {
+        background: Hex,
+        bar: Hex,
+        border_radius: f32,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`radio::Style`].
+    Radio => radio::Style {
+        background: Hex,
+        dot_color: Hex,
+        border_width: f32,
+        border_color: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`rule::Style`].
+    Rule => rule::Style {
+        color: Hex,
+        width: u16,
+        radius: f32,
+        fill_mode: Fill,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`scrollable::Scroller`].
+    Scroller => scrollable::Scroller {
+        color: Hex,
+        border_radius: f32,
+        border_width: f32,
+        border_color: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`scrollable::Scrollbar`].
+    Scrollbar => scrollable::Scrollbar {
+        background: Hex,
+        border_radius: f32,
+        border_width: f32,
+        border_color: Hex,
+        scroller: Scroller,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`slider::Handle`].
+    Handle => slider::Handle {
+        shape: Shape,
+        color: Hex,
+        border_width: f32,
+        border_color: Hex,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`slider::Style`].
+    Slider => slider::Style {
+        rail_colors: Rail,
+        handle: Handle,
+    }
+}
+
+patch! {
+    /// A [`Patch`] of a [`toggler::Style`].
+    Toggler => toggler::Style {
+        background: Hex,
+        background_border: Hex,
+        foreground: Hex,
+        foreground_border: Hex,
+    }
+}
+
+/// A [`Patch`] of a [`pick_list::Style`], together with the [`Menu`] of the
+/// pick list.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PickList {
+    text_color: Option<Hex>,
+    background: Option<Hex>,
+    border_radius: Option<f32>,
+    border_width: Option<f32>,
+    border_color: Option<Hex>,
+    icon_size: Option<f32>,
+    menu: Option<Menu>,
+}
+
+impl Patch for PickList {
+    type Style = pick_list::Style;
+
+    fn apply(&self, style: pick_list::Style) -> pick_list::Style {
+        pick_list::Style {
+            text_color: self
+                .text_color
+                .map(|hex| hex.0)
+                .unwrap_or(style.text_color),
+            background: self
+                .background
+                .map(|hex| Background::Color(hex.0))
+                .unwrap_or(style.background),
+            border_radius: self.border_radius.unwrap_or(style.border_radius),
+            border_width: self.border_width.unwrap_or(style.border_width),
+            border_color: self
+                .border_color
+                .map(|hex| hex.0)
+                .unwrap_or(style.border_color),
+            icon_size: self.icon_size.unwrap_or(style.icon_size),
+        }
+    }
+}
+
+/// A [`Patch`] of the split lines of a [`pane_grid::StyleSheet`].
+///
+/// The built-in style sheet does not draw any lines, so both the `color` and
+/// the `width` are needed to draw one.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Split {
+    color: Option<Hex>,
+    width: Option<f32>,
+}
+
+impl Patch for Split {
+    type Style = Option<pane_grid::Line>;
+
+    fn apply(&self, style: Option<pane_grid::Line>) -> Option<pane_grid::Line> {
+        match (style, self.color, self.width) {
+            (Some(line), color, width) => Some(pane_grid::Line {
+                color: color.map(|hex| hex.0).unwrap_or(line.color),
+                width: width.unwrap_or(line.width),
+            }),
+            (None, Some(color), Some(width)) => Some(pane_grid::Line {
+                color: color.0,
+                width,
+            }),
+            (None, _, _) => None,
+        }
+    }
+}
+
+/// A [`Patch`] of a [`text_input::Style`], together with the colors of its
+/// text.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TextInput {
+    background: Option<Hex>,
+    border_radius: Option<f32>,
+    border_width: Option<f32>,
+    border_color: Option<Hex>,
+    placeholder_color: Option<Hex>,
+    value_color: Option<Hex>,
+    selection_color: Option<Hex>,
+}
+
+impl Patch for TextInput {
+    type Style = text_input::Style;
+
+    fn apply(&self, style: text_input::Style) -> text_input::Style {
+        text_input::Style {
+            background: self
+                .background
+                .map(|hex| Background::Color(hex.0))
+                .unwrap_or(style.background),
+            border_radius: self.border_radius.unwrap_or(style.border_radius),
+            border_width: self.border_width.unwrap_or(style.border_width),
+            border_color: self
+                .border_color
+                .map(|hex| hex.0)
+                .unwrap_or(style.border_color),
+        }
+    }
+}
+
+impl Class<TextInput> {
+    fn color(
+        &self,
+        color: impl Fn(&TextInput) -> Option<Hex>,
+    ) -> Option<Color> {
+        color(&self.active).map(|hex| hex.0)
+    }
+}
+
+impl button::StyleSheet for Class<Button> {
+    fn active(&self, theme: &Theme) -> button::Style {
+        let default = Box::<dyn button::StyleSheet>::default();
+
+        self.resolve(&Button::default(), default.active(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> button::Style {
+        let default = Box::<dyn button::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn pressed(&self, theme: &Theme) -> button::Style {
+        let default = Box::<dyn button::StyleSheet>::default();
+
+        self.resolve(&self.pressed, default.pressed(theme))
+    }
+
+    fn disabled(&self, theme: &Theme) -> button::Style {
+        let default = Box::<dyn button::StyleSheet>::default();
+
+        self.resolve(&self.disabled, default.disabled(theme))
+    }
+}
+
+impl checkbox::StyleSheet for Class<Checkbox> {
+    fn active(&self, theme: &Theme, is_checked: bool) -> checkbox::Style {
+        let default = Box::<dyn checkbox::StyleSheet>::default();
+        let style = self
+            .resolve(&Checkbox::default(), default.active(theme, is_checked));
+
+        if is_checked {
+            self.checked.apply(style)
+        } else {
+            style
+        }
+    }
+
+    fn hovered(&self, theme: &Theme, is_checked: bool) -> checkbox::Style {
+        let default = Box::<dyn checkbox::StyleSheet>::default();
+        let style = self.active.apply(default.hovered(theme, is_checked));
+
+        let style = if is_checked {
+            self.checked.apply(style)
+        } else {
+            style
+        };
+
+        self.hovered.apply(style)
+    }
+
+    fn disabled(&self, theme: &Theme, is_checked: bool) -> checkbox::Style {
+        let default = Box::<dyn checkbox::StyleSheet>::default();
+        let style = self.active.apply(default.disabled(theme, is_checked));
+
+        let style = if is_checked {
+            self.checked.apply(style)
+        } else {
+            style
+        };
+
+        self.disabled.apply(style)
+    }
+}
+
+impl container::StyleSheet for Class<Container> {
+    fn style(&self, theme: &Theme) -> container::Style {
+        let default = Box::<dyn container::StyleSheet>::default();
+
+        self.resolve(&Container::default(), default.style(theme))
+    }
+}
+
+impl menu::StyleSheet for Class<Menu> {
+    fn style(&self, theme: &Theme) -> menu::Style {
+        let default = Box::<dyn menu::StyleSheet>::default();
+
+        self.resolve(&Menu::default(), default.style(theme))
+    }
+}
+
+impl pane_grid::StyleSheet for Class<Split> {
+    fn picked_split(&self, theme: &Theme) -> Option<pane_grid::Line> {
+        let default = Box::<dyn pane_grid::StyleSheet>::default();
+
+        self.resolve(&self.dragging, default.picked_split(theme))
+    }
+
+    fn hovered_split(&self, theme: &Theme) -> Option<pane_grid::Line> {
+        let default = Box::<dyn pane_grid::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered_split(theme))
+    }
+}
+
+impl pick_list::StyleSheet for Class<PickList> {
+    fn menu(&self) -> Box<dyn menu::StyleSheet> {
+        match &self.active.menu {
+            Some(menu) => Box::new(Class {
+                active: menu.clone(),
+                ..Class::default()
+            }),
+            None => Box::default(),
+        }
+    }
+
+    fn active(&self, theme: &Theme) -> pick_list::Style {
+        let default = Box::<dyn pick_list::StyleSheet>::default();
+
+        self.resolve(&PickList::default(), default.active(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> pick_list::Style {
+        let default = Box::<dyn pick_list::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn disabled(&self, theme: &Theme) -> pick_list::Style {
+        let default = Box::<dyn pick_list::StyleSheet>::default();
+
+        self.resolve(&self.disabled, default.disabled(theme))
+    }
+}
+
+impl progress_bar::StyleSheet for Class<ProgressBar> {
+    fn style(&self, theme: &Theme) -> progress_bar::Style {
+        let default = Box::<dyn progress_bar::StyleSheet>::default();
+
+        self.resolve(&ProgressBar::default(), default.style(theme))
+    }
+}
+
+impl radio::StyleSheet for Class<Radio> {
+    fn active(&self, theme: &Theme) -> radio::Style {
+        let default = Box::<dyn radio::StyleSheet>::default();
+
+        self.resolve(&Radio::default(), default.active(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> radio::Style {
+        let default = Box::<dyn radio::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn disabled(&self, theme: &Theme) -> radio::Style {
+        let default = Box::<dyn radio::StyleSheet>::default();
+
+        self.resolve(&self.disabled, default.disabled(theme))
+    }
+}
+
+impl rule::StyleSheet for Class<Rule> {
+    fn style(&self, theme: &Theme) -> rule::Style {
+        let default = Box::<dyn rule::StyleSheet>::default();
+
+        self.resolve(&Rule::default(), default.style(theme))
+    }
+}
+
+impl scrollable::StyleSheet for Class<Scrollbar> {
+    fn active(&self, theme: &Theme) -> scrollable::Scrollbar {
+        let default = Box::<dyn scrollable::StyleSheet>::default();
+
+        self.resolve(&Scrollbar::default(), default.active(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> scrollable::Scrollbar {
+        let default = Box::<dyn scrollable::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn dragging(&self, theme: &Theme) -> scrollable::Scrollbar {
+        let default = Box::<dyn scrollable::StyleSheet>::default();
+
+        self.resolve(&self.dragging, default.dragging(theme))
+    }
+}
+
+impl slider::StyleSheet for Class<Slider> {
+    fn active(&self, theme: &Theme) -> slider::Style {
+        let default = Box::<dyn slider::StyleSheet>::default();
+
+        self.resolve(&Slider::default(), default.active(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> slider::Style {
+        let default = Box::<dyn slider::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn dragging(&self, theme: &Theme) -> slider::Style {
+        let default = Box::<dyn slider::StyleSheet>::default();
+
+        self.resolve(&self.dragging, default.dragging(theme))
+    }
+
+    fn disabled(&self, theme: &Theme) -> slider::Style {
+        let default = Box::<dyn slider::StyleSheet>::default();
+
+        self.resolve(&self.disabled, default.disabled(theme))
+    }
+}
+
+impl text_input::StyleSheet for Class<TextInput> {
+    fn active(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&TextInput::default(), default.active(theme))
+    }
+
+    fn focused(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&self.focused, default.focused(theme))
+    }
+
+    fn hovered(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&self.hovered, default.hovered(theme))
+    }
+
+    fn read_only(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&self.read_only, default.read_only(theme))
+    }
+
+    fn invalid(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&self.invalid, default.invalid(theme))
+    }
+
+    fn disabled(&self, theme: &Theme) -> text_input::Style {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.resolve(&self.disabled, default.disabled(theme))
+    }
+
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.color(|patch| patch.placeholder_color)
+            .unwrap_or_else(|| default.placeholder_color(theme))
+    }
+
+    fn value_color(&self, theme: &Theme) -> Color {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.color(|patch| patch.value_color)
+            .unwrap_or_else(|| default.value_color(theme))
+    }
+
+    fn selection_color(&self, theme: &Theme) -> Color {
+        let default = Box::<dyn text_input::StyleSheet>::default();
+
+        self.color(|patch| patch.selection_color)
+            .unwrap_or_else(|| default.selection_color(theme))
+    }
+}
+
+impl toggler::StyleSheet for Class<Toggler> {
+    fn active(&self, theme: &Theme, is_active: bool) -> toggler::Style {
+        let default = Box::<dyn toggler::StyleSheet>::default();
+        let style =
+            self.resolve(&Toggler::default(), default.active(theme, is_active));
+
+        if is_active {
+            self.checked.apply(style)
+        } else {
+            style
+        }
+    }
+
+    fn hovered(&self, theme: &Theme, is_active: bool) -> toggler::Style {
+        let default = Box::<dyn toggler::StyleSheet>::default();
+        let style = self.active.apply(default.hovered(theme, is_active));
+
+        let style = if is_active {
+            self.checked.apply(style)
+        } else {
+            style
+        };
+
+        self.hovered.apply(style)
+    }
+
+    fn disabled(&self, theme: &Theme, is_active: bool) -> toggler::Style {
+        let default = Box::<dyn toggler::StyleSheet>::default();
+        let style = self.active.apply(default.disabled(theme, is_active));
+
+        let style = if is_active {
+            self.checked.apply(style)
+        } else {
+            style
+        };
+
+        self.disabled.apply(style)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::button::StyleSheet as _;
+
+    #[test]
+    fn resolves_a_class_over_the_theme() {
+        let sheet = Sheet::from_toml(
+            r##"
+            [button.primary.active]
+            background = "#3366ff"
+            border_radius = 4.0
+
+            [button.primary.hovered]
+            background = "#4477ff80"
+            "##,
+        )
+        .expect("Parse sheet");
+
+        let theme = Theme::dark();
+        let default = Box::<dyn button::StyleSheet>::default();
+        let primary = sheet.button("primary");
+
+        let active = primary.active(&theme);
+        assert_eq!(
+            active.background,
+            Some(Background::Color(Color::from_rgb8(0x33, 0x66, 0xff)))
+        );
+        assert_eq!(active.border_radius, 4.0);
+        assert_eq!(active.text_color, default.active(&theme).text_color);
+
+        let hovered = primary.hovered(&theme);
+        assert_eq!(
+            hovered.background,
+            Some(Background::Color(Color::from_rgba8(
+                0x44,
+                0x77,
+                0xff,
+                128.0 / 255.0
+            )))
+        );
+        assert_eq!(hovered.border_radius, 4.0);
+
+        assert_eq!(sheet.button("missing"), Class::default());
+    }
+
+    #[test]
+    fn parses_ron() {
+        let sheet = Sheet::from_ron(
+            r##"(
+                slider: {
+                    "thin": (
+                        active: (
+                            rail_colors: ("#000000", "#ffffff"),
+                            handle: (
+                                shape: circle(radius: 4.0),
+                            ),
+                        ),
+                    ),
+                },
+            )"##,
+        )
+        .expect("Parse sheet");
+
+        let style = slider::StyleSheet::active(
+            &sheet.slider("thin"),
+            &Theme::default(),
+        );
+
+        assert_eq!(style.rail_colors, (Color::BLACK, Color::WHITE));
+        assert!(matches!(
+            style.handle.shape,
+            slider::HandleShape::Circle { radius } if radius == 4.0
+        ));
+    }
+
+    #[test]
+    fn styles_paddings_pick_lists_rules_and_pane_grids() {
+        let sheet = Sheet::from_toml(
+            r##"
+            [pick_list.fruits]
+            padding = [4, 8]
+
+            [pick_list.fruits.active]
+            border_radius = 4.0
+
+            [pick_list.fruits.active.menu]
+            selected_background = "#3366ff"
+
+            [rule.dotted.active]
+            width = 2
+            fill_mode = { percent = 50.0 }
+
+            [pane_grid.split.hovered]
+            color = "#ffffff"
+            width = 2.0
+            "##,
+        )
+        .expect("Parse sheet");
+
+        let theme = Theme::default();
+        let fruits = sheet.pick_list("fruits");
+
+        let padding = fruits.padding().expect("Padding of class");
+        assert_eq!((padding.top, padding.right), (4, 8));
+        assert!(sheet.pick_list("missing").padding().is_none());
+        assert_eq!(
+            pick_list::StyleSheet::active(&fruits, &theme).border_radius,
+            4.0
+        );
+        assert_eq!(
+            pick_list::StyleSheet::menu(&fruits)
+                .style(&theme)
+                .selected_background,
+            Background::Color(Color::from_rgb8(0x33, 0x66, 0xff))
+        );
+
+        let rule = rule::StyleSheet::style(&sheet.rule("dotted"), &theme);
+        assert_eq!(rule.width, 2);
+        assert_eq!(rule.fill_mode.fill(100.0), (25.0, 50.0));
+
+        let split = sheet.pane_grid("split");
+        assert_eq!(
+            pane_grid::StyleSheet::hovered_split(&split, &theme),
+            Some(pane_grid::Line {
+                color: Color::WHITE,
+                width: 2.0,
+            })
+        );
+        assert_eq!(pane_grid::StyleSheet::picked_split(&split, &theme), None);
+    }
+
+    #[test]
+    fn rejects_invalid_sheets() {
+        assert!(Sheet::from_toml(
+            "[button.primary.active]\nbackground = \"#12345\""
+        )
+        .is_err());
+
+        assert!(Sheet::from_toml(
+            "[button.primary.active]\nbackground = \"#gg0000\""
+        )
+        .is_err());
+
+        assert!(Sheet::from_toml(
+            "[button.primary.active]\ncolour = \"#ffffff\""
+        )
+        .is_err());
+    }
+}
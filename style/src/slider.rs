@@ -1,4 +1,5 @@
 //! Display an interactive selector of a single value from a range of values.
+use crate::Theme;
 use iced_core::Color;
 
 /// The appearance of a slider.
@@ -27,51 +28,89 @@ pub enum HandleShape {
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of an hovered slider.
-    fn hovered(&self) -> Style;
+    fn hovered(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a slider that is being dragged.
-    fn dragging(&self) -> Style;
+    fn dragging(&self, theme: &Theme) -> Style;
+
+    /// Produces the style of a disabled slider.
+    fn disabled(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
+        let (left, right) = active.rail_colors;
+
+        Style {
+            rail_colors: (
+                Color {
+                    a: left.a * 0.5,
+                    ..left
+                },
+                Color {
+                    a: right.a * 0.5,
+                    ..right
+                },
+            ),
+            handle: Handle {
+                color: Color {
+                    a: active.handle.color.a * 0.5,
+                    ..active.handle.color
+                },
+                border_color: Color {
+                    a: active.handle.border_color.a * 0.5,
+                    ..active.handle.border_color
+                },
+                ..active.handle
+            },
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
         Style {
-            rail_colors: ([0.6, 0.6, 0.6, 0.5].into(), Color::WHITE),
+            rail_colors: (
+                Color {
+                    a: 0.5,
+                    ..background.strong.color
+                },
+                background.base.color,
+            ),
             handle: Handle {
                 shape: HandleShape::Rectangle {
                     width: 8,
                     border_radius: 4.0,
                 },
-                color: Color::from_rgb(0.95, 0.95, 0.95),
-                border_color: Color::from_rgb(0.6, 0.6, 0.6),
+                color: background.base.color,
+                border_color: background.strong.color,
                 border_width: 1.0,
             },
         }
     }
 
-    fn hovered(&self) -> Style {
-        let active = self.active();
+    fn hovered(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.90, 0.90, 0.90),
+                color: theme.extended_palette().background.weak.color,
                 ..active.handle
             },
             ..active
         }
     }
 
-    fn dragging(&self) -> Style {
-        let active = self.active();
+    fn dragging(&self, theme: &Theme) -> Style {
+        let active = self.active(theme);
 
         Style {
             handle: Handle {
-                color: Color::from_rgb(0.85, 0.85, 0.85),
+                color: theme.extended_palette().background.strong.color,
                 ..active.handle
             },
             ..active
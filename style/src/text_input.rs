@@ -1,4 +1,5 @@
 //! Display fields that can be filled with text.
+use crate::Theme;
 use iced_core::{Background, Color};
 
 /// The appearance of a text input.
@@ -24,52 +25,87 @@ impl std::default::Default for Style {
 /// A set of rules that dictate the style of a text input.
 pub trait StyleSheet {
     /// Produces the style of an active text input.
-    fn active(&self) -> Style;
+    fn active(&self, theme: &Theme) -> Style;
 
     /// Produces the style of a focused text input.
-    fn focused(&self) -> Style;
+    fn focused(&self, theme: &Theme) -> Style;
 
-    fn placeholder_color(&self) -> Color;
+    fn placeholder_color(&self, theme: &Theme) -> Color;
 
-    fn value_color(&self) -> Color;
+    fn value_color(&self, theme: &Theme) -> Color;
 
-    fn selection_color(&self) -> Color;
+    fn selection_color(&self, theme: &Theme) -> Color;
 
     /// Produces the style of an hovered text input.
-    fn hovered(&self) -> Style {
-        self.focused()
+    fn hovered(&self, theme: &Theme) -> Style {
+        self.focused(theme)
+    }
+
+    /// Produces the style of a read-only text input.
+    fn read_only(&self, theme: &Theme) -> Style {
+        Style {
+            background: Background::Color(
+                theme.extended_palette().background.weak.color,
+            ),
+            ..self.active(theme)
+        }
+    }
+
+    /// Produces the style of a text input with an invalid value.
+    fn invalid(&self, theme: &Theme) -> Style {
+        Style {
+            border_color: theme.extended_palette().danger.base.color,
+            ..self.active(theme)
+        }
+    }
+
+    /// Produces the style of a disabled text input.
+    fn disabled(&self, theme: &Theme) -> Style {
+        Style {
+            background: Background::Color(
+                theme.extended_palette().background.weak.color,
+            ),
+            ..self.active(theme)
+        }
+    }
+
+    /// Produces the color of the value of a disabled text input.
+    fn disabled_color(&self, theme: &Theme) -> Color {
+        self.placeholder_color(theme)
     }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self) -> Style {
+    fn active(&self, theme: &Theme) -> Style {
+        let background = theme.extended_palette().background;
+
         Style {
-            background: Background::Color(Color::WHITE),
+            background: Background::Color(background.base.color),
             border_radius: 5.0,
             border_width: 1.0,
-            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            border_color: background.strong.color,
         }
     }
 
-    fn focused(&self) -> Style {
+    fn focused(&self, theme: &Theme) -> Style {
         Style {
-            border_color: Color::from_rgb(0.5, 0.5, 0.5),
-            ..self.active()
+            border_color: theme.extended_palette().primary.base.color,
+            ..self.active(theme)
         }
     }
 
-    fn placeholder_color(&self) -> Color {
-        Color::from_rgb(0.7, 0.7, 0.7)
+    fn placeholder_color(&self, theme: &Theme) -> Color {
+        theme.extended_palette().background.strong.color
     }
 
-    fn value_color(&self) -> Color {
-        Color::from_rgb(0.3, 0.3, 0.3)
+    fn value_color(&self, theme: &Theme) -> Color {
+        theme.palette().text
     }
 
-    fn selection_color(&self) -> Color {
-        Color::from_rgb(0.8, 0.8, 1.0)
+    fn selection_color(&self, theme: &Theme) -> Color {
+        theme.extended_palette().primary.weak.color
     }
 }
 
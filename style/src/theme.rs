@@ -0,0 +1,74 @@
+//! Use the built-in themes or create your own.
+pub mod palette;
+
+pub use palette::Palette;
+
+/// A set of colors shared by all the built-in widgets.
+///
+/// A [`Theme`] is made of a [`Palette`] and an [`Extended`] palette with
+/// shades derived from it. The default style sheets of the built-in widgets
+/// are generated from the [`Theme`] of the application, and custom style
+/// sheets receive it as input.
+///
+/// [`Extended`]: palette::Extended
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Theme {
+    palette: Palette,
+    extended: palette::Extended,
+}
+
+impl Theme {
+    /// Creates the built-in light [`Theme`].
+    pub fn light() -> Self {
+        Self::custom(Palette::LIGHT)
+    }
+
+    /// Creates the built-in dark [`Theme`].
+    pub fn dark() -> Self {
+        Self::custom(Palette::DARK)
+    }
+
+    /// Creates the built-in high-contrast [`Theme`].
+    ///
+    /// Its borders use the text color, so every widget is clearly outlined.
+    pub fn high_contrast() -> Self {
+        let mut theme = Self::custom(Palette::HIGH_CONTRAST);
+        theme.extended.background.strong = palette::Pair::new(
+            theme.palette.text,
+            palette::readable(theme.palette.text),
+        );
+
+        theme
+    }
+
+    /// Creates a custom [`Theme`] from the given [`Palette`].
+    pub fn custom(palette: Palette) -> Self {
+        Self {
+            palette,
+            extended: palette::Extended::generate(palette),
+        }
+    }
+
+    /// Returns the [`Palette`] of the [`Theme`].
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    /// Returns the [`Extended`] palette of the [`Theme`].
+    ///
+    /// [`Extended`]: palette::Extended
+    pub fn extended_palette(&self) -> &palette::Extended {
+        &self.extended
+    }
+
+    /// Returns whether the [`Theme`] has a dark background.
+    pub fn is_dark(&self) -> bool {
+        palette::is_dark(self.palette.background)
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::light()
+    }
+}
@@ -0,0 +1,193 @@
+//! Define the colors of a [`Theme`].
+//!
+//! [`Theme`]: crate::Theme
+use iced_core::Color;
+
+/// The main colors of a [`Theme`].
+///
+/// [`Theme`]: crate::Theme
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Palette {
+    /// The background [`Color`] of the application.
+    pub background: Color,
+
+    /// The [`Color`] of text.
+    pub text: Color,
+
+    /// The [`Color`] of primary elements, like selections and focus.
+    pub primary: Color,
+
+    /// The [`Color`] of elements signaling success, like progress.
+    pub success: Color,
+
+    /// The [`Color`] of elements signaling danger, like errors.
+    pub danger: Color,
+}
+
+impl Palette {
+    /// The light [`Palette`].
+    pub const LIGHT: Palette = Palette {
+        background: Color::WHITE,
+        text: Color::BLACK,
+        primary: Color::from_rgb(0.37, 0.49, 0.89),
+        success: Color::from_rgb(0.07, 0.60, 0.35),
+        danger: Color::from_rgb(0.77, 0.26, 0.25),
+    };
+
+    /// The dark [`Palette`].
+    pub const DARK: Palette = Palette {
+        background: Color::from_rgb(0.13, 0.13, 0.15),
+        text: Color::from_rgb(0.90, 0.90, 0.90),
+        primary: Color::from_rgb(0.37, 0.49, 0.89),
+        success: Color::from_rgb(0.07, 0.60, 0.35),
+        danger: Color::from_rgb(0.77, 0.26, 0.25),
+    };
+
+    /// The high-contrast [`Palette`].
+    pub const HIGH_CONTRAST: Palette = Palette {
+        background: Color::BLACK,
+        text: Color::WHITE,
+        primary: Color::from_rgb(1.0, 0.85, 0.0),
+        success: Color::from_rgb(0.3, 1.0, 0.3),
+        danger: Color::from_rgb(1.0, 0.35, 0.35),
+    };
+}
+
+/// A set of shades derived from a [`Palette`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Extended {
+    /// The shades of the background [`Color`].
+    pub background: Shades,
+
+    /// The shades of the primary [`Color`].
+    pub primary: Shades,
+
+    /// The shades of the success [`Color`].
+    pub success: Shades,
+
+    /// The shades of the danger [`Color`].
+    pub danger: Shades,
+}
+
+impl Extended {
+    /// Generates the [`Extended`] shades of the given [`Palette`].
+    pub fn generate(palette: Palette) -> Self {
+        Self {
+            background: Shades {
+                base: Pair::new(palette.background, palette.text),
+                weak: Pair::new(
+                    mix(palette.background, palette.text, 0.13),
+                    palette.text,
+                ),
+                strong: Pair::new(
+                    mix(palette.background, palette.text, 0.3),
+                    palette.text,
+                ),
+            },
+            primary: Shades::generate(palette.primary, palette.background),
+            success: Shades::generate(palette.success, palette.background),
+            danger: Shades::generate(palette.danger, palette.background),
+        }
+    }
+}
+
+/// The shades of a [`Color`] of a [`Palette`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Shades {
+    /// The original [`Color`].
+    pub base: Pair,
+
+    /// A shade closer to the background.
+    pub weak: Pair,
+
+    /// A shade further from the background.
+    pub strong: Pair,
+}
+
+impl Shades {
+    /// Generates the [`Shades`] of an accent [`Color`] drawn on the given
+    /// background.
+    pub fn generate(color: Color, background: Color) -> Self {
+        let strong = if is_dark(background) {
+            mix(color, Color::WHITE, 0.1)
+        } else {
+            mix(color, Color::BLACK, 0.1)
+        };
+
+        Self {
+            base: Pair::new(color, readable(color)),
+            weak: Pair::new(
+                mix(color, background, 0.6),
+                readable(mix(color, background, 0.6)),
+            ),
+            strong: Pair::new(strong, readable(strong)),
+        }
+    }
+}
+
+/// A background [`Color`] with a readable text [`Color`] on top of it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Pair {
+    /// The background [`Color`].
+    pub color: Color,
+
+    /// The text [`Color`].
+    pub text: Color,
+}
+
+impl Pair {
+    /// Creates a new [`Pair`] from a background and a text [`Color`].
+    pub fn new(color: Color, text: Color) -> Self {
+        Self { color, text }
+    }
+}
+
+/// Mixes two colors, moving `a` towards `b` by the given factor.
+pub fn mix(a: Color, b: Color, factor: f32) -> Color {
+    let mix = |a: f32, b: f32| a + (b - a) * factor;
+
+    Color {
+        r: mix(a.r, b.r),
+        g: mix(a.g, b.g),
+        b: mix(a.b, b.b),
+        a: mix(a.a, b.a),
+    }
+}
+
+/// Returns whether the given [`Color`] is perceived as dark.
+pub fn is_dark(color: Color) -> bool {
+    0.2126 * color.r + 0.7152 * color.g + 0.0722 * color.b < 0.5
+}
+
+/// Returns a text [`Color`] that is readable on top of the given one.
+pub fn readable(background: Color) -> Color {
+    if is_dark(background) {
+        Color::WHITE
+    } else {
+        Color::BLACK
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Theme;
+
+    #[test]
+    fn generates_readable_shades() {
+        for theme in &[Theme::light(), Theme::dark(), Theme::high_contrast()] {
+            let extended = theme.extended_palette();
+
+            for shades in &[
+                extended.background,
+                extended.primary,
+                extended.success,
+                extended.danger,
+            ] {
+                for pair in &[shades.base, shades.weak, shades.strong] {
+                    assert_ne!(is_dark(pair.color), is_dark(pair.text));
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,5 @@
 //! Show toggle controls using togglers.
+use crate::Theme;
 use iced_core::Color;
 
 /// The appearance of a toggler.
@@ -12,31 +13,49 @@ pub struct Style {
 
 /// A set of rules that dictate the style of a toggler.
 pub trait StyleSheet {
-    fn active(&self, is_active: bool) -> Style;
+    fn active(&self, theme: &Theme, is_active: bool) -> Style;
 
-    fn hovered(&self, is_active: bool) -> Style;
+    fn hovered(&self, theme: &Theme, is_active: bool) -> Style;
+
+    fn disabled(&self, theme: &Theme, is_active: bool) -> Style {
+        let active = self.active(theme, is_active);
+
+        Style {
+            background: Color {
+                a: active.background.a * 0.5,
+                ..active.background
+            },
+            foreground: Color {
+                a: active.foreground.a * 0.5,
+                ..active.foreground
+            },
+            ..active
+        }
+    }
 }
 
 struct Default;
 
 impl StyleSheet for Default {
-    fn active(&self, is_active: bool) -> Style {
+    fn active(&self, theme: &Theme, is_active: bool) -> Style {
+        let palette = theme.extended_palette();
+
         Style {
             background: if is_active {
-                Color::from_rgb(0.0, 1.0, 0.0)
+                palette.success.base.color
             } else {
-                Color::from_rgb(0.7, 0.7, 0.7)
+                palette.background.strong.color
             },
             background_border: None,
-            foreground: Color::WHITE,
+            foreground: palette.background.base.color,
             foreground_border: None,
         }
     }
 
-    fn hovered(&self, is_active: bool) -> Style {
+    fn hovered(&self, theme: &Theme, is_active: bool) -> Style {
         Style {
-            foreground: Color::from_rgb(0.95, 0.95, 0.95),
-            ..self.active(is_active)
+            foreground: theme.extended_palette().background.weak.color,
+            ..self.active(theme, is_active)
         }
     }
 }
@@ -1,5 +1,5 @@
 //! Style your widgets.
-use crate::{bumpalo, Align, Background, Color, Length, Padding};
+use crate::{bumpalo, Align, Background, Color, Length, Padding, Theme};
 
 use std::collections::BTreeMap;
 
@@ -107,16 +107,23 @@ impl Rule {
 #[derive(Debug)]
 pub struct Css<'a> {
     rules: BTreeMap<String, &'a str>,
+    theme: Theme,
 }
 
 impl<'a> Css<'a> {
-    /// Creates an empty [`Css`].
-    pub fn new() -> Self {
+    /// Creates an empty [`Css`] with the given [`Theme`].
+    pub fn new(theme: Theme) -> Self {
         Css {
             rules: BTreeMap::new(),
+            theme,
         }
     }
 
+    /// Returns the [`Theme`] of the [`Css`].
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
     /// Inserts the [`Rule`] in the [`Css`], if it was not previously
     /// inserted.
     ///
@@ -137,9 +144,18 @@ impl<'a> Css<'a> {
 
         let mut declarations = bumpalo::collections::Vec::new_in(bump);
 
+        let palette = self.theme.palette();
+
         declarations.push(text("html { height: 100% }"));
         declarations.push(text(
-            "body { height: 100%; margin: 0; padding: 0; font-family: sans-serif }",
+            bumpalo::format!(
+                in bump,
+                "body {{ height: 100%; margin: 0; padding: 0; \
+                 font-family: sans-serif; background: {}; color: {} }}",
+                color(palette.background),
+                color(palette.text)
+            )
+            .into_bump_str(),
         ));
         declarations.push(text("* { margin: 0; padding: 0 }"));
         declarations.push(text(
@@ -50,6 +50,11 @@
 //! [`wasm-pack`]: https://github.com/rustwasm/wasm-pack
 //! [`wasm-bindgen`]: https://github.com/rustwasm/wasm-bindgen
 //! [`tour` example]: https://github.com/hecrj/iced/tree/0.3/examples/tour
+//!
+//! # Commands
+//! The [`Command`] of `iced_web` can only run futures. Abortable commands,
+//! streams, widget operations and window actions are only supported by the
+//! native runtimes, like `iced_winit` and `iced_glutin`.
 #![deny(missing_docs)]
 #![deny(missing_debug_implementations)]
 #![deny(unused_results)]
@@ -78,6 +83,7 @@ pub use iced_core::{
     Length, Padding, Point, Rectangle, Size, Vector, VerticalAlignment,
 };
 pub use iced_futures::{executor, futures, Command};
+pub use iced_style::Theme;
 pub use subscription::Subscription;
 
 #[doc(no_inline)]
@@ -151,6 +157,16 @@ pub trait Application {
         Subscription::none()
     }
 
+    /// Returns the [`Theme`] of the [`Application`].
+    ///
+    /// The [`Theme`] is used by all the built-in widgets and passed to their
+    /// style sheets.
+    ///
+    /// By default, it returns the light [`Theme`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Runs the [`Application`].
     fn run(flags: Self::Flags)
     where
@@ -177,7 +193,7 @@ pub trait Application {
         let mut title = app.title();
         document.set_title(&title);
 
-        runtime.spawn(command);
+        runtime.spawn_command(command);
 
         let application = Rc::new(RefCell::new(app));
 
@@ -199,7 +215,8 @@ pub trait Application {
 
             let new_title = application.borrow().title();
 
-            runtime.spawn(command);
+            runtime.spawn_command(command);
+
             runtime.track(subscription);
 
             if title != new_title {
@@ -233,8 +250,9 @@ where
         use dodrio::builder::*;
 
         let mut ui = self.application.borrow_mut();
+        let theme = ui.theme();
         let element = ui.view();
-        let mut css = Css::new();
+        let mut css = Css::new(theme);
 
         let node = element.widget.node(context.bump, &self.bus, &mut css);
 
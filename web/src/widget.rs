@@ -19,6 +19,7 @@ pub mod button;
 pub mod checkbox;
 pub mod container;
 pub mod image;
+pub mod number_input;
 pub mod progress_bar;
 pub mod radio;
 pub mod scrollable;
@@ -34,6 +35,8 @@ mod text;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use scrollable::Scrollable;
 #[doc(no_inline)]
 pub use slider::Slider;
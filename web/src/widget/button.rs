@@ -141,7 +141,7 @@ where
         use dodrio::builder::*;
 
         // TODO: State-based styling
-        let style = self.style.active();
+        let style = self.style.active(style_sheet.theme());
 
         let background = match style.background {
             None => String::from("none"),
@@ -32,6 +32,7 @@ pub struct Checkbox<Message> {
     width: Length,
     #[allow(dead_code)]
     style: Box<dyn StyleSheet>,
+    is_disabled: bool,
 }
 
 impl<Message> Checkbox<Message> {
@@ -54,6 +55,7 @@ impl<Message> Checkbox<Message> {
             id: None,
             width: Length::Shrink,
             style: Default::default(),
+            is_disabled: false,
         }
     }
 
@@ -74,6 +76,14 @@ impl<Message> Checkbox<Message> {
         self.id = Some(id.into());
         self
     }
+
+    /// Sets whether the [`Checkbox`] is disabled.
+    ///
+    /// A disabled [`Checkbox`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Checkbox<Message>
@@ -124,6 +134,7 @@ where
                  input
                     .attr("type", "checkbox")
                     .bool_attr("checked", self.is_checked)
+                    .bool_attr("disabled", self.is_disabled)
                     .on("click", move |_root, vdom, _event| {
                         let msg = on_toggle(!is_checked);
                         event_bus.publish(msg);
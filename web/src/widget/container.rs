@@ -106,7 +106,7 @@ where
 
         let column_class = style_sheet.insert(bump, css::Rule::Column);
 
-        let style = self.style_sheet.style();
+        let style = self.style_sheet.style(style_sheet.theme());
 
         let node = div(bump)
             .attr(
@@ -0,0 +1,255 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::{css, Bus, Css, Element, Length, Padding, Widget};
+
+pub use iced_style::number_input::{Style, StyleSheet};
+
+use dodrio::bumpalo;
+use std::fmt::Display;
+use std::rc::Rc;
+use std::str::FromStr;
+
+/// A field that can be filled with numbers.
+///
+/// The [`NumberInput`] is generic over numeric values and its step size
+/// defaults to 1 unit.
+///
+/// # Example
+/// ```
+/// # use iced_web::{number_input, NumberInput};
+/// #
+/// pub enum Message {
+///     QuantityChanged(f32),
+/// }
+///
+/// let state = &mut number_input::State::new();
+/// let value = 2.5;
+///
+/// NumberInput::new(state, value, Message::QuantityChanged)
+///     .min(0.0)
+///     .max(10.0)
+///     .step(0.5)
+///     .precision(1);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message> {
+    _state: &'a mut State,
+    value: T,
+    min: Option<T>,
+    max: Option<T>,
+    step: T,
+    precision: Option<usize>,
+    on_change: Rc<Box<dyn Fn(T) -> Message>>,
+    width: Length,
+    padding: Padding,
+    size: Option<u16>,
+    style_sheet: Box<dyn StyleSheet>,
+    is_disabled: bool,
+}
+
+impl<'a, T, Message> NumberInput<'a, T, Message>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+{
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`NumberInput`]
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(state: &'a mut State, value: T, on_change: F) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        NumberInput {
+            _state: state,
+            value,
+            min: None,
+            max: None,
+            step: T::from(1),
+            precision: None,
+            on_change: Rc::new(Box::new(on_change)),
+            width: Length::Fill,
+            padding: Padding::ZERO,
+            size: None,
+            style_sheet: Default::default(),
+            is_disabled: false,
+        }
+    }
+
+    /// Sets the minimum value of the [`NumberInput`].
+    pub fn min(mut self, min: T) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the maximum value of the [`NumberInput`].
+    pub fn max(mut self, max: T) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the number of decimal places shown by the [`NumberInput`].
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the style of the [`NumberInput`].
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet>>) -> Self {
+        self.style_sheet = style.into();
+        self
+    }
+
+    /// Sets whether the [`NumberInput`] is disabled.
+    ///
+    /// A disabled [`NumberInput`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+}
+
+impl<'a, T, Message> Widget<Message> for NumberInput<'a, T, Message>
+where
+    T: 'static
+        + Copy
+        + Display
+        + FromStr
+        + Into<f64>
+        + num_traits::FromPrimitive,
+    Message: 'static,
+{
+    fn node<'b>(
+        &self,
+        bump: &'b bumpalo::Bump,
+        bus: &Bus<Message>,
+        style_sheet: &mut Css<'b>,
+    ) -> dodrio::Node<'b> {
+        use dodrio::builder::*;
+        use wasm_bindgen::JsCast;
+
+        let value = match self.precision {
+            Some(precision) => {
+                bumpalo::format!(in bump, "{:.*}", precision, self.value)
+            }
+            None => bumpalo::format!(in bump, "{}", self.value),
+        };
+        let step = bumpalo::format!(in bump, "{}", self.step);
+
+        let on_change = self.on_change.clone();
+        let event_bus = bus.clone();
+        let theme = style_sheet.theme();
+
+        let style = if self.is_disabled {
+            self.style_sheet.text_input().disabled(theme)
+        } else {
+            self.style_sheet.text_input().active(theme)
+        };
+
+        let mut node = input(bump)
+            .attr(
+                "style",
+                bumpalo::format!(
+                    in bump,
+                    "width: {}; padding: {}; font-size: {}px; \
+                    background: {}; border-width: {}px; border-color: {}; \
+                    border-radius: {}px",
+                    css::length(self.width),
+                    css::padding(self.padding),
+                    self.size.unwrap_or(20),
+                    css::background(style.background),
+                    style.border_width,
+                    css::color(style.border_color),
+                    style.border_radius,
+                )
+                .into_bump_str(),
+            )
+            .attr("type", "number")
+            .attr("step", step.into_bump_str())
+            .attr("value", value.into_bump_str())
+            .bool_attr("disabled", self.is_disabled);
+
+        if let Some(min) = self.min {
+            node = node.attr(
+                "min",
+                bumpalo::format!(in bump, "{}", min).into_bump_str(),
+            );
+        }
+
+        if let Some(max) = self.max {
+            node = node.attr(
+                "max",
+                bumpalo::format!(in bump, "{}", max).into_bump_str(),
+            );
+        }
+
+        node.on("input", move |_root, _vdom, event| {
+            let number_input = match event
+                .target()
+                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+            {
+                None => return,
+                Some(number_input) => number_input,
+            };
+
+            if let Ok(value) = number_input.value().parse::<T>() {
+                event_bus.publish(on_change(value));
+            }
+        })
+        .finish()
+    }
+}
+
+impl<'a, T, Message> From<NumberInput<'a, T, Message>> for Element<'a, Message>
+where
+    T: 'static
+        + Copy
+        + Display
+        + FromStr
+        + Into<f64>
+        + num_traits::FromPrimitive,
+    Message: 'static,
+{
+    fn from(number_input: NumberInput<'a, T, Message>) -> Element<'a, Message> {
+        Element::new(number_input)
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State;
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self
+    }
+}
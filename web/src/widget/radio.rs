@@ -39,6 +39,7 @@ pub struct Radio<Message> {
     name: Option<String>,
     #[allow(dead_code)]
     style: Box<dyn StyleSheet>,
+    is_disabled: bool,
 }
 
 impl<Message> Radio<Message> {
@@ -67,6 +68,7 @@ impl<Message> Radio<Message> {
             id: None,
             name: None,
             style: Default::default(),
+            is_disabled: false,
         }
     }
 
@@ -87,6 +89,14 @@ impl<Message> Radio<Message> {
         self.id = Some(id.into());
         self
     }
+
+    /// Sets whether the [`Radio`] is disabled.
+    ///
+    /// A disabled [`Radio`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Radio<Message>
@@ -132,6 +142,7 @@ where
                     .attr("type", "radio")
                     .attr("style", "margin-right: 10px")
                     .bool_attr("checked", self.is_selected)
+                    .bool_attr("disabled", self.is_disabled)
                     .on("click", move |_root, _vdom, _event| {
                         event_bus.publish(on_click.clone());
                     })
@@ -42,6 +42,7 @@ pub struct Slider<'a, T, Message> {
     width: Length,
     #[allow(dead_code)]
     style: Box<dyn StyleSheet>,
+    is_disabled: bool,
 }
 
 impl<'a, T, Message> Slider<'a, T, Message>
@@ -86,6 +87,7 @@ where
             on_change: Rc::new(Box::new(on_change)),
             width: Length::Fill,
             style: Default::default(),
+            is_disabled: false,
         }
     }
 
@@ -106,6 +108,14 @@ where
         self.step = step;
         self
     }
+
+    /// Sets whether the [`Slider`] is disabled.
+    ///
+    /// A disabled [`Slider`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<'a, T, Message> Widget<Message> for Slider<'a, T, Message>
@@ -140,6 +150,7 @@ where
             .attr("max", max.into_bump_str())
             .attr("value", value.into_bump_str())
             .attr("style", "width: 100%")
+            .bool_attr("disabled", self.is_disabled)
             .on("input", move |_root, _vdom, event| {
                 let slider = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
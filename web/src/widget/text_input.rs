@@ -33,6 +33,7 @@ pub struct TextInput<'a, Message> {
     placeholder: String,
     value: String,
     is_secure: bool,
+    is_read_only: bool,
     width: Length,
     max_width: u32,
     padding: Padding,
@@ -40,6 +41,8 @@ pub struct TextInput<'a, Message> {
     on_change: Rc<Box<dyn Fn(String) -> Message>>,
     on_submit: Option<Message>,
     style_sheet: Box<dyn StyleSheet>,
+    is_disabled: bool,
+    is_invalid: bool,
 }
 
 impl<'a, Message> TextInput<'a, Message> {
@@ -64,6 +67,7 @@ impl<'a, Message> TextInput<'a, Message> {
             placeholder: String::from(placeholder),
             value: String::from(value),
             is_secure: false,
+            is_read_only: false,
             width: Length::Fill,
             max_width: u32::MAX,
             padding: Padding::ZERO,
@@ -71,6 +75,8 @@ impl<'a, Message> TextInput<'a, Message> {
             on_change: Rc::new(Box::new(on_change)),
             on_submit: None,
             style_sheet: Default::default(),
+            is_disabled: false,
+            is_invalid: false,
         }
     }
 
@@ -80,6 +86,15 @@ impl<'a, Message> TextInput<'a, Message> {
         self
     }
 
+    /// Converts the [`TextInput`] into a read-only input.
+    ///
+    /// The value of a read-only [`TextInput`] can be selected and copied, but
+    /// not edited.
+    pub fn read_only(mut self) -> Self {
+        self.is_read_only = true;
+        self
+    }
+
     /// Sets the width of the [`TextInput`].
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
@@ -116,6 +131,22 @@ impl<'a, Message> TextInput<'a, Message> {
         self.style_sheet = style.into();
         self
     }
+
+    /// Sets whether the [`TextInput`] is disabled.
+    ///
+    /// A disabled [`TextInput`] ignores any interaction.
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
+
+    /// Sets whether the value of the [`TextInput`] is invalid.
+    ///
+    /// An invalid [`TextInput`] is drawn with the `invalid` style.
+    pub fn invalid(mut self, is_invalid: bool) -> Self {
+        self.is_invalid = is_invalid;
+        self
+    }
 }
 
 impl<'a, Message> Widget<Message> for TextInput<'a, Message>
@@ -126,7 +157,7 @@ where
         &self,
         bump: &'b bumpalo::Bump,
         bus: &Bus<Message>,
-        _style_sheet: &mut Css<'b>,
+        style_sheet: &mut Css<'b>,
     ) -> dodrio::Node<'b> {
         use dodrio::builder::*;
         use wasm_bindgen::JsCast;
@@ -147,7 +178,29 @@ where
         let on_submit = self.on_submit.clone();
         let input_event_bus = bus.clone();
         let submit_event_bus = bus.clone();
-        let style = self.style_sheet.active();
+        let theme = style_sheet.theme();
+
+        let (style, value_color) = if self.is_disabled {
+            (
+                self.style_sheet.disabled(theme),
+                self.style_sheet.disabled_color(theme),
+            )
+        } else if self.is_read_only {
+            (
+                self.style_sheet.read_only(theme),
+                self.style_sheet.value_color(theme),
+            )
+        } else if self.is_invalid {
+            (
+                self.style_sheet.invalid(theme),
+                self.style_sheet.value_color(theme),
+            )
+        } else {
+            (
+                self.style_sheet.active(theme),
+                self.style_sheet.value_color(theme),
+            )
+        };
 
         input(bump)
             .attr(
@@ -165,13 +218,19 @@ where
                     style.border_width,
                     css::color(style.border_color),
                     style.border_radius,
-                    css::color(self.style_sheet.value_color())
+                    css::color(value_color)
                 )
                 .into_bump_str(),
             )
             .attr("placeholder", placeholder)
             .attr("value", value)
             .attr("type", if self.is_secure { "password" } else { "text" })
+            .bool_attr("disabled", self.is_disabled)
+            .bool_attr("readonly", self.is_read_only)
+            .attr(
+                "aria-invalid",
+                if self.is_invalid { "true" } else { "false" },
+            )
             .on("input", move |_root, _vdom, event| {
                 let text_input = match event.target().and_then(|t| {
                     t.dyn_into::<web_sys::HtmlInputElement>().ok()
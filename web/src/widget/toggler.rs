@@ -30,6 +30,7 @@ pub struct Toggler<Message> {
     id: Option<String>,
     width: Length,
     style: Box<dyn StyleSheet>,
+    is_disabled: bool,
 }
 
 impl<Message> Toggler<Message> {
@@ -58,6 +59,7 @@ impl<Message> Toggler<Message> {
             id: None,
             width: Length::Shrink,
             style: Default::default(),
+            is_disabled: false,
         }
     }
 
@@ -84,6 +86,16 @@ impl<Message> Toggler<Message> {
         self.id = Some(id.into());
         self
     }
+
+    /// Sets whether the [`Toggler`] is disabled.
+    ///
+    /// A disabled [`Toggler`] ignores any interaction.
+    ///
+    /// [`Toggler`]: struct.Toggler.html
+    pub fn disabled(mut self, is_disabled: bool) -> Self {
+        self.is_disabled = is_disabled;
+        self
+    }
 }
 
 impl<Message> Widget<Message> for Toggler<Message>
@@ -122,6 +134,7 @@ where
         let checkbox = input
             .attr("type", "checkbox")
             .bool_attr("checked", self.is_active)
+            .bool_attr("disabled", self.is_disabled)
             .on("click", move |_root, vdom, _event| {
                 let msg = on_toggle(!is_active);
                 event_bus.publish(msg);
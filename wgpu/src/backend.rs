@@ -1,3 +1,4 @@
+use crate::offscreen;
 use crate::quad;
 use crate::text;
 use crate::triangle;
@@ -21,6 +22,7 @@ pub struct Backend {
     quad_pipeline: quad::Pipeline,
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
+    offscreen_pipeline: offscreen::Pipeline,
 
     #[cfg(any(feature = "image_rs", feature = "svg"))]
     image_pipeline: image::Pipeline,
@@ -39,6 +41,8 @@ impl Backend {
             settings.format,
             settings.antialiasing,
         );
+        let offscreen_pipeline =
+            offscreen::Pipeline::new(device, settings.format);
 
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         let image_pipeline = image::Pipeline::new(device, settings.format);
@@ -47,6 +51,7 @@ impl Backend {
             quad_pipeline,
             text_pipeline,
             triangle_pipeline,
+            offscreen_pipeline,
 
             #[cfg(any(feature = "image_rs", feature = "svg"))]
             image_pipeline,
@@ -59,6 +64,11 @@ impl Backend {
     ///
     /// The text provided as overlay will be rendered on top of the primitives.
     /// This is useful for rendering debug information.
+    ///
+    /// Shader widgets need a `Queue` to render their content, so they are
+    /// skipped. Use [`draw_with_queue`] to draw them as well.
+    ///
+    /// [`draw_with_queue`]: Self::draw_with_queue
     pub fn draw<T: AsRef<str>>(
         &mut self,
         device: &wgpu::Device,
@@ -66,6 +76,58 @@ impl Backend {
         encoder: &mut wgpu::CommandEncoder,
         frame: &wgpu::TextureView,
         viewport: &Viewport,
+        output: &(Primitive, mouse::Interaction),
+        overlay_text: &[T],
+    ) -> mouse::Interaction {
+        self.render(
+            device,
+            None,
+            staging_belt,
+            encoder,
+            frame,
+            viewport,
+            output,
+            overlay_text,
+        )
+    }
+
+    /// Draws the provided primitives in the given `TextureView`, including
+    /// the content of any shader widgets.
+    ///
+    /// See [`draw`] to learn more.
+    ///
+    /// [`draw`]: Self::draw
+    pub fn draw_with_queue<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
+        output: &(Primitive, mouse::Interaction),
+        overlay_text: &[T],
+    ) -> mouse::Interaction {
+        self.render(
+            device,
+            Some(queue),
+            staging_belt,
+            encoder,
+            frame,
+            viewport,
+            output,
+            overlay_text,
+        )
+    }
+
+    fn render<T: AsRef<str>>(
+        &mut self,
+        device: &wgpu::Device,
+        queue: Option<&wgpu::Queue>,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        frame: &wgpu::TextureView,
+        viewport: &Viewport,
         (primitive, mouse_interaction): &(Primitive, mouse::Interaction),
         overlay_text: &[T],
     ) -> mouse::Interaction {
@@ -81,6 +143,7 @@ impl Backend {
         for layer in layers {
             self.flush(
                 device,
+                queue,
                 scale_factor,
                 transformation,
                 &layer,
@@ -92,6 +155,8 @@ impl Backend {
             );
         }
 
+        self.offscreen_pipeline.trim();
+
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         self.image_pipeline.trim_cache();
 
@@ -101,6 +166,7 @@ impl Backend {
     fn flush(
         &mut self,
         device: &wgpu::Device,
+        queue: Option<&wgpu::Queue>,
         scale_factor: f32,
         transformation: Transformation,
         layer: &Layer<'_>,
@@ -142,6 +208,19 @@ impl Backend {
             );
         }
 
+        if let Some(queue) = queue {
+            if !layer.shaders.is_empty() {
+                self.offscreen_pipeline.draw(
+                    device,
+                    queue,
+                    encoder,
+                    &layer.shaders,
+                    scale_factor,
+                    target,
+                );
+            }
+        }
+
         #[cfg(any(feature = "image_rs", feature = "svg"))]
         {
             if !layer.images.is_empty() {
@@ -275,6 +354,13 @@ impl backend::Image for Backend {
     fn dimensions(&self, handle: &iced_native::image::Handle) -> (u32, u32) {
         self.image_pipeline.dimensions(handle)
     }
+
+    fn frames(
+        &self,
+        handle: &iced_native::image::Handle,
+    ) -> iced_native::image::animated::Frames {
+        self.image_pipeline.frames(handle)
+    }
 }
 
 #[cfg(feature = "svg")]
@@ -10,15 +10,13 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image;
 use iced_native::Rectangle;
 use std::cell::RefCell;
 use std::mem;
 
 use bytemuck::{Pod, Zeroable};
 
-#[cfg(feature = "image_rs")]
-use iced_native::image;
-
 #[cfg(feature = "svg")]
 use iced_native::svg;
 
@@ -34,7 +32,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -45,15 +44,20 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let create_sampler = |filter_mode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
+                ..Default::default()
+            })
+        };
+
+        let linear_sampler = create_sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = create_sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -90,7 +94,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let create_constants = |sampler: &wgpu::Sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -107,10 +111,14 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = create_constants(&linear_sampler);
+        let nearest_constants = create_constants(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -254,7 +262,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -270,6 +279,13 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image_rs")]
+    pub fn frames(&self, handle: &image::Handle) -> image::animated::Frames {
+        let mut cache = self.raster_cache.borrow_mut();
+
+        cache.frames(handle)
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -291,6 +307,10 @@ impl Pipeline {
     ) {
         let instances: &mut Vec<Instance> = &mut Vec::new();
 
+        // Consecutive instances sharing the same filter method are drawn
+        // together, preserving the order of the images
+        let mut batches: Vec<(image::FilterMethod, usize)> = Vec::new();
+
         #[cfg(feature = "image_rs")]
         let mut raster_cache = self.raster_cache.borrow_mut();
 
@@ -298,9 +318,14 @@ impl Pipeline {
         let mut vector_cache = self.vector_cache.borrow_mut();
 
         for image in images {
-            match &image {
+            let filter_method = match &image {
                 #[cfg(feature = "image_rs")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    bounds,
+                    filter_method,
+                    source,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
                         device,
@@ -308,15 +333,18 @@ impl Pipeline {
                         &mut self.texture_atlas,
                     ) {
                         add_instances(
-                            [bounds.x, bounds.y],
-                            [bounds.width, bounds.height],
+                            *bounds,
+                            *source,
+                            *filter_method,
                             atlas_entry,
                             instances,
                         );
                     }
+
+                    *filter_method
                 }
                 #[cfg(not(feature = "image_rs"))]
-                layer::Image::Raster { .. } => {}
+                layer::Image::Raster { .. } => image::FilterMethod::Linear,
 
                 #[cfg(feature = "svg")]
                 layer::Image::Vector { handle, bounds } => {
@@ -331,15 +359,25 @@ impl Pipeline {
                         &mut self.texture_atlas,
                     ) {
                         add_instances(
-                            [bounds.x, bounds.y],
-                            size,
+                            *bounds,
+                            None,
+                            image::FilterMethod::Linear,
                             atlas_entry,
                             instances,
                         );
                     }
+
+                    image::FilterMethod::Linear
                 }
                 #[cfg(not(feature = "svg"))]
-                layer::Image::Vector { .. } => {}
+                layer::Image::Vector { .. } => image::FilterMethod::Linear,
+            };
+
+            match batches.last_mut() {
+                Some((last, end)) if *last == filter_method => {
+                    *end = instances.len();
+                }
+                _ => batches.push((filter_method, instances.len())),
             }
         }
 
@@ -382,66 +420,75 @@ impl Pipeline {
             }));
         }
 
-        let mut i = 0;
-        let total = instances.len();
-
-        while i < total {
-            let end = (i + Instance::MAX).min(total);
-            let amount = end - i;
+        let mut start = 0;
 
-            let mut instances_buffer = staging_belt.write_buffer(
-                encoder,
-                &self.instances,
-                0,
-                wgpu::BufferSize::new(
-                    (amount * std::mem::size_of::<Instance>()) as u64,
-                )
-                .unwrap(),
-                device,
-            );
+        for (filter_method, end) in batches {
+            let constants = match filter_method {
+                image::FilterMethod::Linear => &self.linear_constants,
+                image::FilterMethod::Nearest => &self.nearest_constants,
+            };
 
-            instances_buffer.copy_from_slice(bytemuck::cast_slice(
-                &instances[i..i + amount],
-            ));
-
-            let mut render_pass =
-                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
-                    label: Some("iced_wgpu::image render pass"),
-                    color_attachments: &[wgpu::RenderPassColorAttachment {
-                        view: target,
-                        resolve_target: None,
-                        ops: wgpu::Operations {
-                            load: wgpu::LoadOp::Load,
-                            store: true,
-                        },
-                    }],
-                    depth_stencil_attachment: None,
-                });
+            let mut i = start;
 
-            render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
-            render_pass.set_bind_group(1, &self.texture, &[]);
-            render_pass.set_index_buffer(
-                self.indices.slice(..),
-                wgpu::IndexFormat::Uint16,
-            );
-            render_pass.set_vertex_buffer(0, self.vertices.slice(..));
-            render_pass.set_vertex_buffer(1, self.instances.slice(..));
-
-            render_pass.set_scissor_rect(
-                bounds.x,
-                bounds.y,
-                bounds.width,
-                bounds.height,
-            );
+            while i < end {
+                let amount = (end - i).min(Instance::MAX);
 
-            render_pass.draw_indexed(
-                0..QUAD_INDICES.len() as u32,
-                0,
-                0..amount as u32,
-            );
+                let mut instances_buffer = staging_belt.write_buffer(
+                    encoder,
+                    &self.instances,
+                    0,
+                    wgpu::BufferSize::new(
+                        (amount * std::mem::size_of::<Instance>()) as u64,
+                    )
+                    .unwrap(),
+                    device,
+                );
+
+                instances_buffer.copy_from_slice(bytemuck::cast_slice(
+                    &instances[i..i + amount],
+                ));
+
+                let mut render_pass =
+                    encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                        label: Some("iced_wgpu::image render pass"),
+                        color_attachments: &[wgpu::RenderPassColorAttachment {
+                            view: target,
+                            resolve_target: None,
+                            ops: wgpu::Operations {
+                                load: wgpu::LoadOp::Load,
+                                store: true,
+                            },
+                        }],
+                        depth_stencil_attachment: None,
+                    });
+
+                render_pass.set_pipeline(&self.pipeline);
+                render_pass.set_bind_group(0, constants, &[]);
+                render_pass.set_bind_group(1, &self.texture, &[]);
+                render_pass.set_index_buffer(
+                    self.indices.slice(..),
+                    wgpu::IndexFormat::Uint16,
+                );
+                render_pass.set_vertex_buffer(0, self.vertices.slice(..));
+                render_pass.set_vertex_buffer(1, self.instances.slice(..));
+
+                render_pass.set_scissor_rect(
+                    bounds.x,
+                    bounds.y,
+                    bounds.width,
+                    bounds.height,
+                );
+
+                render_pass.draw_indexed(
+                    0..QUAD_INDICES.len() as u32,
+                    0,
+                    0..amount as u32,
+                );
+
+                i += amount;
+            }
 
-            i += Instance::MAX;
+            start = end;
         }
     }
 
@@ -498,37 +545,42 @@ struct Uniforms {
 }
 
 fn add_instances(
-    image_position: [f32; 2],
-    image_size: [f32; 2],
+    bounds: Rectangle,
+    source: Option<Rectangle>,
+    filter_method: image::FilterMethod,
     entry: &atlas::Entry,
     instances: &mut Vec<Instance>,
 ) {
+    let (width, height) = entry.size();
+
+    let source = source.unwrap_or(Rectangle {
+        x: 0.0,
+        y: 0.0,
+        width: width as f32,
+        height: height as f32,
+    });
+
+    // Linear filtering samples neighboring texels, so we keep away from the
+    // edges of the allocation to avoid bleeding
+    let inset = match filter_method {
+        image::FilterMethod::Linear => 0.5,
+        image::FilterMethod::Nearest => 0.0,
+    };
+
     match entry {
         atlas::Entry::Contiguous(allocation) => {
-            add_instance(image_position, image_size, allocation, instances);
+            add_instance(bounds, source, inset, (0, 0), allocation, instances);
         }
-        atlas::Entry::Fragmented { fragments, size } => {
-            let scaling_x = image_size[0] / size.0 as f32;
-            let scaling_y = image_size[1] / size.1 as f32;
-
+        atlas::Entry::Fragmented { fragments, .. } => {
             for fragment in fragments {
-                let allocation = &fragment.allocation;
-
-                let [x, y] = image_position;
-                let (fragment_x, fragment_y) = fragment.position;
-                let (fragment_width, fragment_height) = allocation.size();
-
-                let position = [
-                    x + fragment_x as f32 * scaling_x,
-                    y + fragment_y as f32 * scaling_y,
-                ];
-
-                let size = [
-                    fragment_width as f32 * scaling_x,
-                    fragment_height as f32 * scaling_y,
-                ];
-
-                add_instance(position, size, allocation, instances);
+                add_instance(
+                    bounds,
+                    source,
+                    inset,
+                    fragment.position,
+                    &fragment.allocation,
+                    instances,
+                );
             }
         }
     }
@@ -536,25 +588,48 @@ fn add_instances(
 
 #[inline]
 fn add_instance(
-    position: [f32; 2],
-    size: [f32; 2],
+    bounds: Rectangle,
+    source: Rectangle,
+    inset: f32,
+    fragment_position: (u32, u32),
     allocation: &atlas::Allocation,
     instances: &mut Vec<Instance>,
 ) {
-    let (x, y) = allocation.position();
+    let (fragment_x, fragment_y) = fragment_position;
     let (width, height) = allocation.size();
+
+    let fragment = Rectangle {
+        x: fragment_x as f32,
+        y: fragment_y as f32,
+        width: width as f32,
+        height: height as f32,
+    };
+
+    // The part of the source region stored in this allocation
+    let region = match source.intersection(&fragment) {
+        Some(region) => region,
+        None => return,
+    };
+
+    let scale_x = bounds.width / source.width;
+    let scale_y = bounds.height / source.height;
+
+    let (x, y) = allocation.position();
     let layer = allocation.layer();
 
     let instance = Instance {
-        _position: position,
-        _size: size,
+        _position: [
+            bounds.x + (region.x - source.x) * scale_x,
+            bounds.y + (region.y - source.y) * scale_y,
+        ],
+        _size: [region.width * scale_x, region.height * scale_y],
         _position_in_atlas: [
-            (x as f32 + 0.5) / atlas::SIZE as f32,
-            (y as f32 + 0.5) / atlas::SIZE as f32,
+            (x as f32 + region.x - fragment.x + inset) / atlas::SIZE as f32,
+            (y as f32 + region.y - fragment.y + inset) / atlas::SIZE as f32,
         ],
         _size_in_atlas: [
-            (width as f32 - 1.0) / atlas::SIZE as f32,
-            (height as f32 - 1.0) / atlas::SIZE as f32,
+            (region.width - 2.0 * inset).max(0.0) / atlas::SIZE as f32,
+            (region.height - 2.0 * inset).max(0.0) / atlas::SIZE as f32,
         ],
         _layer: layer as u32,
     };
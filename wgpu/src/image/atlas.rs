@@ -145,6 +145,99 @@ impl Atlas {
         Some(entry)
     }
 
+    /// Replaces the pixels in the given region of an uploaded [`Entry`].
+    ///
+    /// The region is relative to the image of the [`Entry`] and the pixels
+    /// must cover it exactly.
+    #[cfg(feature = "image_rs")]
+    pub fn update(
+        &mut self,
+        entry: &Entry,
+        region: iced_native::Rectangle<u32>,
+        data: &[u8],
+        device: &wgpu::Device,
+        encoder: &mut wgpu::CommandEncoder,
+    ) {
+        use wgpu::util::DeviceExt;
+
+        if region.width == 0 || region.height == 0 {
+            return;
+        }
+
+        let align = wgpu::COPY_BYTES_PER_ROW_ALIGNMENT;
+        let padding = (align - (4 * region.width) % align) % align;
+        let row_length = 4 * region.width as usize;
+        let padded_width = row_length + padding as usize;
+
+        let mut padded_data = vec![0; padded_width * region.height as usize];
+
+        for (row, pixels) in data.chunks_exact(row_length).enumerate() {
+            let offset = row * padded_width;
+
+            padded_data[offset..offset + row_length].copy_from_slice(pixels);
+        }
+
+        let buffer =
+            device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+                label: Some("iced_wgpu::image update staging buffer"),
+                contents: &padded_data,
+                usage: wgpu::BufferUsage::COPY_SRC,
+            });
+
+        let mut copy = |position: (u32, u32), allocation: &Allocation| {
+            let (width, height) = allocation.size();
+
+            // Clip the region to the part of the image covered by the
+            // allocation
+            let left = region.x.max(position.0);
+            let top = region.y.max(position.1);
+            let right = (region.x + region.width).min(position.0 + width);
+            let bottom = (region.y + region.height).min(position.1 + height);
+
+            if left >= right || top >= bottom {
+                return;
+            }
+
+            let (x, y) = allocation.position();
+            let offset = (top - region.y) as u64 * padded_width as u64
+                + 4 * (left - region.x) as u64;
+
+            encoder.copy_buffer_to_texture(
+                wgpu::ImageCopyBuffer {
+                    buffer: &buffer,
+                    layout: wgpu::ImageDataLayout {
+                        offset,
+                        bytes_per_row: NonZeroU32::new(padded_width as u32),
+                        rows_per_image: NonZeroU32::new(region.height),
+                    },
+                },
+                wgpu::ImageCopyTexture {
+                    texture: &self.texture,
+                    mip_level: 0,
+                    origin: wgpu::Origin3d {
+                        x: x + left - position.0,
+                        y: y + top - position.1,
+                        z: allocation.layer() as u32,
+                    },
+                },
+                wgpu::Extent3d {
+                    width: right - left,
+                    height: bottom - top,
+                    depth_or_array_layers: 1,
+                },
+            );
+        };
+
+        match entry {
+            Entry::Contiguous(allocation) => copy((0, 0), allocation),
+            Entry::Fragmented { fragments, .. } => {
+                for fragment in fragments {
+                    copy(fragment.position, &fragment.allocation);
+                }
+            }
+        }
+    }
+
     pub fn remove(&mut self, entry: &Entry) {
         log::info!("Removing atlas entry: {:?}", entry);
 
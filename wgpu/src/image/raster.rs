@@ -1,10 +1,11 @@
 use crate::image::atlas::{self, Atlas};
-use iced_native::image;
+use iced_graphics::raster;
+use iced_native::image::{self, animated};
 use std::collections::{HashMap, HashSet};
 
 #[derive(Debug)]
 pub enum Memory {
-    Host(::image_rs::ImageBuffer<::image_rs::Bgra<u8>, Vec<u8>>),
+    Host(raster::Image),
     Device(atlas::Entry),
     NotFound,
     Invalid,
@@ -24,6 +25,7 @@ impl Memory {
 #[derive(Debug)]
 pub struct Cache {
     map: HashMap<u64, Memory>,
+    animations: HashMap<u64, animated::Frames>,
     hits: HashSet<u64>,
 }
 
@@ -31,6 +33,7 @@ impl Cache {
     pub fn new() -> Self {
         Self {
             map: HashMap::new(),
+            animations: HashMap::new(),
             hits: HashSet::new(),
         }
     }
@@ -40,42 +43,37 @@ impl Cache {
             return self.get(handle).unwrap();
         }
 
-        let memory = match handle.data() {
-            image::Data::Path(path) => {
-                if let Ok(image) = ::image_rs::open(path) {
-                    Memory::Host(image.to_bgra8())
-                } else {
-                    Memory::NotFound
-                }
-            }
-            image::Data::Bytes(bytes) => {
-                if let Ok(image) = ::image_rs::load_from_memory(&bytes) {
-                    Memory::Host(image.to_bgra8())
-                } else {
-                    Memory::Invalid
-                }
-            }
-            image::Data::Pixels {
-                width,
-                height,
-                pixels,
-            } => {
-                if let Some(image) = ::image_rs::ImageBuffer::from_vec(
-                    *width,
-                    *height,
-                    pixels.to_vec(),
-                ) {
-                    Memory::Host(image)
-                } else {
-                    Memory::Invalid
-                }
-            }
+        let memory = match raster::load(handle) {
+            Ok(image) => Memory::Host(image),
+            Err(raster::Error::NotFound) => Memory::NotFound,
+            Err(raster::Error::Invalid) => Memory::Invalid,
         };
 
         self.insert(handle, memory);
         self.get(handle).unwrap()
     }
 
+    pub fn frames(&mut self, handle: &image::Handle) -> animated::Frames {
+        let frames = self
+            .animations
+            .entry(handle.id())
+            .or_insert_with(|| {
+                raster::load_frames(handle)
+                    .unwrap_or_else(|_| animated::Frames::from(handle.clone()))
+            })
+            .clone();
+
+        // Keep every frame of the animation in the atlas while it is in use,
+        // not only the one that is currently displayed.
+        let _ = self.hits.insert(handle.id());
+
+        for frame in frames.iter() {
+            let _ = self.hits.insert(frame.handle.id());
+        }
+
+        frames
+    }
+
     pub fn upload(
         &mut self,
         handle: &image::Handle,
@@ -93,6 +91,14 @@ impl Cache {
             *memory = Memory::Device(entry);
         }
 
+        if let (Memory::Device(entry), image::Data::Texture(texture)) =
+            (&*memory, handle.data())
+        {
+            if let Some((region, pixels)) = texture.take_changes() {
+                atlas.update(entry, region, &pixels, device, encoder);
+            }
+        }
+
         if let Memory::Device(allocation) = memory {
             Some(allocation)
         } else {
@@ -103,6 +109,8 @@ impl Cache {
     pub fn trim(&mut self, atlas: &mut Atlas) {
         let hits = &self.hits;
 
+        self.animations.retain(|k, _| hits.contains(k));
+
         self.map.retain(|k, memory| {
             let retain = hits.contains(k);
 
@@ -33,6 +33,7 @@ pub mod widget;
 pub mod window;
 
 mod backend;
+mod offscreen;
 mod quad;
 mod text;
 
@@ -41,6 +42,10 @@ pub use iced_graphics::{
 };
 pub use wgpu;
 
+#[cfg(feature = "sheet")]
+#[cfg_attr(docsrs, doc(cfg(feature = "sheet")))]
+pub use iced_graphics::sheet;
+
 pub use backend::Backend;
 pub use settings::Settings;
 
@@ -0,0 +1,302 @@
+use crate::widget::shader;
+
+use iced_graphics::layer;
+use iced_native::Size;
+
+#[derive(Debug)]
+pub struct Pipeline {
+    format: wgpu::TextureFormat,
+    pipeline: wgpu::RenderPipeline,
+    constants: wgpu::BindGroup,
+    texture_layout: wgpu::BindGroupLayout,
+    targets: Vec<Target>,
+    next_target: usize,
+}
+
+impl Pipeline {
+    pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
+        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
+            address_mode_u: wgpu::AddressMode::ClampToEdge,
+            address_mode_v: wgpu::AddressMode::ClampToEdge,
+            address_mode_w: wgpu::AddressMode::ClampToEdge,
+            mag_filter: wgpu::FilterMode::Nearest,
+            min_filter: wgpu::FilterMode::Nearest,
+            mipmap_filter: wgpu::FilterMode::Nearest,
+            ..Default::default()
+        });
+
+        let constant_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::offscreen constants layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Sampler {
+                        comparison: false,
+                        filtering: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let constants = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::offscreen constants bind group"),
+            layout: &constant_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::Sampler(&sampler),
+            }],
+        });
+
+        let texture_layout =
+            device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                label: Some("iced_wgpu::offscreen texture layout"),
+                entries: &[wgpu::BindGroupLayoutEntry {
+                    binding: 0,
+                    visibility: wgpu::ShaderStage::FRAGMENT,
+                    ty: wgpu::BindingType::Texture {
+                        sample_type: wgpu::TextureSampleType::Float {
+                            filterable: false,
+                        },
+                        view_dimension: wgpu::TextureViewDimension::D2,
+                        multisampled: false,
+                    },
+                    count: None,
+                }],
+            });
+
+        let layout =
+            device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: Some("iced_wgpu::offscreen pipeline layout"),
+                push_constant_ranges: &[],
+                bind_group_layouts: &[&constant_layout, &texture_layout],
+            });
+
+        let shader =
+            device.create_shader_module(&wgpu::ShaderModuleDescriptor {
+                label: Some("iced_wgpu::offscreen blit shader"),
+                source: wgpu::ShaderSource::Wgsl(std::borrow::Cow::Borrowed(
+                    include_str!("shader/blit.wgsl"),
+                )),
+                flags: wgpu::ShaderFlags::all(),
+            });
+
+        let pipeline =
+            device.create_render_pipeline(&wgpu::RenderPipelineDescriptor {
+                label: Some("iced_wgpu::offscreen pipeline"),
+                layout: Some(&layout),
+                vertex: wgpu::VertexState {
+                    module: &shader,
+                    entry_point: "vs_main",
+                    buffers: &[],
+                },
+                fragment: Some(wgpu::FragmentState {
+                    module: &shader,
+                    entry_point: "fs_main",
+                    targets: &[wgpu::ColorTargetState {
+                        format,
+                        blend: Some(wgpu::BlendState {
+                            color: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::SrcAlpha,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                            alpha: wgpu::BlendComponent {
+                                src_factor: wgpu::BlendFactor::One,
+                                dst_factor: wgpu::BlendFactor::OneMinusSrcAlpha,
+                                operation: wgpu::BlendOperation::Add,
+                            },
+                        }),
+                        write_mask: wgpu::ColorWrite::ALL,
+                    }],
+                }),
+                primitive: wgpu::PrimitiveState {
+                    topology: wgpu::PrimitiveTopology::TriangleList,
+                    front_face: wgpu::FrontFace::Cw,
+                    ..Default::default()
+                },
+                depth_stencil: None,
+                multisample: wgpu::MultisampleState {
+                    count: 1,
+                    mask: !0,
+                    alpha_to_coverage_enabled: false,
+                },
+            });
+
+        Pipeline {
+            format,
+            pipeline,
+            constants,
+            texture_layout,
+            targets: Vec::new(),
+            next_target: 0,
+        }
+    }
+
+    pub fn draw(
+        &mut self,
+        device: &wgpu::Device,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        shaders: &[layer::Shader<'_>],
+        scale_factor: f32,
+        target: &wgpu::TextureView,
+    ) {
+        for shader in shaders {
+            let primitive = match shader
+                .primitive
+                .downcast_ref::<Box<dyn shader::Primitive>>()
+            {
+                Some(primitive) => primitive,
+                None => continue,
+            };
+
+            let bounds = shader.bounds * scale_factor;
+            let clip_bounds = (shader.clip_bounds * scale_factor).snap();
+
+            let size = Size::new(
+                bounds.width.round() as u32,
+                bounds.height.round() as u32,
+            );
+
+            if size.width < 1
+                || size.height < 1
+                || clip_bounds.width < 1
+                || clip_bounds.height < 1
+            {
+                continue;
+            }
+
+            let index = self.prepare_target(device, size);
+            let offscreen = &self.targets[index];
+
+            primitive.render(shader::Target {
+                device,
+                queue,
+                encoder,
+                view: &offscreen.view,
+                format: self.format,
+                size,
+                scale_factor,
+            });
+
+            let mut render_pass =
+                encoder.begin_render_pass(&wgpu::RenderPassDescriptor {
+                    label: Some("iced_wgpu::offscreen render pass"),
+                    color_attachments: &[wgpu::RenderPassColorAttachment {
+                        view: target,
+                        resolve_target: None,
+                        ops: wgpu::Operations {
+                            load: wgpu::LoadOp::Load,
+                            store: true,
+                        },
+                    }],
+                    depth_stencil_attachment: None,
+                });
+
+            render_pass.set_pipeline(&self.pipeline);
+            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(1, &offscreen.bind_group, &[]);
+
+            render_pass.set_viewport(
+                bounds.x,
+                bounds.y,
+                size.width as f32,
+                size.height as f32,
+                0.0,
+                1.0,
+            );
+
+            render_pass.set_scissor_rect(
+                clip_bounds.x,
+                clip_bounds.y,
+                clip_bounds.width,
+                clip_bounds.height,
+            );
+
+            render_pass.draw(0..6, 0..1);
+        }
+    }
+
+    /// Releases the offscreen textures that were not used in the last frame.
+    pub fn trim(&mut self) {
+        self.targets.truncate(self.next_target);
+        self.next_target = 0;
+    }
+
+    fn prepare_target(
+        &mut self,
+        device: &wgpu::Device,
+        size: Size<u32>,
+    ) -> usize {
+        let index = self.next_target;
+
+        let is_outdated = match self.targets.get(index) {
+            Some(target) => target.size != size,
+            None => true,
+        };
+
+        if is_outdated {
+            let target =
+                Target::new(device, self.format, &self.texture_layout, size);
+
+            if index < self.targets.len() {
+                self.targets[index] = target;
+            } else {
+                self.targets.push(target);
+            }
+        }
+
+        self.next_target += 1;
+
+        index
+    }
+}
+
+#[derive(Debug)]
+struct Target {
+    view: wgpu::TextureView,
+    bind_group: wgpu::BindGroup,
+    size: Size<u32>,
+}
+
+impl Target {
+    fn new(
+        device: &wgpu::Device,
+        format: wgpu::TextureFormat,
+        texture_layout: &wgpu::BindGroupLayout,
+        size: Size<u32>,
+    ) -> Target {
+        let texture = device.create_texture(&wgpu::TextureDescriptor {
+            label: Some("iced_wgpu::offscreen texture"),
+            size: wgpu::Extent3d {
+                width: size.width,
+                height: size.height,
+                depth_or_array_layers: 1,
+            },
+            mip_level_count: 1,
+            sample_count: 1,
+            dimension: wgpu::TextureDimension::D2,
+            format,
+            usage: wgpu::TextureUsage::RENDER_ATTACHMENT
+                | wgpu::TextureUsage::SAMPLED,
+        });
+
+        let view = texture.create_view(&wgpu::TextureViewDescriptor::default());
+
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("iced_wgpu::offscreen texture bind group"),
+            layout: texture_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: wgpu::BindingResource::TextureView(&view),
+            }],
+        });
+
+        Target {
+            view,
+            bind_group,
+            size,
+        }
+    }
+}
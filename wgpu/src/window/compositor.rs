@@ -166,8 +166,9 @@ impl iced_graphics::window::Compositor for Compositor {
             depth_stencil_attachment: None,
         });
 
-        let mouse_interaction = renderer.backend_mut().draw(
+        let mouse_interaction = renderer.backend_mut().draw_with_queue(
             &mut self.device,
+            &self.queue,
             &mut self.staging_belt,
             &mut encoder,
             &frame.output.view,
@@ -0,0 +1,607 @@
+//! Follow the light or dark appearance of the operating system.
+//!
+//! The [`theme_changes`] subscription produces the system appearance once on
+//! startup and then every time the user changes it. The system is only
+//! watched while the subscription is active:
+//!
+//! - Windows reports changes on its own with window events.
+//! - On Linux and BSDs, the [`System`] source reads the `color-scheme` of the
+//!   XDG settings portal, falling back to the GNOME settings and the
+//!   `GTK_THEME` environment variable. It waits for the `SettingChanged`
+//!   signal of the portal, or for changes in the GNOME settings, with a
+//!   monitor process.
+//! - On macOS, it reads the `AppleInterfaceStyle` user default. Since there
+//!   is no way to be notified of its changes from the command line, the
+//!   global preferences file is checked for modifications every
+//!   [`POLL_INTERVAL`] in a background thread, and the default is only read
+//!   again once the file changes.
+//!
+//! The background thread notices that the subscription is gone within a
+//! [`POLL_INTERVAL`], and stops watching the system then.
+//!
+//! The `ICED_APPEARANCE` environment variable can be set to `light` or `dark`
+//! to override the appearance of the system.
+use crate::event::{self, Event};
+use crate::subscription::{EventStream, Recipe};
+use crate::window::{self, Theme};
+use crate::{Hasher, Subscription};
+
+use iced_futures::futures::channel::mpsc;
+use iced_futures::futures::future;
+use iced_futures::futures::stream::{self, StreamExt};
+use iced_futures::BoxStream;
+use std::thread;
+use std::time::Duration;
+
+/// The environment variable used to override the system appearance.
+pub const APPEARANCE_VARIABLE: &str = "ICED_APPEARANCE";
+
+/// The maximum amount of time a watched [`Source`] blocks in a single
+/// [`Source::wait`].
+///
+/// It is also the interval between checks for changes in platforms that do
+/// not notify of them.
+pub const POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// A source of the appearance of the system.
+pub trait Source: Send {
+    /// Queries the current appearance of the system.
+    ///
+    /// It returns `None` if the appearance is unknown.
+    fn query(&mut self) -> Option<Theme>;
+
+    /// Blocks until the appearance of the system may have changed, or until
+    /// the given `timeout` elapses.
+    fn wait(&mut self, timeout: Duration) -> Wait;
+}
+
+/// The outcome of a [`Source::wait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Wait {
+    /// The appearance of the system may have changed.
+    Changed,
+
+    /// The timeout elapsed without any changes.
+    TimedOut,
+
+    /// The changes cannot be watched anymore.
+    Stopped,
+}
+
+/// The appearance [`Source`] of the current platform.
+#[derive(Debug, Default)]
+pub struct System {
+    monitor: platform::Monitor,
+}
+
+impl Source for System {
+    fn query(&mut self) -> Option<Theme> {
+        match std::env::var(APPEARANCE_VARIABLE) {
+            Ok(appearance) => parse_name(&appearance),
+            Err(_) => platform::query(),
+        }
+    }
+
+    fn wait(&mut self, timeout: Duration) -> Wait {
+        // An overridden appearance never changes
+        if std::env::var_os(APPEARANCE_VARIABLE).is_some() {
+            return Wait::Stopped;
+        }
+
+        self.monitor.wait(timeout)
+    }
+}
+
+/// Returns a [`Subscription`] that produces the appearance of the system on
+/// startup and every time the user changes it.
+///
+/// This can be used to make an application follow the light or dark
+/// appearance of the operating system. The [`System`] is only watched while
+/// the [`Subscription`] is active.
+pub fn theme_changes() -> Subscription<Theme> {
+    Subscription::from_recipe(ThemeChanges)
+}
+
+/// Returns the appearance of the system reported by the given window, if the
+/// platform reports it on its own.
+///
+/// Only Windows does. Elsewhere, [`theme_changes`] queries the [`System`].
+pub fn window_theme(window: &winit::window::Window) -> Option<Theme> {
+    #[cfg(target_os = "windows")]
+    {
+        use winit::platform::windows::WindowExtWindows;
+
+        Some(crate::conversion::theme(window.theme()))
+    }
+
+    #[cfg(not(target_os = "windows"))]
+    {
+        let _ = window;
+
+        None
+    }
+}
+
+/// Watches the given [`Source`] in a background thread.
+///
+/// The returned stream produces the current appearance first and then every
+/// change. It ends once the [`Source`] cannot be watched anymore.
+///
+/// The [`Source`] is dropped, and the thread finishes, within a
+/// [`POLL_INTERVAL`] after the returned stream is dropped.
+pub fn watch(mut source: impl Source + 'static) -> BoxStream<Theme> {
+    let (sender, receiver) = mpsc::unbounded();
+
+    let _ = thread::Builder::new()
+        .name(String::from("iced_winit appearance"))
+        .spawn(move || {
+            let mut last = None;
+
+            loop {
+                if let Some(appearance) = source.query() {
+                    if Some(appearance) != last {
+                        last = Some(appearance);
+
+                        if sender.unbounded_send(appearance).is_err() {
+                            return;
+                        }
+                    }
+                }
+
+                loop {
+                    match source.wait(POLL_INTERVAL) {
+                        Wait::Changed => break,
+                        Wait::TimedOut if !sender.is_closed() => {}
+                        Wait::TimedOut | Wait::Stopped => return,
+                    }
+                }
+            }
+        });
+
+    receiver.boxed()
+}
+
+struct ThemeChanges;
+
+impl Recipe<Hasher, (Event, event::Status)> for ThemeChanges {
+    type Output = Theme;
+
+    fn hash(&self, state: &mut Hasher) {
+        use std::hash::Hash;
+
+        std::any::TypeId::of::<Self>().hash(state);
+    }
+
+    fn stream(self: Box<Self>, input: EventStream) -> BoxStream<Theme> {
+        let events = input.filter_map(|(event, _status)| {
+            future::ready(match event {
+                Event::Window(window::Event::ThemeChanged(theme)) => {
+                    Some(theme)
+                }
+                _ => None,
+            })
+        });
+
+        stream::select(events, watch(System::default())).boxed()
+    }
+}
+
+/// Parses an appearance name, like `light` or `dark`.
+fn parse_name(name: &str) -> Option<Theme> {
+    match name.trim().to_lowercase().as_str() {
+        "light" => Some(Theme::Light),
+        "dark" => Some(Theme::Dark),
+        _ => None,
+    }
+}
+
+#[cfg(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd"
+))]
+mod platform {
+    use super::{Theme, Wait};
+
+    use std::io::{BufRead, BufReader};
+    use std::process::{Child, Command, Stdio};
+    use std::sync::mpsc;
+    use std::thread;
+    use std::time::Duration;
+
+    pub fn query() -> Option<Theme> {
+        portal()
+            .or_else(|| {
+                gsettings("color-scheme")
+                    .as_deref()
+                    .and_then(parse_color_scheme)
+            })
+            .or_else(|| {
+                std::env::var("GTK_THEME")
+                    .ok()
+                    .as_deref()
+                    .and_then(parse_gtk_theme)
+            })
+            .or_else(|| {
+                gsettings("gtk-theme").as_deref().and_then(parse_gtk_theme)
+            })
+    }
+
+    /// A process reporting changes in the settings of the desktop.
+    ///
+    /// Its output is read in a separate thread, which finishes once the
+    /// process is killed.
+    #[derive(Debug, Default)]
+    pub struct Monitor {
+        process: Option<Child>,
+        changes: Option<mpsc::Receiver<()>>,
+    }
+
+    impl Monitor {
+        pub fn wait(&mut self, timeout: Duration) -> Wait {
+            if self.process.is_none() {
+                self.spawn();
+            }
+
+            match &self.changes {
+                Some(changes) => match changes.recv_timeout(timeout) {
+                    Ok(()) => Wait::Changed,
+                    Err(mpsc::RecvTimeoutError::Timeout) => Wait::TimedOut,
+                    Err(mpsc::RecvTimeoutError::Disconnected) => Wait::Stopped,
+                },
+                None => Wait::Stopped,
+            }
+        }
+
+        fn spawn(&mut self) {
+            let mut command = if portal().is_some() {
+                // Listen to the `SettingChanged` signal of the portal
+                let mut command = Command::new("gdbus");
+                let _ = command.args([
+                    "monitor",
+                    "--session",
+                    "--dest",
+                    "org.freedesktop.portal.Desktop",
+                    "--object-path",
+                    "/org/freedesktop/portal/desktop",
+                ]);
+
+                command
+            } else {
+                let mut command = Command::new("gsettings");
+                let _ =
+                    command.args(["monitor", "org.gnome.desktop.interface"]);
+
+                command
+            };
+
+            let process = command
+                .stdin(Stdio::null())
+                .stdout(Stdio::piped())
+                .stderr(Stdio::null())
+                .spawn();
+
+            if let Ok(mut process) = process {
+                if let Some(stdout) = process.stdout.take() {
+                    let (sender, receiver) = mpsc::channel();
+
+                    let _ = thread::spawn(move || {
+                        for line in BufReader::new(stdout).lines() {
+                            match line {
+                                Ok(line) if is_change(&line) => {
+                                    if sender.send(()).is_err() {
+                                        break;
+                                    }
+                                }
+                                Ok(_) => {}
+                                Err(_) => break,
+                            }
+                        }
+                    });
+
+                    self.changes = Some(receiver);
+                }
+
+                self.process = Some(process);
+            }
+        }
+    }
+
+    impl Drop for Monitor {
+        fn drop(&mut self) {
+            if let Some(process) = &mut self.process {
+                let _ = process.kill();
+                let _ = process.wait();
+            }
+        }
+    }
+
+    fn portal() -> Option<Theme> {
+        let output = run(
+            "gdbus",
+            &[
+                "call",
+                "--session",
+                "--timeout",
+                "1",
+                "--dest",
+                "org.freedesktop.portal.Desktop",
+                "--object-path",
+                "/org/freedesktop/portal/desktop",
+                "--method",
+                "org.freedesktop.portal.Settings.Read",
+                "org.freedesktop.appearance",
+                "color-scheme",
+            ],
+        )?;
+
+        parse_portal(&output)
+    }
+
+    fn gsettings(key: &str) -> Option<String> {
+        run("gsettings", &["get", "org.gnome.desktop.interface", key])
+    }
+
+    fn run(program: &str, args: &[&str]) -> Option<String> {
+        let output = Command::new(program).args(args).output().ok()?;
+
+        if output.status.success() {
+            String::from_utf8(output.stdout).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Returns whether a line reported by a [`Monitor`] is a change in the
+    /// appearance, like the `SettingChanged` signal of the portal or a
+    /// `color-scheme: 'prefer-dark'` change in the GNOME settings.
+    pub fn is_change(line: &str) -> bool {
+        line.contains("color-scheme") || line.contains("gtk-theme")
+    }
+
+    /// Parses the `color-scheme` setting read from the XDG settings portal with
+    /// `gdbus`, like `(<<uint32 1>>,)`.
+    pub fn parse_portal(output: &str) -> Option<Theme> {
+        let value = output
+            .rsplit(|c: char| !c.is_ascii_digit())
+            .find(|part| !part.is_empty())?;
+
+        match value {
+            "1" => Some(Theme::Dark),
+            "2" => Some(Theme::Light),
+            _ => None,
+        }
+    }
+
+    /// Parses the GNOME `color-scheme` setting, like `'prefer-dark'`.
+    pub fn parse_color_scheme(output: &str) -> Option<Theme> {
+        match output.trim().trim_matches('\'') {
+            "prefer-dark" => Some(Theme::Dark),
+            "prefer-light" => Some(Theme::Light),
+            _ => None,
+        }
+    }
+
+    /// Parses the name of a GTK theme, like `Adwaita:dark` or `Yaru-dark`.
+    pub fn parse_gtk_theme(name: &str) -> Option<Theme> {
+        let name = name.trim().trim_matches('\'');
+
+        if name.is_empty() {
+            None
+        } else if name.to_lowercase().contains("dark") {
+            Some(Theme::Dark)
+        } else {
+            Some(Theme::Light)
+        }
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod platform {
+    use super::{Theme, Wait};
+
+    use std::path::PathBuf;
+    use std::process::Command;
+    use std::thread;
+    use std::time::{Duration, SystemTime};
+
+    pub fn query() -> Option<Theme> {
+        let output = Command::new("defaults")
+            .args(&["read", "-g", "AppleInterfaceStyle"])
+            .output()
+            .ok()?;
+
+        // The key is missing when the light appearance is selected
+        if String::from_utf8_lossy(&output.stdout).trim() == "Dark" {
+            Some(Theme::Dark)
+        } else {
+            Some(Theme::Light)
+        }
+    }
+
+    /// The last modification time of the global preferences, where the
+    /// `AppleInterfaceStyle` default is stored.
+    #[derive(Debug, Default)]
+    pub struct Monitor {
+        modified: Option<SystemTime>,
+    }
+
+    impl Monitor {
+        pub fn wait(&mut self, timeout: Duration) -> Wait {
+            thread::sleep(timeout);
+
+            let modified = preferences()
+                .and_then(|path| path.metadata().ok())
+                .and_then(|metadata| metadata.modified().ok());
+
+            if modified != self.modified {
+                self.modified = modified;
+
+                Wait::Changed
+            } else {
+                Wait::TimedOut
+            }
+        }
+    }
+
+    fn preferences() -> Option<PathBuf> {
+        let home = std::env::var_os("HOME")?;
+
+        Some(
+            PathBuf::from(home)
+                .join("Library/Preferences/.GlobalPreferences.plist"),
+        )
+    }
+}
+
+#[cfg(not(any(
+    target_os = "linux",
+    target_os = "dragonfly",
+    target_os = "freebsd",
+    target_os = "netbsd",
+    target_os = "openbsd",
+    target_os = "macos"
+)))]
+mod platform {
+    use super::{Theme, Wait};
+
+    use std::time::Duration;
+
+    pub fn query() -> Option<Theme> {
+        None
+    }
+
+    #[derive(Debug, Default)]
+    pub struct Monitor;
+
+    impl Monitor {
+        pub fn wait(&mut self, _timeout: Duration) -> Wait {
+            Wait::Stopped
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_futures::futures::executor;
+    use std::sync::mpsc;
+
+    #[derive(Debug)]
+    struct Mock {
+        current: Option<Theme>,
+        changes: mpsc::Receiver<Option<Theme>>,
+        _alive: mpsc::Sender<()>,
+    }
+
+    impl Mock {
+        /// Creates a [`Mock`] source, together with a sender of its changes
+        /// and a receiver that disconnects once it is dropped.
+        fn new(
+            current: Option<Theme>,
+        ) -> (Self, mpsc::Sender<Option<Theme>>, mpsc::Receiver<()>) {
+            let (sender, changes) = mpsc::channel();
+            let (alive, dropped) = mpsc::channel();
+
+            let mock = Mock {
+                current,
+                changes,
+                _alive: alive,
+            };
+
+            (mock, sender, dropped)
+        }
+    }
+
+    impl Source for Mock {
+        fn query(&mut self) -> Option<Theme> {
+            self.current
+        }
+
+        fn wait(&mut self, timeout: Duration) -> Wait {
+            match self.changes.recv_timeout(timeout) {
+                Ok(appearance) => {
+                    self.current = appearance;
+
+                    Wait::Changed
+                }
+                Err(mpsc::RecvTimeoutError::Timeout) => Wait::TimedOut,
+                Err(mpsc::RecvTimeoutError::Disconnected) => Wait::Stopped,
+            }
+        }
+    }
+
+    #[test]
+    fn parses_appearance_names() {
+        assert_eq!(parse_name(" Dark\n"), Some(Theme::Dark));
+        assert_eq!(parse_name("sepia"), None);
+    }
+
+    #[cfg(any(
+        target_os = "linux",
+        target_os = "dragonfly",
+        target_os = "freebsd",
+        target_os = "netbsd",
+        target_os = "openbsd"
+    ))]
+    #[test]
+    fn parses_linux_settings() {
+        use platform::{
+            is_change, parse_color_scheme, parse_gtk_theme, parse_portal,
+        };
+
+        assert_eq!(parse_portal("(<<uint32 1>>,)\n"), Some(Theme::Dark));
+        assert_eq!(parse_portal("(<uint32 2>,)\n"), Some(Theme::Light));
+        assert_eq!(parse_portal("(<<uint32 0>>,)\n"), None);
+
+        assert_eq!(parse_color_scheme("'prefer-dark'\n"), Some(Theme::Dark));
+        assert_eq!(parse_color_scheme("'default'\n"), None);
+
+        assert_eq!(parse_gtk_theme("Adwaita:dark"), Some(Theme::Dark));
+        assert_eq!(parse_gtk_theme("'Yaru'\n"), Some(Theme::Light));
+
+        assert!(is_change(
+            "/org/freedesktop/portal/desktop: \
+             org.freedesktop.portal.Settings.SettingChanged \
+             ('org.freedesktop.appearance', 'color-scheme', <uint32 1>)"
+        ));
+        assert!(is_change("color-scheme: 'prefer-dark'"));
+        assert!(!is_change("font-name: 'Cantarell 11'"));
+    }
+
+    #[test]
+    fn watches_a_source() {
+        let (mock, sender, _dropped) = Mock::new(Some(Theme::Light));
+
+        let mut appearances = executor::block_on_stream(watch(mock));
+
+        assert_eq!(appearances.next(), Some(Theme::Light));
+
+        // Repeated and unknown appearances are skipped
+        sender.send(Some(Theme::Light)).unwrap();
+        sender.send(None).unwrap();
+        sender.send(Some(Theme::Dark)).unwrap();
+        assert_eq!(appearances.next(), Some(Theme::Dark));
+
+        drop(sender);
+        assert_eq!(appearances.next(), None);
+    }
+
+    #[test]
+    fn stops_watching_once_dropped() {
+        let (mock, _sender, dropped) = Mock::new(Some(Theme::Dark));
+
+        let mut appearances = executor::block_on_stream(watch(mock));
+        assert_eq!(appearances.next(), Some(Theme::Dark));
+
+        drop(appearances);
+
+        assert_eq!(
+            dropped.recv_timeout(POLL_INTERVAL * 2),
+            Err(mpsc::RecvTimeoutError::Disconnected)
+        );
+    }
+}
@@ -1,22 +1,33 @@
 //! Create interactive, native cross-platform applications.
+pub mod inspector;
 mod state;
+pub mod time_travel;
 
+pub use inspector::Inspector;
 pub use state::State;
+pub use time_travel::TimeTravel;
 
+use crate::appearance;
 use crate::conversion;
 use crate::mouse;
 use crate::{
-    Clipboard, Color, Command, Debug, Error, Executor, Mode, Proxy, Runtime,
-    Settings, Size, Subscription,
+    Clipboard, Color, Command, Debug, Error, Executor, Mode, Point, Proxy,
+    Runtime, Settings, Size, Subscription, Theme,
 };
 
 use iced_futures::futures;
 use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
+use iced_graphics::Themed;
+use iced_native::command;
 use iced_native::program::Program;
+use iced_native::widget;
 use iced_native::{Cache, UserInterface};
 
+use std::cell::Cell;
 use std::mem::ManuallyDrop;
+use std::rc::Rc;
+use std::time::Instant;
 
 /// An interactive, native cross-platform application.
 ///
@@ -28,7 +39,10 @@ use std::mem::ManuallyDrop;
 /// [`Command`] in some of its methods.
 ///
 /// When using an [`Application`] with the `debug` feature enabled, a debug view
-/// can be toggled by pressing `F12`.
+/// can be toggled by pressing `F12`. From the debug view, you can inspect the
+/// layout of the user interface with the [`Inspector`]. If the
+/// [`Application`] also produces a [`snapshot`](Self::snapshot), you can
+/// travel back in time through its states. See [`TimeTravel`] to learn more.
 pub trait Application: Program<Clipboard = Clipboard> {
     /// The data needed to initialize your [`Application`].
     type Flags;
@@ -72,11 +86,22 @@ pub trait Application: Program<Clipboard = Clipboard> {
         Mode::Windowed
     }
 
+    /// Returns the [`Theme`] of the [`Application`].
+    ///
+    /// The [`Theme`] is used by all the built-in widgets and passed to their
+    /// style sheets.
+    ///
+    /// By default, it returns the light [`Theme`].
+    fn theme(&self) -> Theme {
+        Theme::default()
+    }
+
     /// Returns the background [`Color`] of the [`Application`].
     ///
-    /// By default, it returns [`Color::WHITE`].
+    /// By default, it returns the background color of the [`Theme`] of the
+    /// [`Application`].
     fn background_color(&self) -> Color {
-        Color::WHITE
+        self.theme().palette().background
     }
 
     /// Returns the scale factor of the [`Application`].
@@ -98,6 +123,17 @@ pub trait Application: Program<Clipboard = Clipboard> {
     fn should_exit(&self) -> bool {
         false
     }
+
+    /// Returns a snapshot of the current state of the [`Application`].
+    ///
+    /// Snapshots are used by the debug view to travel back in time. If your
+    /// [`Application`] implements `Clone`, you can simply return
+    /// `Some(self.clone())` to enable it.
+    ///
+    /// By default, it returns `None`, which disables time travel.
+    fn snapshot(&self) -> Option<Self> {
+        None
+    }
 }
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
@@ -110,6 +146,7 @@ where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: inspector::Renderer + Themed,
 {
     use futures::task;
     use futures::Future;
@@ -120,7 +157,7 @@ where
 
     let event_loop = EventLoop::with_user_event();
 
-    let mut runtime = {
+    let runtime = {
         let proxy = Proxy::new(event_loop.create_proxy());
         let executor = E::new().map_err(Error::ExecutorCreationFailed)?;
 
@@ -133,11 +170,6 @@ where
         runtime.enter(|| A::new(flags))
     };
 
-    let subscription = application.subscription();
-
-    runtime.spawn(init_command);
-    runtime.track(subscription);
-
     let window = settings
         .window
         .into_builder(
@@ -152,14 +184,20 @@ where
 
     let (mut sender, receiver) = mpsc::unbounded();
 
+    // The first frame is drawn right away
+    let next_frame = Rc::new(Cell::new(Some(Instant::now())));
+
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
         compositor,
         renderer,
         runtime,
+        event_loop.create_proxy(),
         debug,
         receiver,
         window,
+        next_frame.clone(),
+        init_command,
         settings.exit_on_close_request,
     ));
 
@@ -193,7 +231,10 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => match next_frame.get() {
+                    Some(at) => ControlFlow::WaitUntil(at),
+                    None => ControlFlow::Wait,
+                },
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -205,14 +246,18 @@ async fn run_instance<A, E, C>(
     mut compositor: C,
     mut renderer: A::Renderer,
     mut runtime: Runtime<E, Proxy<A::Message>, A::Message>,
+    mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
     window: winit::window::Window,
+    next_frame: Rc<Cell<Option<Instant>>>,
+    init_command: Command<A::Message>,
     exit_on_close_request: bool,
 ) where
     A: Application + 'static,
     E: Executor + 'static,
     C: window::Compositor<Renderer = A::Renderer> + 'static,
+    A::Renderer: inspector::Renderer + Themed,
 {
     use iced_futures::futures::stream::StreamExt;
     use winit::event;
@@ -220,6 +265,17 @@ async fn run_instance<A, E, C>(
     let surface = compositor.create_surface(&window);
     let mut clipboard = Clipboard::connect(&window);
 
+    let mut operations = Vec::new();
+
+    run_command(
+        init_command,
+        &mut runtime,
+        &mut proxy,
+        &mut operations,
+        &window,
+    );
+    runtime.track(application.subscription());
+
     let mut state = State::new(&application, &window);
     let mut viewport_version = state.viewport_version();
     let mut swap_chain = {
@@ -232,6 +288,11 @@ async fn run_instance<A, E, C>(
         )
     };
 
+    let mut time_travel = TimeTravel::new(&application);
+    let can_time_travel = time_travel.is_enabled();
+    let mut is_traveling = false;
+    debug.time_travel(time_travel.overlay());
+
     let mut user_interface = ManuallyDrop::new(build_user_interface(
         &mut application,
         Cache::default(),
@@ -240,27 +301,79 @@ async fn run_instance<A, E, C>(
         &mut debug,
     ));
 
-    let mut primitive =
-        user_interface.draw(&mut renderer, state.cursor_position());
+    run_operations(&mut user_interface, &mut operations, &mut proxy);
+
+    let mut inspector = Inspector::new();
+    let mut primitive = inspector.draw(
+        &mut user_interface,
+        &mut renderer,
+        state.cursor_position(),
+        &mut debug,
+    );
     let mut mouse_interaction = mouse::Interaction::default();
 
-    let mut events = Vec::new();
+    let mut events: Vec<_> = appearance::window_theme(&window)
+        .map(|theme| {
+            iced_native::Event::Window(
+                iced_native::window::Event::ThemeChanged(theme),
+            )
+        })
+        .into_iter()
+        .collect();
     let mut messages = Vec::new();
 
+    #[cfg(feature = "recording")]
+    let mut session = crate::recording::Session::from_env(&window);
+
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
-                if events.is_empty() && messages.is_empty() {
+                #[cfg(feature = "recording")]
+                session.replay(&window, &mut events);
+
+                // Earlier states are frozen while traveling back in time
+                if is_traveling {
+                    events.clear();
+                }
+
+                let now = Instant::now();
+
+                let is_frame_late = match next_frame.get() {
+                    Some(at) => at <= now,
+                    None => false,
+                };
+
+                // Nothing is drawn while the window is minimized, and earlier
+                // states do not animate
+                let can_draw_frames = !state.is_minimized() && !is_traveling;
+
+                if is_frame_late && !can_draw_frames {
+                    next_frame.set(None);
+                }
+
+                let mut is_frame_due = is_frame_late && can_draw_frames;
+
+                if events.is_empty() && messages.is_empty() && !is_frame_due {
                     continue;
                 }
 
+                #[cfg(feature = "recording")]
+                session.record(&events);
+
+                #[cfg(feature = "recording")]
+                let cursor_position =
+                    session.cursor_position(state.cursor_position());
+
+                #[cfg(not(feature = "recording"))]
+                let cursor_position = state.cursor_position();
+
                 debug.event_processing_started();
 
                 let statuses = user_interface.update(
                     &events,
-                    state.cursor_position(),
+                    cursor_position,
                     &mut renderer,
                     &mut clipboard,
                     &mut messages,
@@ -280,9 +393,13 @@ async fn run_instance<A, E, C>(
                     update(
                         &mut application,
                         &mut runtime,
+                        &mut proxy,
                         &mut debug,
                         &mut clipboard,
                         &mut messages,
+                        &mut operations,
+                        &mut time_travel,
+                        &window,
                     );
 
                     // Update window
@@ -291,21 +408,79 @@ async fn run_instance<A, E, C>(
                     let should_exit = application.should_exit();
 
                     user_interface = ManuallyDrop::new(build_user_interface(
-                        &mut application,
+                        time_travel.state(&mut application),
                         cache,
                         &mut renderer,
                         state.logical_size(),
                         &mut debug,
                     ));
 
+                    run_operations(
+                        &mut user_interface,
+                        &mut operations,
+                        &mut proxy,
+                    );
+
                     if should_exit {
                         break;
                     }
+
+                    // The new state of the application is drawn right away
+                    is_frame_due = can_draw_frames;
+                }
+
+                // Widgets and subscriptions are notified of the new frame
+                // right before it is drawn
+                let status = if is_frame_due {
+                    let frame = iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    );
+
+                    let status = user_interface.update(
+                        std::slice::from_ref(&frame),
+                        cursor_position,
+                        &renderer,
+                        &mut clipboard,
+                        &mut messages,
+                    )[0];
+
+                    runtime.broadcast((frame, status));
+
+                    status
+                } else {
+                    iced_native::event::Status::Ignored
+                };
+
+                next_frame.set(if is_traveling {
+                    None
+                } else {
+                    schedule_frame(
+                        &mut user_interface,
+                        status,
+                        &messages,
+                        now,
+                        state.is_minimized(),
+                    )
+                });
+
+                // Wake up again when the next recorded events are due
+                #[cfg(feature = "recording")]
+                {
+                    if let Some(at) = session.next_replay() {
+                        next_frame.set(Some(match next_frame.get() {
+                            Some(next) => next.min(at),
+                            None => at,
+                        }));
+                    }
                 }
 
                 debug.draw_started();
-                primitive =
-                    user_interface.draw(&mut renderer, state.cursor_position());
+                primitive = inspector.draw(
+                    &mut user_interface,
+                    &mut renderer,
+                    state.cursor_position(),
+                    &mut debug,
+                );
                 debug.draw_finished();
 
                 window.request_redraw();
@@ -344,8 +519,12 @@ async fn run_instance<A, E, C>(
                     debug.layout_finished();
 
                     debug.draw_started();
-                    primitive = user_interface
-                        .draw(&mut renderer, state.cursor_position());
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
                     debug.draw_finished();
 
                     swap_chain = compositor.create_swap_chain(
@@ -375,9 +554,6 @@ async fn run_instance<A, E, C>(
 
                     mouse_interaction = new_mouse_interaction;
                 }
-
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -389,8 +565,93 @@ async fn run_instance<A, E, C>(
                     break;
                 }
 
+                let step = if can_time_travel && debug.is_enabled() {
+                    time_travel::Step::from_window_event(&window_event)
+                } else {
+                    None
+                };
+
+                if let Some(step) = step {
+                    let cache =
+                        ManuallyDrop::into_inner(user_interface).into_cache();
+
+                    time_travel.step(step);
+                    is_traveling = time_travel.is_traveling();
+                    debug.time_travel(time_travel.overlay());
+
+                    // Animations resume once back in the present
+                    next_frame.set(if is_traveling {
+                        None
+                    } else {
+                        Some(Instant::now())
+                    });
+
+                    user_interface = ManuallyDrop::new(build_user_interface(
+                        time_travel.state(&mut application),
+                        cache,
+                        &mut renderer,
+                        state.logical_size(),
+                        &mut debug,
+                    ));
+
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
+
+                    window.request_redraw();
+
+                    continue;
+                }
+
+                let scale_factor = state.scale_factor();
+                let was_minimized = state.is_minimized();
+
                 state.update(&window, &window_event, &mut debug);
 
+                // `winit` only reports minimization as an empty resize
+                if state.is_minimized() != was_minimized {
+                    events.extend_from_slice(&conversion::minimized(
+                        state.is_minimized(),
+                    ));
+
+                    // Animations resume as soon as the window is restored
+                    if !state.is_minimized() {
+                        next_frame.set(Some(Instant::now()));
+                    }
+                }
+
+                // `ScaleFactorChanged` is turned into a `Resized` event before
+                // reaching us, so we notice the change through the viewport
+                if state.scale_factor() != scale_factor {
+                    events.push(crate::Event::Window(
+                        iced_native::window::Event::ScaleFactorChanged(
+                            window.scale_factor(),
+                        ),
+                    ));
+                }
+
+                // The inspector captures any input while enabled
+                if inspector.update(
+                    &window_event,
+                    state.cursor_position(),
+                    user_interface.layout(),
+                    debug.is_enabled(),
+                ) {
+                    primitive = inspector.draw(
+                        &mut user_interface,
+                        &mut renderer,
+                        state.cursor_position(),
+                        &mut debug,
+                    );
+
+                    window.request_redraw();
+
+                    continue;
+                }
+
                 if let Some(event) = conversion::window_event(
                     &window_event,
                     state.scale_factor(),
@@ -403,6 +664,9 @@ async fn run_instance<A, E, C>(
         }
     }
 
+    #[cfg(feature = "recording")]
+    session.finish();
+
     // Manually drop the user interface
     drop(ManuallyDrop::into_inner(user_interface));
 }
@@ -431,15 +695,57 @@ pub fn requests_exit(
     }
 }
 
-/// Builds a [`UserInterface`] for the provided [`Application`], logging
-/// [`struct@Debug`] information accordingly.
+/// Returns the moment the next frame of a [`UserInterface`] is due, if any,
+/// given the [`event::Status`] of its last frame.
+///
+/// A frame is due as soon as possible if a widget captured the last one or
+/// there are messages left to process. Otherwise, it is due when the earliest
+/// widget animation needs it.
+///
+/// No frame is ever due while the window is minimized, since nothing would be
+/// drawn.
+///
+/// [`event::Status`]: iced_native::event::Status
+pub fn schedule_frame<Message, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    status: iced_native::event::Status,
+    messages: &[Message],
+    now: Instant,
+    is_minimized: bool,
+) -> Option<Instant>
+where
+    Renderer: iced_native::Renderer,
+{
+    if is_minimized {
+        return None;
+    }
+
+    if status == iced_native::event::Status::Captured || !messages.is_empty() {
+        return Some(now);
+    }
+
+    use widget::Operation;
+
+    let mut operation = widget::operation::next_frame();
+    user_interface.operate(&mut operation);
+
+    operation.finish()
+}
+
+/// Builds a [`UserInterface`] for the provided [`Application`] using its
+/// [`Theme`], logging [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
     application: &'a mut A,
     cache: Cache,
     renderer: &mut A::Renderer,
     size: Size,
     debug: &mut Debug,
-) -> UserInterface<'a, A::Message, A::Renderer> {
+) -> UserInterface<'a, A::Message, A::Renderer>
+where
+    A::Renderer: Themed,
+{
+    renderer.set_theme(application.theme());
+
     debug.view_started();
     let view = application.view();
     debug.view_finished();
@@ -451,25 +757,154 @@ pub fn build_user_interface<'a, A: Application>(
     user_interface
 }
 
-/// Updates an [`Application`] by feeding it the provided messages, spawning any
-/// resulting [`Command`], and tracking its [`Subscription`].
+/// Updates an [`Application`] by feeding it the provided messages, running any
+/// resulting [`Command`], recording its [`TimeTravel`] history, and tracking
+/// its [`Subscription`].
+#[allow(clippy::too_many_arguments)]
 pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     clipboard: &mut A::Clipboard,
     messages: &mut Vec<A::Message>,
+    operations: &mut Vec<Box<dyn widget::Operation<A::Message>>>,
+    time_travel: &mut TimeTravel<A>,
+    window: &winit::window::Window,
 ) {
     for message in messages.drain(..) {
         debug.log_message(&message);
+        time_travel.log_message(&message);
 
         debug.update_started();
         let command = runtime.enter(|| application.update(message, clipboard));
         debug.update_finished();
 
-        runtime.spawn(command);
+        run_command(command, runtime, proxy, operations, window);
+
+        time_travel.record(application);
     }
 
+    debug.time_travel(time_travel.overlay());
+
     let subscription = application.subscription();
     runtime.track(subscription);
 }
+
+/// Runs the actions of a [`Command`].
+///
+/// Futures are spawned in the [`Runtime`], while window actions are
+/// performed right away on the given window. The messages produced by window
+/// queries are sent through the given proxy.
+///
+/// Widget operations are queued, since they need a [`UserInterface`] to run.
+/// You can run them later with [`run_operations`].
+pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
+    command: Command<Message>,
+    runtime: &mut Runtime<E, Proxy<Message>, Message>,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+    operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+    window: &winit::window::Window,
+) {
+    use iced_native::window::Action;
+
+    for action in command.actions() {
+        match action {
+            command::Action::Future(future) => {
+                runtime.spawn(future);
+            }
+            command::Action::Stream(stream) => {
+                runtime.run(stream);
+            }
+            command::Action::Window(action) => match action {
+                Action::Resize { width, height } => {
+                    window.set_inner_size(winit::dpi::LogicalSize {
+                        width,
+                        height,
+                    });
+                }
+                Action::Move { x, y } => {
+                    window.set_outer_position(winit::dpi::LogicalPosition {
+                        x,
+                        y,
+                    });
+                }
+                Action::Minimize(minimized) => {
+                    window.set_minimized(minimized);
+                }
+                Action::Maximize(maximized) => {
+                    window.set_maximized(maximized);
+                }
+                Action::Fullscreen(fullscreen) => {
+                    window.set_fullscreen(if fullscreen {
+                        Some(winit::window::Fullscreen::Borderless(
+                            window.current_monitor(),
+                        ))
+                    } else {
+                        None
+                    });
+                }
+                Action::SetTitle(title) => {
+                    window.set_title(&title);
+                }
+                Action::Drag => {
+                    let _ = window.drag_window();
+                }
+                Action::GrabCursor(grabbed) => {
+                    let _ = window.set_cursor_grab(grabbed);
+                }
+                Action::FetchSize(tag) => {
+                    let size = window
+                        .inner_size()
+                        .to_logical::<f32>(window.scale_factor());
+
+                    proxy
+                        .send_event(tag(Size::new(size.width, size.height)))
+                        .expect("Send message to event loop");
+                }
+                Action::FetchPosition(tag) => {
+                    let position =
+                        window.outer_position().ok().map(|position| {
+                            let position = position
+                                .to_logical::<f32>(window.scale_factor());
+
+                            Point::new(position.x, position.y)
+                        });
+
+                    proxy
+                        .send_event(tag(position))
+                        .expect("Send message to event loop");
+                }
+                Action::FetchScaleFactor(tag) => {
+                    proxy
+                        .send_event(tag(window.scale_factor()))
+                        .expect("Send message to event loop");
+                }
+            },
+            command::Action::Widget(operation) => {
+                operations.push(operation);
+            }
+        }
+    }
+}
+
+/// Runs the queued widget operations on the given [`UserInterface`].
+///
+/// The results of the operations, if any, are sent through the given proxy.
+pub fn run_operations<Message: 'static + std::fmt::Debug + Send, Renderer>(
+    user_interface: &mut UserInterface<'_, Message, Renderer>,
+    operations: &mut Vec<Box<dyn widget::Operation<Message>>>,
+    proxy: &mut winit::event_loop::EventLoopProxy<Message>,
+) where
+    Renderer: iced_native::Renderer,
+{
+    for mut operation in operations.drain(..) {
+        user_interface.operate(operation.as_mut());
+
+        if let Some(message) = operation.finish() {
+            proxy
+                .send_event(message)
+                .expect("Send message to event loop");
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Inspect the layout of the user interface of an [`Application`].
+//!
+//! [`Application`]: crate::Application
+#[cfg(feature = "debug")]
+use iced_native::layout;
+
+// The inspector is only available together with the debug view, which is
+// disabled unless the `debug` feature is explicitly enabled.
+#[cfg(feature = "debug")]
+mod basic;
+#[cfg(not(feature = "debug"))]
+mod null;
+
+#[cfg(feature = "debug")]
+pub use basic::Inspector;
+#[cfg(not(feature = "debug"))]
+pub use null::Inspector;
+
+/// The renderer of an [`Inspector`].
+///
+/// When the `debug` feature is enabled, the [`Inspector`] highlights the
+/// inspected nodes and, therefore, needs a [`Debugger`]. Otherwise,
+/// any renderer will do.
+///
+/// [`Debugger`]: iced_native::layout::Debugger
+#[cfg(feature = "debug")]
+pub trait Renderer: layout::Debugger {}
+
+#[cfg(feature = "debug")]
+impl<T> Renderer for T where T: layout::Debugger {}
+
+/// The renderer of an [`Inspector`].
+///
+/// When the `debug` feature is enabled, the [`Inspector`] highlights the
+/// inspected nodes and, therefore, needs a [`Debugger`]. Otherwise,
+/// any renderer will do.
+///
+/// [`Debugger`]: iced_native::layout::Debugger
+#[cfg(not(feature = "debug"))]
+pub trait Renderer: iced_native::Renderer {}
+
+#[cfg(not(feature = "debug"))]
+impl<T> Renderer for T where T: iced_native::Renderer {}
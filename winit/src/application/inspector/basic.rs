@@ -0,0 +1,447 @@
+use iced_native::layout::{self, Layout};
+use iced_native::{Color, Point, UserInterface};
+
+use std::collections::HashSet;
+use winit::event::{
+    ElementState, KeyboardInput, MouseButton, VirtualKeyCode, WindowEvent,
+};
+
+/// The color used to highlight the hovered node.
+const HOVERED_COLOR: Color = Color::from_rgb(1.0, 0.6, 0.0);
+
+/// The color used to highlight the selected node.
+const SELECTED_COLOR: Color = Color::from_rgb(0.0, 0.6, 1.0);
+
+/// The maximum amount of rows of the layout tree displayed in the debug view.
+const VISIBLE_ROWS: usize = 30;
+
+/// The location of a node in a layout tree, as the indices of the children
+/// leading to it from the root.
+type Path = Vec<usize>;
+
+/// An inspector of the layout of a [`UserInterface`].
+///
+/// While the debug view is visible, `F11` toggles the [`Inspector`]. Once
+/// enabled, it captures all the mouse and keyboard input:
+///
+/// - Hovering a widget highlights its layout node.
+/// - Clicking a widget selects it and displays its bounds, its `Length`
+///   width and height, its padding and the `Limits` it was laid out with.
+/// - A collapsible tree of the layout is displayed in the debug view. The
+///   arrow keys move through it, collapsing and expanding nodes, and `Escape`
+///   clears the selection.
+#[derive(Debug)]
+pub struct Inspector {
+    is_enabled: bool,
+    hovered: Option<Path>,
+    selected: Option<Path>,
+    expanded: HashSet<Path>,
+}
+
+impl Inspector {
+    /// Creates a new disabled [`Inspector`].
+    pub fn new() -> Self {
+        Self {
+            is_enabled: false,
+            hovered: None,
+            selected: None,
+            expanded: std::iter::once(Path::new()).collect(),
+        }
+    }
+
+    /// Returns whether the [`Inspector`] is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Processes a window event while inspecting the given [`Layout`].
+    ///
+    /// The [`Inspector`] is closed together with the debug view.
+    ///
+    /// Returns whether the event was captured by the [`Inspector`], in which
+    /// case the user interface needs to be redrawn.
+    pub fn update(
+        &mut self,
+        event: &WindowEvent<'_>,
+        cursor_position: Point,
+        layout: Layout<'_>,
+        is_debugging: bool,
+    ) -> bool {
+        if !is_debugging {
+            let was_enabled = self.is_enabled;
+            self.is_enabled = false;
+
+            return was_enabled;
+        }
+
+        match event {
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(VirtualKeyCode::F11),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                self.is_enabled = !self.is_enabled;
+                self.hovered = None;
+
+                true
+            }
+            _ if !self.is_enabled => false,
+            WindowEvent::CursorMoved { .. } => {
+                self.hovered = path_at(layout, cursor_position);
+
+                true
+            }
+            WindowEvent::MouseInput {
+                state: ElementState::Pressed,
+                button: MouseButton::Left,
+                ..
+            } => {
+                self.select(self.hovered.clone());
+
+                true
+            }
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(key_code),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => {
+                match key_code {
+                    VirtualKeyCode::Escape => self.select(None),
+                    VirtualKeyCode::Up => self.move_selection(layout, -1),
+                    VirtualKeyCode::Down => self.move_selection(layout, 1),
+                    VirtualKeyCode::Left => self.collapse(),
+                    VirtualKeyCode::Right => self.expand(layout),
+                    _ => {}
+                }
+
+                true
+            }
+            WindowEvent::MouseInput { .. }
+            | WindowEvent::MouseWheel { .. }
+            | WindowEvent::KeyboardInput { .. }
+            | WindowEvent::ReceivedCharacter(_)
+            | WindowEvent::Touch(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Draws the [`UserInterface`], highlighting the inspected nodes, and
+    /// updates the lines of the [`Inspector`] in the debug view.
+    pub fn draw<Message, Renderer>(
+        &self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+        debug: &mut crate::Debug,
+    ) -> Renderer::Output
+    where
+        Renderer: layout::Debugger,
+    {
+        let mut output = user_interface.draw(renderer, cursor_position);
+
+        if debug.is_enabled() {
+            debug.inspector(self.overlay(user_interface.layout()));
+        }
+
+        if !self.is_enabled {
+            return output;
+        }
+
+        let layout = user_interface.layout();
+
+        for (path, color) in &[
+            (&self.hovered, HOVERED_COLOR),
+            (&self.selected, SELECTED_COLOR),
+        ] {
+            if let Some(node) =
+                path.as_ref().and_then(|path| find(layout, path))
+            {
+                output = renderer.highlight(output, node.bounds(), *color);
+            }
+        }
+
+        output
+    }
+
+    /// Returns the lines of the inspector section of the debug view.
+    pub fn overlay(&self, layout: Layout<'_>) -> Vec<String> {
+        if !self.is_enabled {
+            return vec![String::from("Layout inspector: F11")];
+        }
+
+        let mut lines = vec![String::from(
+            "Layout inspector (F11: close, click: select, arrows: navigate)",
+        )];
+
+        let target = self.selected.as_ref().or(self.hovered.as_ref());
+
+        if let Some(node) = target.and_then(|path| find(layout, path)) {
+            let bounds = node.bounds();
+
+            lines.push(format!(
+                "  {} at ({}, {}) with size {}x{}",
+                name(node),
+                bounds.x,
+                bounds.y,
+                bounds.width,
+                bounds.height
+            ));
+
+            if let Some(details) = node.details() {
+                let padding = details.padding;
+                let limits = details.limits;
+
+                lines.push(format!(
+                    "  Width: {:?}, Height: {:?}",
+                    details.width, details.height
+                ));
+                lines.push(format!(
+                    "  Padding: {} {} {} {}",
+                    padding.top, padding.right, padding.bottom, padding.left
+                ));
+                lines.push(format!(
+                    "  Limits: min {}x{}, max {}x{}",
+                    limits.min().width,
+                    limits.min().height,
+                    limits.max().width,
+                    limits.max().height
+                ));
+            }
+        }
+
+        lines.push(String::from("Layout tree:"));
+
+        let rows = rows(layout, &self.expanded);
+        let current = self
+            .selected
+            .as_ref()
+            .and_then(|selected| {
+                rows.iter().position(|(path, _)| path == selected)
+            })
+            .unwrap_or(0);
+        let start = current.saturating_sub(VISIBLE_ROWS / 2);
+
+        lines.extend(rows.iter().skip(start).take(VISIBLE_ROWS).map(
+            |(path, node)| {
+                let marker = if Some(path) == self.selected.as_ref() {
+                    ">"
+                } else {
+                    " "
+                };
+
+                let toggle = if node.children().next().is_none() {
+                    " "
+                } else if self.expanded.contains(path) {
+                    "-"
+                } else {
+                    "+"
+                };
+
+                let bounds = node.bounds();
+
+                format!(
+                    "  {} {}{} {} {}x{}",
+                    marker,
+                    "  ".repeat(path.len()),
+                    toggle,
+                    name(*node),
+                    bounds.width,
+                    bounds.height
+                )
+            },
+        ));
+
+        if rows.len() > start + VISIBLE_ROWS {
+            lines.push(String::from("    ..."));
+        }
+
+        lines
+    }
+
+    fn select(&mut self, path: Option<Path>) {
+        if let Some(path) = &path {
+            for depth in 0..path.len() {
+                let _ = self.expanded.insert(path[..depth].to_vec());
+            }
+        }
+
+        self.selected = path;
+    }
+
+    fn move_selection(&mut self, layout: Layout<'_>, offset: isize) {
+        let rows = rows(layout, &self.expanded);
+
+        let current = self.selected.as_ref().and_then(|selected| {
+            rows.iter().position(|(path, _)| path == selected)
+        });
+
+        let next = match current {
+            Some(current) => (current as isize + offset)
+                .max(0)
+                .min(rows.len() as isize - 1)
+                as usize,
+            None => 0,
+        };
+
+        self.selected = rows.into_iter().nth(next).map(|(path, _)| path);
+    }
+
+    fn collapse(&mut self) {
+        if let Some(selected) = &mut self.selected {
+            if !self.expanded.remove(selected) {
+                let _ = selected.pop();
+            }
+        }
+    }
+
+    fn expand(&mut self, layout: Layout<'_>) {
+        if let Some(selected) = &self.selected {
+            let has_children = find(layout, selected)
+                .map(|node| node.children().next().is_some())
+                .unwrap_or(false);
+
+            if has_children {
+                let _ = self.expanded.insert(selected.clone());
+            }
+        }
+    }
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn name(layout: Layout<'_>) -> &'static str {
+    layout
+        .details()
+        .map(|details| details.name)
+        .unwrap_or("Node")
+}
+
+fn find<'a>(layout: Layout<'a>, path: &[usize]) -> Option<Layout<'a>> {
+    match path.split_first() {
+        Some((index, rest)) => find(layout.children().nth(*index)?, rest),
+        None => Some(layout),
+    }
+}
+
+fn path_at(layout: Layout<'_>, position: Point) -> Option<Path> {
+    if !layout.bounds().contains(position) {
+        return None;
+    }
+
+    let children: Vec<_> = layout.children().collect();
+
+    // Children drawn last are on top
+    for (index, child) in children.into_iter().enumerate().rev() {
+        if let Some(mut path) = path_at(child, position) {
+            path.insert(0, index);
+
+            return Some(path);
+        }
+    }
+
+    Some(Path::new())
+}
+
+fn rows<'a>(
+    layout: Layout<'a>,
+    expanded: &HashSet<Path>,
+) -> Vec<(Path, Layout<'a>)> {
+    fn visit<'a>(
+        layout: Layout<'a>,
+        path: Path,
+        expanded: &HashSet<Path>,
+        rows: &mut Vec<(Path, Layout<'a>)>,
+    ) {
+        let is_expanded = expanded.contains(&path);
+
+        rows.push((path.clone(), layout));
+
+        if is_expanded {
+            for (index, child) in layout.children().enumerate() {
+                let mut child_path = path.clone();
+                child_path.push(index);
+
+                visit(child, child_path, expanded, rows);
+            }
+        }
+    }
+
+    let mut rows = Vec::new();
+    visit(layout, Path::new(), expanded, &mut rows);
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use iced_native::layout::Node;
+    use iced_native::Size;
+
+    fn tree() -> Node {
+        let mut left = Node::new(Size::new(50.0, 50.0));
+        let mut right = Node::with_children(
+            Size::new(50.0, 50.0),
+            vec![Node::new(Size::new(10.0, 10.0))],
+        );
+
+        left.move_to(Point::new(0.0, 0.0));
+        right.move_to(Point::new(50.0, 0.0));
+
+        Node::with_children(Size::new(100.0, 50.0), vec![left, right])
+    }
+
+    #[test]
+    fn finds_the_deepest_node_under_the_cursor() {
+        let node = tree();
+        let layout = Layout::new(&node);
+
+        assert_eq!(path_at(layout, Point::new(55.0, 5.0)), Some(vec![1, 0]));
+        assert_eq!(path_at(layout, Point::new(75.0, 25.0)), Some(vec![1]));
+        assert_eq!(path_at(layout, Point::new(25.0, 25.0)), Some(vec![0]));
+        assert_eq!(path_at(layout, Point::new(150.0, 25.0)), None);
+    }
+
+    #[test]
+    fn navigates_a_collapsible_tree() {
+        let node = tree();
+        let layout = Layout::new(&node);
+        let mut inspector = Inspector::new();
+
+        assert_eq!(rows(layout, &inspector.expanded).len(), 3);
+
+        inspector.select(Some(vec![1, 0]));
+        assert_eq!(rows(layout, &inspector.expanded).len(), 4);
+
+        inspector.collapse();
+        assert_eq!(inspector.selected, Some(vec![1]));
+
+        inspector.collapse();
+        assert_eq!(rows(layout, &inspector.expanded).len(), 3);
+
+        inspector.move_selection(layout, -1);
+        assert_eq!(inspector.selected, Some(vec![0]));
+
+        inspector.move_selection(layout, -5);
+        assert_eq!(inspector.selected, Some(vec![]));
+
+        inspector.expand(layout);
+        inspector.move_selection(layout, 2);
+        inspector.expand(layout);
+        inspector.move_selection(layout, 1);
+        assert_eq!(inspector.selected, Some(vec![1, 0]));
+    }
+}
@@ -0,0 +1,47 @@
+#![allow(missing_docs)]
+use iced_native::layout::Layout;
+use iced_native::{Point, UserInterface};
+
+use winit::event::WindowEvent;
+
+#[derive(Debug)]
+pub struct Inspector;
+
+impl Inspector {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        false
+    }
+
+    pub fn update(
+        &mut self,
+        _event: &WindowEvent<'_>,
+        _cursor_position: Point,
+        _layout: Layout<'_>,
+        _is_debugging: bool,
+    ) -> bool {
+        false
+    }
+
+    pub fn draw<Message, Renderer>(
+        &self,
+        user_interface: &mut UserInterface<'_, Message, Renderer>,
+        renderer: &mut Renderer,
+        cursor_position: Point,
+        _debug: &mut crate::Debug,
+    ) -> Renderer::Output
+    where
+        Renderer: iced_native::Renderer,
+    {
+        user_interface.draw(renderer, cursor_position)
+    }
+}
+
+impl Default for Inspector {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -72,6 +72,15 @@ impl<A: Application> State<A> {
         self.viewport.physical_size()
     }
 
+    /// Returns whether the window of the [`State`] is minimized.
+    ///
+    /// Minimized windows are reported with an empty physical [`Size`].
+    pub fn is_minimized(&self) -> bool {
+        let physical_size = self.physical_size();
+
+        physical_size.width == 0 || physical_size.height == 0
+    }
+
     /// Returns the logical [`Size`] of the [`Viewport`] of the [`State`].
     pub fn logical_size(&self) -> Size<f32> {
         self.viewport.logical_size()
@@ -0,0 +1,293 @@
+//! Travel back in time through the states of an [`Application`].
+use crate::Application;
+
+use std::collections::VecDeque;
+use winit::event::{ElementState, KeyboardInput, VirtualKeyCode, WindowEvent};
+
+/// The maximum amount of snapshots kept in the history.
+const HISTORY_LIMIT: usize = 1_000;
+
+/// The amount of messages displayed in the debug view.
+const VISIBLE_MESSAGES: usize = 10;
+
+/// A history of the states of an [`Application`], which can be used to
+/// travel back in time while debugging.
+///
+/// Every message processed by the [`Application`] is recorded together with
+/// a snapshot of its state after the update. Snapshots are only taken when
+/// the `debug` feature is enabled and [`Application::snapshot`] produces
+/// one.
+///
+/// While the debug view is visible, `F9` and `F10` step back and forward in
+/// the history. Earlier states are only displayed; the [`Application`] keeps
+/// running with its latest state and no [`Command`] is run again. Stepping
+/// forward past the latest state resumes the [`Application`].
+///
+/// [`Command`]: crate::Command
+#[allow(missing_debug_implementations)]
+pub struct TimeTravel<A: Application> {
+    history: VecDeque<Entry<A>>,
+    position: Option<usize>,
+    last_message: Option<String>,
+}
+
+struct Entry<A> {
+    message: String,
+    state: A,
+}
+
+/// A step through the history of a [`TimeTravel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    /// Go to the previous state.
+    Back,
+
+    /// Go to the next state.
+    Forward,
+}
+
+impl Step {
+    /// Returns the [`Step`] triggered by the given window event, if any.
+    ///
+    /// `F9` steps back, while `F10` steps forward.
+    pub fn from_window_event(event: &WindowEvent<'_>) -> Option<Self> {
+        match event {
+            WindowEvent::KeyboardInput {
+                input:
+                    KeyboardInput {
+                        virtual_keycode: Some(key_code),
+                        state: ElementState::Pressed,
+                        ..
+                    },
+                ..
+            } => match key_code {
+                VirtualKeyCode::F9 => Some(Step::Back),
+                VirtualKeyCode::F10 => Some(Step::Forward),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+}
+
+impl<A: Application> TimeTravel<A> {
+    /// Creates a new [`TimeTravel`] history, starting with a snapshot of the
+    /// given [`Application`].
+    pub fn new(application: &A) -> Self {
+        let initial = if cfg!(feature = "debug") {
+            application.snapshot()
+        } else {
+            None
+        };
+
+        Self {
+            history: initial
+                .map(|state| Entry {
+                    message: String::from("Init"),
+                    state,
+                })
+                .into_iter()
+                .collect(),
+            position: None,
+            last_message: None,
+        }
+    }
+
+    /// Returns whether the [`TimeTravel`] history is being recorded.
+    pub fn is_enabled(&self) -> bool {
+        !self.history.is_empty()
+    }
+
+    /// Returns whether an earlier state is currently being displayed.
+    pub fn is_traveling(&self) -> bool {
+        self.position.is_some()
+    }
+
+    /// Logs the message that is about to be processed by the
+    /// [`Application`].
+    pub fn log_message(&mut self, message: &A::Message) {
+        if self.is_enabled() {
+            self.last_message = Some(format!("{:?}", message));
+        }
+    }
+
+    /// Records a snapshot of the [`Application`] after processing the last
+    /// logged message.
+    pub fn record(&mut self, application: &A) {
+        let message = match self.last_message.take() {
+            Some(message) => message,
+            None => return,
+        };
+
+        if let Some(state) = application.snapshot() {
+            self.history.push_back(Entry { message, state });
+
+            if self.history.len() > HISTORY_LIMIT {
+                let _ = self.history.pop_front();
+
+                self.position =
+                    self.position.map(|position| position.saturating_sub(1));
+            }
+        }
+    }
+
+    /// Displays the state before the current one.
+    pub fn step_back(&mut self) {
+        let latest = self.history.len().saturating_sub(1);
+
+        self.position = Some(
+            self.position
+                .unwrap_or(latest)
+                .saturating_sub(1)
+                .min(latest),
+        );
+    }
+
+    /// Displays the state after the current one, resuming the
+    /// [`Application`] if it is the latest.
+    pub fn step_forward(&mut self) {
+        let latest = self.history.len().saturating_sub(1);
+
+        self.position = match self.position {
+            Some(position) if position + 1 < latest => Some(position + 1),
+            _ => None,
+        };
+    }
+
+    /// Takes the given [`Step`] through the history.
+    pub fn step(&mut self, step: Step) {
+        match step {
+            Step::Back => self.step_back(),
+            Step::Forward => self.step_forward(),
+        }
+    }
+
+    /// Returns the state that should be displayed: either the snapshot being
+    /// visited or the given [`Application`].
+    pub fn state<'a>(&'a mut self, application: &'a mut A) -> &'a mut A {
+        match self.position {
+            Some(position) => &mut self.history[position].state,
+            None => application,
+        }
+    }
+
+    /// Returns the lines of the time travel section of the debug view.
+    pub fn overlay(&self) -> Vec<String> {
+        if !self.is_enabled() {
+            return Vec::new();
+        }
+
+        let latest = self.history.len() - 1;
+        let current = self.position.unwrap_or(latest);
+
+        let mut lines = vec![format!(
+            "Time travel (F9: back, F10: forward): {}/{}{}",
+            current,
+            latest,
+            if self.is_traveling() { "" } else { " (live)" },
+        )];
+
+        let start = (current + 1).saturating_sub(VISIBLE_MESSAGES);
+
+        lines.extend(
+            self.history
+                .iter()
+                .enumerate()
+                .skip(start)
+                .take(VISIBLE_MESSAGES)
+                .map(|(index, entry)| {
+                    let marker = if index == current { ">" } else { " " };
+
+                    if entry.message.len() <= 100 {
+                        format!("  {} {}", marker, entry.message)
+                    } else {
+                        format!("  {} {:.100}...", marker, entry.message)
+                    }
+                }),
+        );
+
+        lines
+    }
+}
+
+#[cfg(all(test, feature = "debug"))]
+mod tests {
+    use super::*;
+
+    use crate::{Clipboard, Command, Program};
+    use iced_native::renderer::Null;
+    use iced_native::{Column, Element};
+
+    #[derive(Debug, Clone, Default)]
+    struct Counter {
+        value: i32,
+    }
+
+    impl Program for Counter {
+        type Renderer = Null;
+        type Message = i32;
+        type Clipboard = Clipboard;
+
+        fn update(
+            &mut self,
+            message: i32,
+            _clipboard: &mut Clipboard,
+        ) -> Command<i32> {
+            self.value += message;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, i32, Null> {
+            Column::new().into()
+        }
+    }
+
+    impl Application for Counter {
+        type Flags = ();
+
+        fn new(_flags: ()) -> (Self, Command<i32>) {
+            (Self::default(), Command::none())
+        }
+
+        fn title(&self) -> String {
+            String::from("Counter")
+        }
+
+        fn snapshot(&self) -> Option<Self> {
+            Some(self.clone())
+        }
+    }
+
+    #[test]
+    fn steps_through_snapshots() {
+        let mut counter = Counter::default();
+        let mut time_travel = TimeTravel::new(&counter);
+
+        for message in &[1, 2, 3] {
+            counter.value += message;
+
+            time_travel.log_message(message);
+            time_travel.record(&counter);
+        }
+
+        assert!(!time_travel.is_traveling());
+        assert_eq!(time_travel.state(&mut counter).value, 6);
+
+        time_travel.step_back();
+        time_travel.step_back();
+        assert_eq!(time_travel.state(&mut counter).value, 1);
+
+        time_travel.step_back();
+        time_travel.step_back();
+        assert_eq!(time_travel.state(&mut counter).value, 0);
+
+        time_travel.step_forward();
+        time_travel.step_forward();
+        assert_eq!(time_travel.state(&mut counter).value, 3);
+
+        time_travel.step_forward();
+        assert!(!time_travel.is_traveling());
+        assert_eq!(time_travel.state(&mut counter).value, 6);
+    }
+}
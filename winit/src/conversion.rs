@@ -25,6 +25,14 @@ pub fn window_event(
                 height: logical_size.height,
             }))
         }
+        WindowEvent::Moved(new_position) => {
+            let logical_position = new_position.to_logical(scale_factor);
+
+            Some(Event::Window(window::Event::Moved {
+                x: logical_position.x,
+                y: logical_position.y,
+            }))
+        }
         WindowEvent::ScaleFactorChanged { new_inner_size, .. } => {
             let logical_size = new_inner_size.to_logical(scale_factor);
 
@@ -33,6 +41,9 @@ pub fn window_event(
                 height: logical_size.height,
             }))
         }
+        WindowEvent::ThemeChanged(theme) => Some(Event::Window(
+            window::Event::ThemeChanged(self::theme(*theme)),
+        )),
         WindowEvent::CloseRequested => {
             Some(Event::Window(window::Event::CloseRequested))
         }
@@ -133,6 +144,26 @@ pub fn window_event(
     }
 }
 
+/// Converts a change in the minimized state of a window into iced events.
+///
+/// [`winit`] does not report the occlusion of a window yet, so a minimized
+/// window is considered occluded and a restored one visible.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn minimized(is_minimized: bool) -> [Event; 2] {
+    if is_minimized {
+        [
+            Event::Window(window::Event::Minimized),
+            Event::Window(window::Event::Occluded(true)),
+        ]
+    } else {
+        [
+            Event::Window(window::Event::Restored),
+            Event::Window(window::Event::Occluded(false)),
+        ]
+    }
+}
+
 /// Converts a [`Mode`] to a [`winit`] fullscreen mode.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -156,6 +187,17 @@ pub fn visible(mode: Mode) -> bool {
     }
 }
 
+/// Converts a `Theme` from [`winit`] to an [`iced_native`] window theme.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn theme(theme: winit::window::Theme) -> window::Theme {
+    match theme {
+        winit::window::Theme::Light => window::Theme::Light,
+        winit::window::Theme::Dark => window::Theme::Dark,
+    }
+}
+
 /// Converts a `MouseCursor` from [`iced_native`] to a [`winit`] cursor icon.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -177,6 +219,7 @@ pub fn mouse_interaction(
             winit::window::CursorIcon::EwResize
         }
         Interaction::ResizingVertically => winit::window::CursorIcon::NsResize,
+        Interaction::NotAllowed => winit::window::CursorIcon::NotAllowed,
     }
 }
 
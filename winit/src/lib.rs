@@ -24,10 +24,14 @@
 pub use iced_native::*;
 pub use winit;
 
+pub mod appearance;
 pub mod application;
 pub mod conversion;
 pub mod settings;
 
+#[cfg(feature = "recording")]
+pub mod recording;
+
 mod clipboard;
 mod error;
 mod mode;
@@ -40,4 +44,4 @@ pub use mode::Mode;
 pub use proxy::Proxy;
 pub use settings::Settings;
 
-pub use iced_graphics::Viewport;
+pub use iced_graphics::{Theme, Viewport};
@@ -0,0 +1,362 @@
+//! Record the events of an application and replay them later.
+//!
+//! This module is only available when the `recording` feature is enabled.
+//!
+//! A [`Recording`] captures every [`Event`] processed by an application,
+//! together with the time it happened and the initial size of the window.
+//! This is useful to reproduce bugs reported by users:
+//!
+//! - Set the `ICED_RECORD` environment variable to a file path and run the
+//!   application. The events will be saved to that file once it exits.
+//! - Set the `ICED_REPLAY` environment variable to the path of a recording,
+//!   and the application will ignore any user input and feed the recorded
+//!   events back at the same cadence.
+//!
+//! A [`Recording`] can also be replayed headlessly in your tests by feeding
+//! its [`Recording::events`] to a [`Simulator`].
+//!
+//! [`Simulator`]: crate::testing::Simulator
+use crate::{Event, Point, Size};
+
+use std::collections::VecDeque;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// The environment variable used to start recording.
+pub const RECORD_VARIABLE: &str = "ICED_RECORD";
+
+/// The environment variable used to replay a recording.
+pub const REPLAY_VARIABLE: &str = "ICED_REPLAY";
+
+/// A sequence of events captured from an application.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct Recording {
+    /// The logical size of the window when the recording started.
+    pub size: Size,
+
+    /// The frames of the [`Recording`], in order.
+    pub frames: Vec<Frame>,
+}
+
+/// A batch of events processed at once.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(crate = "serde")]
+pub struct Frame {
+    /// The time elapsed since the start of the [`Recording`].
+    pub time: Duration,
+
+    /// The events of the [`Frame`].
+    pub events: Vec<Event>,
+}
+
+/// An error produced when loading or saving a [`Recording`].
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    /// The recording file could not be read or written.
+    #[error("the recording file could not be read or written: {0}")]
+    Io(#[from] io::Error),
+
+    /// The recording file has an invalid format.
+    #[error("the recording file has an invalid format: {0}")]
+    Format(#[from] serde_json::Error),
+}
+
+impl Recording {
+    /// Creates an empty [`Recording`] for a window of the given size.
+    pub fn new(size: Size) -> Self {
+        Self {
+            size,
+            frames: Vec::new(),
+        }
+    }
+
+    /// Adds the given events to the [`Recording`] as a new [`Frame`].
+    ///
+    /// Redraw requests are not recorded, since shells produce them on their
+    /// own. Nothing is recorded if no events remain.
+    pub fn push(&mut self, time: Duration, events: &[Event]) {
+        let events: Vec<_> = events
+            .iter()
+            .filter(|event| !is_redraw_request(event))
+            .cloned()
+            .collect();
+
+        if !events.is_empty() {
+            self.frames.push(Frame { time, events });
+        }
+    }
+
+    /// Returns an iterator over all the events of the [`Recording`], in
+    /// order.
+    pub fn events(&self) -> impl Iterator<Item = Event> + '_ {
+        self.frames
+            .iter()
+            .flat_map(|frame| frame.events.iter().cloned())
+    }
+
+    /// Loads a [`Recording`] from the file at the given path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let contents = fs::read_to_string(path)?;
+
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Saves the [`Recording`] to a file at the given path.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), Error> {
+        let contents = serde_json::to_string_pretty(self)?;
+
+        Ok(fs::write(path, contents)?)
+    }
+}
+
+/// A recording or replaying session in the event loop of a shell.
+///
+/// A [`Session`] is configured with the `ICED_RECORD` and `ICED_REPLAY`
+/// environment variables. When neither is set, it does nothing.
+#[derive(Debug)]
+pub struct Session {
+    start: Instant,
+    mode: Option<Mode>,
+}
+
+#[derive(Debug)]
+enum Mode {
+    Record {
+        path: PathBuf,
+        recording: Recording,
+    },
+    Replay {
+        frames: VecDeque<Frame>,
+        cursor_position: Option<Point>,
+    },
+}
+
+impl Session {
+    /// Creates a new [`Session`] for the given window, as configured by the
+    /// environment.
+    ///
+    /// When replaying, the window is resized to the size of the recording.
+    pub fn from_env(window: &winit::window::Window) -> Self {
+        let start = Instant::now();
+
+        let mode = if let Some(path) = std::env::var_os(REPLAY_VARIABLE) {
+            match Recording::load(&path) {
+                Ok(recording) => {
+                    window.set_inner_size(winit::dpi::LogicalSize {
+                        width: recording.size.width,
+                        height: recording.size.height,
+                    });
+
+                    Some(Mode::Replay {
+                        frames: recording.frames.into(),
+                        cursor_position: None,
+                    })
+                }
+                Err(error) => {
+                    log::error!(
+                        "Failed to load recording {:?}: {}",
+                        path,
+                        error
+                    );
+
+                    None
+                }
+            }
+        } else if let Some(path) = std::env::var_os(RECORD_VARIABLE) {
+            let size =
+                window.inner_size().to_logical::<f32>(window.scale_factor());
+
+            Some(Mode::Record {
+                path: path.into(),
+                recording: Recording::new(Size::new(size.width, size.height)),
+            })
+        } else {
+            None
+        };
+
+        Self { start, mode }
+    }
+
+    /// Returns the moment the next recorded events are due, if the
+    /// [`Session`] still has events to replay.
+    pub fn next_replay(&self) -> Option<Instant> {
+        match &self.mode {
+            Some(Mode::Replay { frames, .. }) => {
+                frames.front().map(|frame| self.start + frame.time)
+            }
+            _ => None,
+        }
+    }
+
+    /// Records the given events, if the [`Session`] is recording.
+    pub fn record(&mut self, events: &[Event]) {
+        if let Some(Mode::Record { recording, .. }) = &mut self.mode {
+            recording.push(self.start.elapsed(), events);
+        }
+    }
+
+    /// Replaces the given events with the recorded events that are due, if
+    /// the [`Session`] is replaying.
+    pub fn replay(
+        &mut self,
+        window: &winit::window::Window,
+        events: &mut Vec<Event>,
+    ) {
+        if let Some(Mode::Replay {
+            frames,
+            cursor_position,
+        }) = &mut self.mode
+        {
+            let elapsed = self.start.elapsed();
+
+            events.clear();
+
+            while matches!(frames.front(), Some(frame) if frame.time <= elapsed)
+            {
+                let frame = frames.pop_front().expect("Pop recorded frame");
+
+                for event in &frame.events {
+                    match event {
+                        Event::Mouse(crate::mouse::Event::CursorMoved {
+                            position,
+                        }) => {
+                            *cursor_position = Some(*position);
+                        }
+                        Event::Window(crate::window::Event::Resized {
+                            width,
+                            height,
+                        }) => {
+                            window.set_inner_size(winit::dpi::LogicalSize {
+                                width: *width,
+                                height: *height,
+                            });
+                        }
+                        _ => {}
+                    }
+                }
+
+                events.extend(frame.events);
+            }
+        }
+    }
+
+    /// Returns the position of the mouse cursor, which is taken from the
+    /// recording while replaying.
+    pub fn cursor_position(&self, cursor_position: Point) -> Point {
+        match &self.mode {
+            Some(Mode::Replay {
+                cursor_position: Some(position),
+                ..
+            }) => *position,
+            _ => cursor_position,
+        }
+    }
+
+    /// Finishes the [`Session`], saving the [`Recording`] if recording.
+    pub fn finish(self) {
+        if let Some(Mode::Record { path, recording }) = self.mode {
+            if let Err(error) = recording.save(&path) {
+                log::error!("Failed to save recording {:?}: {}", path, error);
+            }
+        }
+    }
+}
+
+fn is_redraw_request(event: &Event) -> bool {
+    matches!(
+        event,
+        Event::Window(crate::window::Event::RedrawRequested(_))
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::keyboard;
+    use crate::mouse;
+    use crate::testing::{Renderer, Simulator};
+    use crate::window;
+    use crate::{text_input, Command, Element, Program, TextInput};
+
+    use iced_native::clipboard;
+
+    /// A single text input that keeps its value.
+    #[derive(Default)]
+    struct Input {
+        value: String,
+        state: text_input::State,
+    }
+
+    impl Program for Input {
+        type Renderer = Renderer;
+        type Message = String;
+        type Clipboard = clipboard::Null;
+
+        fn update(
+            &mut self,
+            value: String,
+            _clipboard: &mut clipboard::Null,
+        ) -> Command<String> {
+            self.value = value;
+
+            Command::none()
+        }
+
+        fn view(&mut self) -> Element<'_, String, Renderer> {
+            TextInput::new(&mut self.state, "Name", &self.value, String::from)
+                .into()
+        }
+    }
+
+    #[test]
+    fn saves_loads_and_replays() {
+        let mut recording = Recording::new(Size::new(400.0, 300.0));
+
+        recording.push(
+            Duration::from_millis(10),
+            &[
+                Event::Window(window::Event::RedrawRequested(Instant::now())),
+                Event::Mouse(mouse::Event::CursorMoved {
+                    position: Point::new(10.0, 10.0),
+                }),
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            ],
+        );
+        recording.push(
+            Duration::from_millis(20),
+            &[Event::Window(
+                window::Event::RedrawRequested(Instant::now()),
+            )],
+        );
+        recording.push(
+            Duration::from_millis(30),
+            &[
+                Event::Keyboard(keyboard::Event::CharacterReceived('o')),
+                Event::Keyboard(keyboard::Event::CharacterReceived('k')),
+            ],
+        );
+
+        assert_eq!(recording.frames.len(), 2);
+        assert_eq!(recording.events().count(), 4);
+
+        let path = std::env::temp_dir()
+            .join(format!("iced-recording-{}.json", std::process::id()));
+
+        recording.save(&path).unwrap();
+        let loaded = Recording::load(&path).unwrap();
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(loaded, recording);
+
+        let mut simulator =
+            Simulator::new(Input::default(), clipboard::Null, loaded.size);
+
+        simulator.simulate(loaded.events()).unwrap();
+
+        assert_eq!(simulator.program().value, "ok");
+    }
+}